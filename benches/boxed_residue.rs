@@ -1,6 +1,6 @@
 use criterion::{
     black_box, criterion_group, criterion_main, measurement::Measurement, BatchSize,
-    BenchmarkGroup, Criterion,
+    BenchmarkGroup, BenchmarkId, Criterion,
 };
 use crypto_bigint::{
     modular::{BoxedResidue, BoxedResidueParams},
@@ -33,6 +33,14 @@ fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
         )
     });
 
+    group.bench_function("squaring, BoxedUint^2", |b| {
+        b.iter_batched(
+            || BoxedResidue::new(BoxedUint::random(&mut OsRng, UINT_BITS), params.clone()),
+            |x| black_box(x.square()),
+            BatchSize::SmallInput,
+        )
+    });
+
     let modulus = to_biguint(params.modulus());
     group.bench_function("multiplication, BigUint*BigUint (num-bigint-dig)", |b| {
         b.iter_batched(
@@ -121,6 +129,39 @@ fn bench_montgomery_conversion<M: Measurement>(group: &mut BenchmarkGroup<'_, M>
     });
 }
 
+/// Modulus sizes to use in benchmarks which are parameterized by size, e.g. RSA key sizes.
+const SIZES: [u32; 4] = [1024, 2048, 3072, 4096];
+
+fn bench_montgomery_sizes<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    for bits in SIZES {
+        let params = BoxedResidueParams::new(
+            BoxedUint::random(&mut OsRng, bits) | BoxedUint::one_with_precision(bits),
+        )
+        .unwrap();
+
+        group.bench_function(BenchmarkId::new("squaring", bits), |b| {
+            b.iter_batched(
+                || BoxedResidue::new(BoxedUint::random(&mut OsRng, bits), params.clone()),
+                |x| black_box(x.square()),
+                BatchSize::SmallInput,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("modpow", bits), |b| {
+            b.iter_batched(
+                || {
+                    let x = BoxedResidue::new(BoxedUint::random(&mut OsRng, bits), params.clone());
+                    let p = BoxedUint::random(&mut OsRng, bits)
+                        | (BoxedUint::one_with_precision(bits) << (bits - 1));
+                    (x, p)
+                },
+                |(x, p)| black_box(x.pow(&p)),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+}
+
 fn bench_montgomery(c: &mut Criterion) {
     let mut group = c.benchmark_group("Montgomery arithmetic");
     bench_montgomery_conversion(&mut group);
@@ -128,6 +169,12 @@ fn bench_montgomery(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_montgomery);
+fn bench_montgomery_by_size(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Montgomery arithmetic by modulus size");
+    bench_montgomery_sizes(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_montgomery, bench_montgomery_by_size);
 
 criterion_main!(benches);
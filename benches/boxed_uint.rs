@@ -0,0 +1,93 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::Measurement, BatchSize,
+    BenchmarkGroup, BenchmarkId, Criterion,
+};
+use crypto_bigint::BoxedUint;
+use num_bigint::BigUint;
+use rand_core::OsRng;
+
+/// Sizes of `BoxedUint` to use in the multiplication benchmarks, chosen to straddle the threshold
+/// above which Karatsuba multiplication is used.
+const MUL_BITS: &[u32] = &[1024, 1536, 2048, 3072, 4096, 8192];
+
+/// Minimum operand sizes in limbs for Karatsuba multiplication to compare, including one which
+/// always uses schoolbook multiplication.
+const KARATSUBA_MIN_LIMBS: &[usize] = &[8, 16, 32, 64, usize::MAX];
+
+fn to_biguint(uint: &BoxedUint) -> BigUint {
+    BigUint::from_bytes_be(&uint.to_be_bytes())
+}
+
+fn bench_mul<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    for &bits in MUL_BITS {
+        group.bench_function(format!("mul, {bits}-bit BoxedUint*BoxedUint"), |b| {
+            b.iter_batched(
+                || {
+                    (
+                        BoxedUint::random(&mut OsRng, bits),
+                        BoxedUint::random(&mut OsRng, bits),
+                    )
+                },
+                |(x, y)| black_box(x.mul(&y)),
+                BatchSize::SmallInput,
+            )
+        });
+
+        group.bench_function(
+            format!("mul, {bits}-bit BigUint*BigUint (num-bigint-dig)"),
+            |b| {
+                b.iter_batched(
+                    || {
+                        (
+                            to_biguint(&BoxedUint::random(&mut OsRng, bits)),
+                            to_biguint(&BoxedUint::random(&mut OsRng, bits)),
+                        )
+                    },
+                    |(x, y)| black_box(x * y),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+}
+
+fn bench_karatsuba_threshold<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    for &bits in MUL_BITS {
+        for &min_limbs in KARATSUBA_MIN_LIMBS {
+            let name = if min_limbs == usize::MAX {
+                "schoolbook".into()
+            } else {
+                format!("karatsuba, min {min_limbs} limbs")
+            };
+
+            group.bench_function(BenchmarkId::new(name, bits), |b| {
+                b.iter_batched(
+                    || {
+                        (
+                            BoxedUint::random(&mut OsRng, bits),
+                            BoxedUint::random(&mut OsRng, bits),
+                        )
+                    },
+                    |(x, y)| black_box(x.mul_with_karatsuba_threshold(&y, min_limbs)),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+}
+
+fn bench_wrapping_ops(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wrapping ops");
+    bench_mul(&mut group);
+    group.finish();
+}
+
+fn bench_karatsuba(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Karatsuba threshold");
+    bench_karatsuba_threshold(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_wrapping_ops, bench_karatsuba);
+
+criterion_main!(benches);
@@ -146,9 +146,28 @@ fn bench_inv_mod<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
     });
 }
 
+fn bench_multiplication<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    group.bench_function("wrapping_mul, U2048", |b| {
+        b.iter_batched(
+            || U2048::random(&mut OsRng),
+            |x| black_box(x.wrapping_mul(&x)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("wrapping_square, U2048", |b| {
+        b.iter_batched(
+            || U2048::random(&mut OsRng),
+            |x| black_box(x.wrapping_square()),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
 fn bench_wrapping_ops(c: &mut Criterion) {
     let mut group = c.benchmark_group("wrapping ops");
     bench_division(&mut group);
+    bench_multiplication(&mut group);
     group.finish();
 }
 
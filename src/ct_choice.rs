@@ -3,6 +3,9 @@ use subtle::Choice;
 use crate::Word;
 
 /// A boolean value returned by constant-time `const fn`s.
+///
+/// The `from_*` comparison constructors are computed with bitwise arithmetic rather than
+/// comparison operators, so they don't introduce branches on their inputs.
 // TODO: should be replaced by `subtle::Choice` or `CtOption`
 // when `subtle` starts supporting const fns.
 #[derive(Debug, Copy, Clone)]
@@ -58,44 +61,105 @@ impl CtChoice {
 
     /// Returns the truthy value if `x == y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_u32_eq(x: u32, y: u32) -> Self {
+    pub const fn from_u32_eq(x: u32, y: u32) -> Self {
         Self::from_u32_nonzero(x ^ y).not()
     }
 
     /// Returns the truthy value if `x == y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_word_eq(x: Word, y: Word) -> Self {
+    pub const fn from_word_eq(x: Word, y: Word) -> Self {
         Self::from_word_nonzero(x ^ y).not()
     }
 
     /// Returns the truthy value if `x < y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_word_lt(x: Word, y: Word) -> Self {
+    pub const fn from_word_lt(x: Word, y: Word) -> Self {
         let bit = (((!x) & y) | (((!x) | y) & (x.wrapping_sub(y)))) >> (Word::BITS - 1);
         Self::from_word_lsb(bit)
     }
 
     /// Returns the truthy value if `x < y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_u32_lt(x: u32, y: u32) -> Self {
+    pub const fn from_u32_lt(x: u32, y: u32) -> Self {
         let bit = (((!x) & y) | (((!x) | y) & (x.wrapping_sub(y)))) >> (u32::BITS - 1);
         Self::from_u32_lsb(bit)
     }
 
     /// Returns the truthy value if `x <= y` and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_word_le(x: Word, y: Word) -> Self {
+    pub const fn from_word_le(x: Word, y: Word) -> Self {
         let bit = (((!x) | y) & ((x ^ y) | !(y.wrapping_sub(x)))) >> (Word::BITS - 1);
         Self::from_word_lsb(bit)
     }
 
     /// Returns the truthy value if `x <= y` and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_u32_le(x: u32, y: u32) -> Self {
+    pub const fn from_u32_le(x: u32, y: u32) -> Self {
         let bit = (((!x) | y) & ((x ^ y) | !(y.wrapping_sub(x)))) >> (u32::BITS - 1);
         Self::from_u32_lsb(bit)
     }
 
+    /// Returns the truthy value if `x != y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_word_ne(x: Word, y: Word) -> Self {
+        Self::from_word_eq(x, y).not()
+    }
+
+    /// Returns the truthy value if `x > y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_word_gt(x: Word, y: Word) -> Self {
+        Self::from_word_lt(y, x)
+    }
+
+    /// Returns the truthy value if `x >= y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_word_ge(x: Word, y: Word) -> Self {
+        Self::from_word_lt(x, y).not()
+    }
+
+    /// Returns the truthy value if `x > y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_u32_gt(x: u32, y: u32) -> Self {
+        Self::from_u32_lt(y, x)
+    }
+
+    /// Returns the truthy value if `x >= y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_u32_ge(x: u32, y: u32) -> Self {
+        Self::from_u32_lt(x, y).not()
+    }
+
+    /// Returns the truthy value if `x == y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_u64_eq(x: u64, y: u64) -> Self {
+        let z = x ^ y;
+        let bit = (z | z.wrapping_neg()) >> (u64::BITS - 1);
+        #[allow(trivial_numeric_casts)]
+        let bit = bit as Word;
+        Self::from_word_lsb(bit).not()
+    }
+
+    /// Returns the truthy value if `x < y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_u64_lt(x: u64, y: u64) -> Self {
+        let bit = (((!x) & y) | (((!x) | y) & (x.wrapping_sub(y)))) >> (u64::BITS - 1);
+        #[allow(trivial_numeric_casts)]
+        let bit = bit as Word;
+        Self::from_word_lsb(bit)
+    }
+
+    /// Returns the truthy value if `x > y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_u64_gt(x: u64, y: u64) -> Self {
+        Self::from_u64_lt(y, x)
+    }
+
+    /// Returns the truthy value if `x >= y`, and the falsy value otherwise.
+    #[inline]
+    pub const fn from_u64_ge(x: u64, y: u64) -> Self {
+        Self::from_u64_lt(x, y).not()
+    }
+
     #[inline]
     pub(crate) const fn not(&self) -> Self {
         Self(!self.0)
@@ -117,12 +181,6 @@ impl CtChoice {
         a ^ (self.0 & (a ^ b))
     }
 
-    /// Return `b` if `self` is truthy, otherwise return `a`.
-    #[inline]
-    pub(crate) const fn select_u32(&self, a: u32, b: u32) -> u32 {
-        a ^ (self.as_u32_mask() & (a ^ b))
-    }
-
     /// Return `x` if `self` is truthy, otherwise return 0.
     #[inline]
     pub(crate) const fn if_true_word(&self, x: Word) -> Word {
@@ -140,10 +198,19 @@ impl CtChoice {
         self.0 == CtChoice::TRUE.0
     }
 
+    /// Returns `1` if `self` is truthy, and `0` otherwise.
     #[inline]
-    pub(crate) const fn to_u8(self) -> u8 {
+    pub const fn to_u8(self) -> u8 {
         (self.0 as u8) & 1
     }
+
+    /// Returns the truthy value if `value == 1`, and the falsy value if `value == 0`.
+    /// Panics for other values in debug builds.
+    #[inline]
+    pub const fn from_u8(value: u8) -> Self {
+        debug_assert!(value == 0 || value == 1);
+        Self((value as Word).wrapping_neg())
+    }
 }
 
 impl From<CtChoice> for Choice {
@@ -152,6 +219,12 @@ impl From<CtChoice> for Choice {
     }
 }
 
+impl From<Choice> for CtChoice {
+    fn from(choice: Choice) -> Self {
+        CtChoice::from_u8(choice.unwrap_u8())
+    }
+}
+
 impl From<CtChoice> for bool {
     fn from(choice: CtChoice) -> Self {
         choice.is_true_vartime()
@@ -162,6 +235,7 @@ impl From<CtChoice> for bool {
 mod tests {
     use super::CtChoice;
     use crate::Word;
+    use subtle::Choice;
 
     #[test]
     fn select() {
@@ -170,4 +244,67 @@ mod tests {
         assert_eq!(CtChoice::TRUE.select_word(a, b), b);
         assert_eq!(CtChoice::FALSE.select_word(a, b), a);
     }
+
+    #[test]
+    fn comparisons() {
+        const WORDS: [Word; 6] = [
+            0,
+            1,
+            Word::MAX / 2,
+            Word::MAX / 2 + 1,
+            Word::MAX - 1,
+            Word::MAX,
+        ];
+        for x in WORDS {
+            for y in WORDS {
+                assert_eq!(CtChoice::from_word_eq(x, y).is_true_vartime(), x == y);
+                assert_eq!(CtChoice::from_word_ne(x, y).is_true_vartime(), x != y);
+                assert_eq!(CtChoice::from_word_lt(x, y).is_true_vartime(), x < y);
+                assert_eq!(CtChoice::from_word_le(x, y).is_true_vartime(), x <= y);
+                assert_eq!(CtChoice::from_word_gt(x, y).is_true_vartime(), x > y);
+                assert_eq!(CtChoice::from_word_ge(x, y).is_true_vartime(), x >= y);
+            }
+        }
+
+        const U32S: [u32; 6] = [0, 1, u32::MAX / 2, u32::MAX / 2 + 1, u32::MAX - 1, u32::MAX];
+        for x in U32S {
+            for y in U32S {
+                assert_eq!(CtChoice::from_u32_eq(x, y).is_true_vartime(), x == y);
+                assert_eq!(CtChoice::from_u32_lt(x, y).is_true_vartime(), x < y);
+                assert_eq!(CtChoice::from_u32_le(x, y).is_true_vartime(), x <= y);
+                assert_eq!(CtChoice::from_u32_gt(x, y).is_true_vartime(), x > y);
+                assert_eq!(CtChoice::from_u32_ge(x, y).is_true_vartime(), x >= y);
+            }
+        }
+
+        const U64S: [u64; 6] = [0, 1, u64::MAX / 2, u64::MAX / 2 + 1, u64::MAX - 1, u64::MAX];
+        for x in U64S {
+            for y in U64S {
+                assert_eq!(CtChoice::from_u64_eq(x, y).is_true_vartime(), x == y);
+                assert_eq!(CtChoice::from_u64_lt(x, y).is_true_vartime(), x < y);
+                assert_eq!(CtChoice::from_u64_gt(x, y).is_true_vartime(), x > y);
+                assert_eq!(CtChoice::from_u64_ge(x, y).is_true_vartime(), x >= y);
+            }
+        }
+    }
+
+    #[test]
+    fn u8_round_trip() {
+        assert_eq!(CtChoice::from_u8(1).to_u8(), 1);
+        assert_eq!(CtChoice::from_u8(0).to_u8(), 0);
+        assert!(CtChoice::from_u8(1).is_true_vartime());
+        assert!(!CtChoice::from_u8(0).is_true_vartime());
+    }
+
+    #[test]
+    fn choice_round_trip() {
+        for value in [0u8, 1] {
+            let choice = CtChoice::from(Choice::from(value));
+            assert_eq!(choice.to_u8(), value);
+            assert_eq!(Choice::from(choice).unwrap_u8(), value);
+        }
+
+        assert_eq!(CtChoice::from(Choice::from(1)).0, CtChoice::TRUE.0);
+        assert_eq!(CtChoice::from(Choice::from(0)).0, CtChoice::FALSE.0);
+    }
 }
@@ -118,8 +118,9 @@
 //! ### Random number generation
 //!
 //! When the `rand_core` or `rand` features of this crate are enabled, it's
-//! possible to generate random numbers using any CSRNG by using the
-//! [`Random`] trait:
+//! possible to generate random numbers using any RNG by using the
+//! [`Random`] trait. Note that it also accepts non-cryptographic RNGs, which
+//! are convenient for tests but must never be used to generate secrets:
 //!
 //! ```
 //! # #[cfg(feature = "rand")]
@@ -181,13 +182,16 @@ pub use crate::{
     non_zero::NonZero,
     traits::*,
     uint::div_limb::Reciprocal,
+    uint::encoding::{DecodeError, ParseError},
+    uint::from::TryFromUintError,
+    uint::reciprocal::UintReciprocal,
     uint::*,
     wrapping::Wrapping,
 };
 pub use subtle;
 
 #[cfg(feature = "alloc")]
-pub use crate::uint::boxed::{encoding::DecodeError, BoxedUint};
+pub use crate::uint::boxed::BoxedUint;
 
 #[cfg(feature = "generic-array")]
 pub use {
@@ -195,6 +199,9 @@ pub use {
     generic_array::{self, typenum::consts},
 };
 
+#[cfg(feature = "hmac-drbg")]
+pub use digest;
+
 #[cfg(feature = "rand_core")]
 pub use rand_core;
 
@@ -10,6 +10,7 @@ mod bits;
 mod cmp;
 mod encoding;
 mod from;
+mod inv;
 mod mul;
 mod neg;
 mod shl;
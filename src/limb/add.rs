@@ -6,6 +6,18 @@ use subtle::CtOption;
 
 impl Limb {
     /// Computes `self + rhs + carry`, returning the result along with the new carry.
+    ///
+    /// The incoming `carry` must be `0` or `1`, and the returned carry is always `0` or `1`, so
+    /// the carry of one limb can be passed directly into the addition of the next:
+    ///
+    /// ```
+    /// use crypto_bigint::Limb;
+    ///
+    /// // (MAX, 0) + (1, 0) as two-limb little endian integers
+    /// let (lo, carry) = Limb::MAX.adc(Limb::ONE, Limb::ZERO);
+    /// let (hi, carry) = Limb::ZERO.adc(Limb::ZERO, carry);
+    /// assert_eq!((lo, hi, carry), (Limb::ZERO, Limb::ONE, Limb::ZERO));
+    /// ```
     #[inline(always)]
     pub const fn adc(self, rhs: Limb, carry: Limb) -> (Limb, Limb) {
         let a = self.0 as WideWord;
@@ -1,6 +1,6 @@
 //! Limb comparisons
 
-use crate::{CtChoice, Limb};
+use crate::{CtChoice, Limb, Word};
 use core::cmp::Ordering;
 use subtle::{Choice, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
 
@@ -30,6 +30,22 @@ impl Limb {
         Self(c.select_word(a.0, b.0))
     }
 
+    /// Constant-time lookup of `table[index]`.
+    ///
+    /// Every entry of `table` is accessed regardless of `index`, so the access pattern does not
+    /// depend on it. If `index` is out of range, [`Limb::ZERO`] is returned.
+    pub const fn ct_select_from(table: &[Self], index: Word) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+
+        while i < table.len() {
+            ret = Self::ct_select(ret, table[i], CtChoice::from_word_eq(i as Word, index));
+            i += 1;
+        }
+
+        ret
+    }
+
     /// Returns the truthy value if `self != 0` and the falsy value otherwise.
     #[inline]
     pub(crate) const fn ct_is_nonzero(&self) -> CtChoice {
@@ -94,7 +110,7 @@ impl PartialEq for Limb {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Limb, Zero};
+    use crate::{Limb, Word, Zero};
     use core::cmp::Ordering;
     use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
 
@@ -161,6 +177,21 @@ mod tests {
         assert!(!bool::from(c.ct_lt(&b)));
     }
 
+    #[test]
+    fn ct_select_from() {
+        let table: [Limb; 5] = core::array::from_fn(|i| Limb((i as Word + 1) * 0x0101));
+
+        for (i, entry) in table.iter().enumerate() {
+            assert_eq!(Limb::ct_select_from(&table, i as Word), *entry);
+        }
+
+        // Out of range indices select zero
+        for index in [5, 6, Word::MAX] {
+            assert_eq!(Limb::ct_select_from(&table, index), Limb::ZERO);
+        }
+        assert_eq!(Limb::ct_select_from(&[], 0), Limb::ZERO);
+    }
+
     #[test]
     fn cmp() {
         assert_eq!(Limb::ZERO.cmp(&Limb::ONE), Ordering::Less);
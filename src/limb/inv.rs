@@ -0,0 +1,59 @@
+//! Limb inversion
+
+use crate::Limb;
+
+impl Limb {
+    /// Computes `-modulus_lsb^-1 mod 2^Limb::BITS`, the constant used by Montgomery reduction
+    /// (often called `mod_neg_inv`), where `modulus_lsb` is the least significant limb of an odd
+    /// modulus.
+    ///
+    /// `modulus_lsb` must be odd, as otherwise no inverse exists.
+    ///
+    /// This is computed in constant time using Newton's iteration, which doubles the number of
+    /// correct low bits of the inverse on each step.
+    pub const fn montgomery_inv(modulus_lsb: Limb) -> Limb {
+        debug_assert!(modulus_lsb.0 & 1 == 1, "modulus must be odd");
+
+        // For odd `m`, `m * m == 1 mod 8`, so `m` is its own inverse to 3 bits of precision.
+        let m = modulus_lsb.0;
+        let mut inv = m;
+        let mut bits = 3;
+
+        while bits < Limb::BITS {
+            inv = inv
+                .wrapping_mul(2)
+                .wrapping_sub(m.wrapping_mul(inv).wrapping_mul(inv));
+            bits *= 2;
+        }
+
+        Limb(inv.wrapping_neg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Limb, Word};
+
+    #[test]
+    fn montgomery_inv() {
+        for m in [1, 3, 5, 0xfffffff1, Word::MAX, Word::MAX - 2, 0x12345679] {
+            let inv = Limb::montgomery_inv(Limb(m));
+            assert_eq!(m.wrapping_mul(inv.0), Word::MAX);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn montgomery_inv_random() {
+        use crate::Random;
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..1000 {
+            let m = Limb::random(&mut rng).0 | 1;
+            let inv = Limb::montgomery_inv(Limb(m));
+            assert_eq!(m.wrapping_mul(inv.0), Word::MAX);
+        }
+    }
+}
@@ -6,6 +6,23 @@ use subtle::CtOption;
 
 impl Limb {
     /// Computes `self + (b * c) + carry`, returning the result along with the new carry.
+    ///
+    /// The result always fits in two limbs, even when all of the inputs are [`Limb::MAX`], so the
+    /// returned carry can be passed directly into the multiply-accumulate of the next limb. This is
+    /// the building block of schoolbook multiplication and Montgomery reduction:
+    ///
+    /// ```
+    /// use crypto_bigint::Limb;
+    ///
+    /// // MAX + MAX * MAX + MAX = MAX * 2^BITS + MAX
+    /// let (lo, carry) = Limb::MAX.mac(Limb::MAX, Limb::MAX, Limb::MAX);
+    /// assert_eq!((lo, carry), (Limb::MAX, Limb::MAX));
+    ///
+    /// // Multiply the two-limb value (MAX, MAX) by MAX, accumulating into zero
+    /// let (lo, carry) = Limb::ZERO.mac(Limb::MAX, Limb::MAX, Limb::ZERO);
+    /// let (mid, carry) = Limb::ZERO.mac(Limb::MAX, Limb::MAX, carry);
+    /// assert_eq!((lo, mid, carry), (Limb::ONE, Limb::MAX, Limb::MAX.wrapping_sub(Limb::ONE)));
+    /// ```
     #[inline(always)]
     pub const fn mac(self, b: Limb, c: Limb, carry: Limb) -> (Limb, Limb) {
         let a = self.0 as WideWord;
@@ -6,6 +6,22 @@ use subtle::CtOption;
 
 impl Limb {
     /// Computes `self - (rhs + borrow)`, returning the result along with the new borrow.
+    ///
+    /// Unlike the carry of [`Limb::adc`], the borrow is a mask: only its most significant bit is
+    /// used, and the returned borrow is either `0` or [`Limb::MAX`]. This allows it to be passed
+    /// directly into the subtraction of the next limb, or used as a mask once the subtraction is
+    /// complete:
+    ///
+    /// ```
+    /// use crypto_bigint::Limb;
+    ///
+    /// // (0, 1) - (1, 0) as two-limb little endian integers
+    /// let (lo, borrow) = Limb::ZERO.sbb(Limb::ONE, Limb::ZERO);
+    /// assert_eq!(borrow, Limb::MAX);
+    ///
+    /// let (hi, borrow) = Limb::ONE.sbb(Limb::ZERO, borrow);
+    /// assert_eq!((lo, hi, borrow), (Limb::MAX, Limb::ZERO, Limb::ZERO));
+    /// ```
     #[inline(always)]
     pub const fn sbb(self, rhs: Limb, borrow: Limb) -> (Limb, Limb) {
         let a = self.0 as WideWord;
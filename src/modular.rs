@@ -19,6 +19,7 @@
 mod dyn_residue;
 mod reduction;
 mod residue;
+mod wide_uint;
 
 mod add;
 mod bernstein_yang;
@@ -34,12 +35,19 @@ pub(crate) mod boxed_residue;
 pub use self::{
     bernstein_yang::BernsteinYangInverter,
     dyn_residue::{DynResidue, DynResidueParams},
-    reduction::montgomery_reduction,
+    reduction::{
+        montgomery_reduction, montgomery_reduction_unreduced, montgomery_reduction_wide,
+        MontgomeryParams,
+    },
     residue::{Residue, ResidueParams},
+    wide_uint::WideUint,
 };
 
 #[cfg(feature = "alloc")]
-pub use self::boxed_residue::{BoxedResidue, BoxedResidueParams};
+pub use self::boxed_residue::{
+    BoxedMontgomeryEngine, BoxedResidue, BoxedResidueFixedBase, BoxedResidueParams, CachedResidue,
+    PowError,
+};
 
 /// A generalization for numbers kept in optimized representations (e.g. Montgomery)
 /// that can be converted back to the original form.
@@ -2,6 +2,9 @@
 //! is chosen at runtime.
 
 mod add;
+mod cached;
+mod engine;
+mod fixed_base;
 mod inv;
 mod mul;
 mod neg;
@@ -12,14 +15,19 @@ use super::{
     reduction::{montgomery_reduction_boxed, montgomery_reduction_boxed_mut},
     Retrieve,
 };
-use crate::{BoxedUint, Limb, NonZero, Word};
-use subtle::CtOption;
+use crate::{BoxedUint, Limb, NonZero};
+
+pub use self::{
+    cached::CachedResidue, engine::BoxedMontgomeryEngine, fixed_base::BoxedResidueFixedBase,
+    pow::PowError,
+};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "std")]
 use std::sync::Arc;
 
 #[cfg(feature = "zeroize")]
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Parameters to efficiently go to/from the Montgomery form for an odd modulus whose size and value
 /// are both chosen at runtime.
@@ -45,6 +53,22 @@ impl BoxedResidueParams {
     /// Returns a `CtOption` that is `None` if the provided modulus is not odd.
     /// TODO(tarcieri): DRY out with `DynResidueParams::new`?
     pub fn new(modulus: BoxedUint) -> CtOption<Self> {
+        Self::new_with_validity(modulus, Choice::from(1))
+    }
+
+    /// Instantiates a new set of [`BoxedResidueParams`] representing the given `modulus`, which
+    /// may come from an untrusted source.
+    ///
+    /// Returns a `CtOption` that is `None` if the provided modulus is even (including zero) or one.
+    /// Only the least significant bit is used to determine whether the modulus is odd.
+    pub fn new_checked(modulus: &BoxedUint) -> CtOption<Self> {
+        let is_not_one = !modulus.ct_eq(&BoxedUint::one());
+        Self::new_with_validity(modulus.clone(), is_not_one)
+    }
+
+    /// Common functionality of `new` and `new_checked`, where `is_valid` is an additional condition
+    /// on the modulus which must hold for the result to be `Some`.
+    fn new_with_validity(modulus: BoxedUint, is_valid: Choice) -> CtOption<Self> {
         let bits_precision = modulus.bits_precision();
 
         // Use a surrogate value of `1` in case a modulus of `0` is passed.
@@ -65,7 +89,7 @@ impl BoxedResidueParams {
             .rem(&modulus_nz.widen(bits_precision * 2))
             .shorten(bits_precision);
 
-        Self::new_inner(modulus, r, r2)
+        Self::new_inner(modulus, r, r2, is_valid)
     }
 
     /// Instantiates a new set of [`BoxedResidueParams`] representing the given `modulus`, which
@@ -98,17 +122,23 @@ impl BoxedResidueParams {
             .rem_vartime(&modulus_nz.widen(bits_precision * 2))
             .shorten(bits_precision);
 
-        Self::new_inner(modulus, r, r2).into()
+        Self::new_inner(modulus, r, r2, Choice::from(1)).into()
     }
 
     /// Common functionality of `new` and `new_vartime`.
-    fn new_inner(modulus: BoxedUint, r: BoxedUint, r2: BoxedUint) -> CtOption<Self> {
+    fn new_inner(
+        modulus: BoxedUint,
+        r: BoxedUint,
+        r2: BoxedUint,
+        is_valid: Choice,
+    ) -> CtOption<Self> {
         let is_odd = modulus.is_odd();
 
         // Since we are calculating the inverse modulo (Word::MAX+1),
         // we can take the modulo right away and calculate the inverse of the first limb only.
-        let modulus_lo = BoxedUint::from(modulus.limbs.get(0).copied().unwrap_or_default());
-        let mod_neg_inv = Limb(Word::MIN.wrapping_sub(modulus_lo.inv_mod2k(Word::BITS).limbs[0].0));
+        // A surrogate value of `1` is used for an even modulus, which will be rejected below.
+        let modulus_lo = Limb::conditional_select(&Limb::ONE, &modulus.limbs[0], is_odd);
+        let mod_neg_inv = Limb::montgomery_inv(modulus_lo);
         let r3 = montgomery_reduction_boxed(&mut r2.square(), &modulus, mod_neg_inv);
 
         let params = Self {
@@ -119,7 +149,7 @@ impl BoxedResidueParams {
             mod_neg_inv,
         };
 
-        CtOption::new(params, is_odd)
+        CtOption::new(params, is_odd & is_valid)
     }
 
     /// Modulus value.
@@ -131,6 +161,69 @@ impl BoxedResidueParams {
     pub fn bits_precision(&self) -> u32 {
         self.modulus.bits_precision()
     }
+
+    /// Converts `x` into Montgomery form, i.e. computes `x * R mod modulus`.
+    ///
+    /// This is the transform performed by [`BoxedResidue::new`], operating on a raw [`BoxedUint`].
+    pub fn to_montgomery(&self, x: &BoxedUint) -> BoxedUint {
+        debug_assert_eq!(x.bits_precision(), self.bits_precision());
+
+        let mut product = x.mul(&self.r2);
+        let mut ret = BoxedUint::zero_with_precision(self.bits_precision());
+        montgomery_reduction_boxed_mut(&mut product, &self.modulus, self.mod_neg_inv, &mut ret);
+
+        #[cfg(feature = "zeroize")]
+        product.zeroize();
+
+        ret
+    }
+
+    /// Converts `x` out of Montgomery form, i.e. computes `x * R^-1 mod modulus`.
+    ///
+    /// This is the transform performed by [`BoxedResidue::retrieve`], operating on a raw
+    /// [`BoxedUint`].
+    pub fn from_montgomery(&self, x: &BoxedUint) -> BoxedUint {
+        debug_assert_eq!(x.bits_precision(), self.bits_precision());
+
+        let mut wide = x.widen(self.bits_precision() * 2);
+        let mut ret = BoxedUint::zero_with_precision(self.bits_precision());
+        montgomery_reduction_boxed_mut(&mut wide, &self.modulus, self.mod_neg_inv, &mut ret);
+
+        #[cfg(feature = "zeroize")]
+        wide.zeroize();
+
+        ret
+    }
+
+    /// Performs Montgomery reduction of the double-width value `wide`, returning the
+    /// [`BoxedResidue`] whose Montgomery form is `wide * R^-1 mod modulus`.
+    ///
+    /// In particular, reducing the product of the Montgomery forms of two residues (e.g. computed
+    /// using [`BoxedUint::mul`]) yields their product as a residue. `wide` must be less than
+    /// `modulus * R`, which always holds for such a product.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `wide` doesn't have exactly twice as many limbs as the modulus.
+    pub fn reduce_wide(&self, wide: &BoxedUint) -> BoxedResidue {
+        assert_eq!(
+            wide.nlimbs(),
+            self.modulus.nlimbs() * 2,
+            "input to reduce_wide must have twice the precision of the modulus"
+        );
+
+        let mut wide = wide.clone();
+        let montgomery_form =
+            montgomery_reduction_boxed(&mut wide, &self.modulus, self.mod_neg_inv);
+
+        #[cfg(feature = "zeroize")]
+        wide.zeroize();
+
+        BoxedResidue {
+            montgomery_form,
+            residue_params: self.clone().into(),
+        }
+    }
 }
 
 /// A residue represented using heap-allocated limbs.
@@ -151,22 +244,9 @@ pub struct BoxedResidue {
 
 impl BoxedResidue {
     /// Instantiates a new [`BoxedResidue`] that represents an integer modulo the provided params.
-    pub fn new(mut integer: BoxedUint, residue_params: BoxedResidueParams) -> Self {
-        debug_assert_eq!(integer.bits_precision(), residue_params.bits_precision());
-
-        let mut product = integer.mul(&residue_params.r2);
-        montgomery_reduction_boxed_mut(
-            &mut product,
-            &residue_params.modulus,
-            residue_params.mod_neg_inv,
-            &mut integer,
-        );
-
-        #[cfg(feature = "zeroize")]
-        product.zeroize();
-
+    pub fn new(integer: BoxedUint, residue_params: BoxedResidueParams) -> Self {
         Self {
-            montgomery_form: integer,
+            montgomery_form: residue_params.to_montgomery(&integer),
             residue_params: residue_params.into(),
         }
     }
@@ -178,18 +258,30 @@ impl BoxedResidue {
 
     /// Retrieves the integer currently encoded in this [`BoxedResidue`], guaranteed to be reduced.
     pub fn retrieve(&self) -> BoxedUint {
+        self.retrieve_with_reduced().0
+    }
+
+    /// Retrieves the integer currently encoded in this [`BoxedResidue`], guaranteed to be reduced,
+    /// along with the truthy value if the final subtraction of the Montgomery reduction was
+    /// performed.
+    ///
+    /// The subtraction is only needed when the Montgomery form is not fully reduced, e.g. after
+    /// [`BoxedResidue::mul_lazy`], so this can be used to check invariants in tests.
+    pub fn retrieve_with_reduced(&self) -> (BoxedUint, Choice) {
         let mut montgomery_form = self.montgomery_form.widen(self.bits_precision() * 2);
+        let mut ret = BoxedUint::zero_with_precision(self.bits_precision());
 
-        let ret = montgomery_reduction_boxed(
+        let reduced = montgomery_reduction_boxed_mut(
             &mut montgomery_form,
             &self.residue_params.modulus,
             self.residue_params.mod_neg_inv,
+            &mut ret,
         );
 
         #[cfg(feature = "zeroize")]
         montgomery_form.zeroize();
 
-        ret
+        (ret, reduced)
     }
 
     /// Instantiates a new `Residue` that represents zero.
@@ -231,6 +323,38 @@ impl BoxedResidue {
     pub fn to_montgomery(&self) -> BoxedUint {
         self.montgomery_form.clone()
     }
+
+    /// Conditionally select `a` or `b` in constant time depending on [`Choice`].
+    ///
+    /// NOTE: can't impl `subtle`'s [`ConditionallySelectable`] trait due to its `Copy` bound, so
+    /// this is an inherent function instead.
+    ///
+    /// `a` and `b` must have the same residue params, which is checked in debug builds. The
+    /// params of the result are those of `a`.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        debug_assert_eq!(a.residue_params, b.residue_params);
+        Self {
+            montgomery_form: BoxedUint::conditional_select(
+                &a.montgomery_form,
+                &b.montgomery_form,
+                choice,
+            ),
+            residue_params: a.residue_params.clone(),
+        }
+    }
+
+    /// Conditionally assign `other` to `self`, according to `choice`.
+    ///
+    /// NOTE: can't impl `subtle`'s [`ConditionallySelectable`] trait due to its `Copy` bound, so
+    /// this is an inherent function instead.
+    ///
+    /// `self` and `other` must have the same residue params, which is checked in debug builds.
+    #[inline]
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        debug_assert_eq!(self.residue_params, other.residue_params);
+        self.montgomery_form
+            .conditional_assign(&other.montgomery_form, choice);
+    }
 }
 
 impl Retrieve for BoxedResidue {
@@ -240,9 +364,21 @@ impl Retrieve for BoxedResidue {
     }
 }
 
+/// NOTE: this does _not_ zeroize the parameters, in order to maintain some form of type consistency
+#[cfg(feature = "zeroize")]
+impl Zeroize for BoxedResidue {
+    fn zeroize(&mut self) {
+        self.montgomery_form.zeroize()
+    }
+}
+
+/// The Montgomery form is a [`BoxedUint`], which zeroizes itself on drop.
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for BoxedResidue {}
+
 #[cfg(test)]
 mod tests {
-    use super::{BoxedResidueParams, BoxedUint};
+    use super::{BoxedResidue, BoxedResidueParams, BoxedUint, NonZero};
 
     #[test]
     fn new_params_with_invalid_modulus() {
@@ -259,4 +395,182 @@ mod tests {
     fn new_params_with_valid_modulus() {
         BoxedResidueParams::new(BoxedUint::from(3u8)).unwrap();
     }
+
+    #[test]
+    fn new_checked_with_invalid_modulus() {
+        for modulus in [
+            BoxedUint::zero(),
+            BoxedUint::one(),
+            BoxedUint::from(2u8),
+            BoxedUint::one_with_precision(256),
+            BoxedUint::max(256).wrapping_sub(&BoxedUint::one()),
+        ] {
+            let ret = BoxedResidueParams::new_checked(&modulus);
+            assert!(bool::from(ret.is_none()));
+        }
+    }
+
+    #[test]
+    fn new_checked_with_valid_modulus() {
+        for modulus in [BoxedUint::from(3u8), BoxedUint::max(256)] {
+            let params = BoxedResidueParams::new_checked(&modulus).unwrap();
+            assert_eq!(params, BoxedResidueParams::new(modulus.clone()).unwrap());
+
+            // The parameters can be used for arithmetic
+            let x = BoxedResidue::new(modulus.wrapping_sub(&BoxedUint::one()), params);
+            assert_eq!(
+                x.square().retrieve(),
+                BoxedUint::one_with_precision(modulus.bits_precision())
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn ring_axioms() {
+        use hex_literal::hex;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let modulus = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            256,
+        )
+        .unwrap();
+        let params = BoxedResidueParams::new(modulus).unwrap();
+        let zero = BoxedResidue::zero(params.clone());
+        let one = BoxedResidue::one(params.clone());
+
+        for _ in 0..16 {
+            let a = BoxedResidue::new(BoxedUint::random(&mut rng, 256), params.clone());
+            let b = BoxedResidue::new(BoxedUint::random(&mut rng, 256), params.clone());
+            let c = BoxedResidue::new(BoxedUint::random(&mut rng, 256), params.clone());
+
+            assert_eq!((&a + &zero).retrieve(), a.retrieve());
+            assert_eq!((&a * &one).retrieve(), a.retrieve());
+            assert_eq!((&a + &-&a).retrieve(), zero.retrieve());
+            assert_eq!((&a - &b).retrieve(), (&a + &-&b).retrieve());
+            assert_eq!((&a * &b).retrieve(), (&b * &a).retrieve());
+            assert_eq!((&a * &(&b + &c)).retrieve(), (&a * &b + &a * &c).retrieve());
+            assert_eq!(((&a * &b) * &c).retrieve(), (&a * &(&b * &c)).retrieve());
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn conditional_select() {
+        use hex_literal::hex;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+        use subtle::Choice;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let modulus = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            256,
+        )
+        .unwrap();
+        let params = BoxedResidueParams::new(modulus).unwrap();
+
+        for _ in 0..16 {
+            let a = BoxedResidue::new(BoxedUint::random(&mut rng, 256), params.clone());
+            let b = BoxedResidue::new(BoxedUint::random(&mut rng, 256), params.clone());
+
+            assert_eq!(BoxedResidue::conditional_select(&a, &b, Choice::from(0)), a);
+            assert_eq!(BoxedResidue::conditional_select(&a, &b, Choice::from(1)), b);
+
+            let mut c = a.clone();
+            c.conditional_assign(&b, Choice::from(0));
+            assert_eq!(c, a);
+            c.conditional_assign(&b, Choice::from(1));
+            assert_eq!(c, b);
+        }
+    }
+
+    #[test]
+    fn retrieve_with_reduced() {
+        let modulus = BoxedUint::from(0xffff_fffbu32).widen(128);
+        let params = BoxedResidueParams::new(modulus.clone()).unwrap();
+
+        // Construction fully reduces the Montgomery form, even for inputs exceeding the modulus
+        let a = BoxedResidue::new(modulus.wrapping_add(&BoxedUint::from(5u8)), params.clone());
+        let (retrieved, reduced) = a.retrieve_with_reduced();
+        assert_eq!(retrieved, BoxedUint::from(5u8).widen(128));
+        assert!(!bool::from(reduced));
+        assert_eq!(a.retrieve(), retrieved);
+
+        // An unreduced Montgomery form needs the final subtraction
+        let b = BoxedResidue::from_montgomery(modulus, params);
+        let (retrieved, reduced) = b.retrieve_with_reduced();
+        assert_eq!(retrieved, BoxedUint::zero_with_precision(128));
+        assert!(bool::from(reduced));
+        assert_eq!(b.retrieve(), retrieved);
+    }
+
+    #[test]
+    fn montgomery_conversions() {
+        let modulus = BoxedUint::from(0xffff_fffbu32).widen(128);
+        let params = BoxedResidueParams::new(modulus.clone()).unwrap();
+
+        for x in [
+            BoxedUint::zero_with_precision(128),
+            BoxedUint::from(5u8).widen(128),
+            modulus.wrapping_sub(&BoxedUint::one()),
+            modulus.wrapping_add(&BoxedUint::from(5u8)),
+            BoxedUint::max(128),
+        ] {
+            let montgomery_form = params.to_montgomery(&x);
+            let residue = BoxedResidue::new(x.clone(), params.clone());
+            assert_eq!(&montgomery_form, residue.as_montgomery());
+            assert_eq!(params.from_montgomery(&montgomery_form), residue.retrieve());
+            assert_eq!(
+                params.from_montgomery(&montgomery_form),
+                x.rem(&NonZero::new(modulus.clone()).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn reduce_wide() {
+        let params = BoxedResidueParams::new(BoxedUint::from(0xffff_fffbu32).widen(128)).unwrap();
+        let a = BoxedResidue::new(BoxedUint::from(0x1234_5678u32).widen(128), params.clone());
+        let b = BoxedResidue::new(BoxedUint::from(0x9abc_def0u32).widen(128), params.clone());
+
+        let product = a.as_montgomery().mul(b.as_montgomery());
+        assert_eq!(
+            params.reduce_wide(&product).retrieve(),
+            (&a * &b).retrieve()
+        );
+
+        // With one factor not in Montgomery form, `R` cancels and the plain product is obtained
+        let product = a.as_montgomery().mul(&b.retrieve());
+        assert_eq!(
+            params.reduce_wide(&product).as_montgomery(),
+            &(&a * &b).retrieve()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "twice the precision")]
+    fn reduce_wide_wrong_precision() {
+        let params = BoxedResidueParams::new(BoxedUint::from(0xffff_fffbu32).widen(128)).unwrap();
+        params.reduce_wide(&BoxedUint::one_with_precision(128));
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::{Zeroize, ZeroizeOnDrop};
+
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<BoxedResidue>();
+
+        let params = BoxedResidueParams::new(BoxedUint::from(0xffff_fffbu32).widen(128)).unwrap();
+        let mut a = BoxedResidue::new(BoxedUint::from(0x1234_5678u32).widen(128), params.clone());
+        a.zeroize();
+
+        assert_eq!(a.as_montgomery(), &BoxedUint::zero_with_precision(128));
+        assert_eq!(a.params(), &params);
+    }
 }
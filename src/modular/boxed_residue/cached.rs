@@ -0,0 +1,97 @@
+//! Memoized exponentiation of [`BoxedResidue`]s.
+
+use super::BoxedResidue;
+use crate::BoxedUint;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// A [`BoxedResidue`] base which caches the results of raising it to each exponent, for protocols
+/// which repeatedly perform the same exponentiations.
+///
+/// Results are keyed by the full value of the exponent, so exponents which only differ in their
+/// precision share an entry.
+///
+/// NOTE: whether an exponent has been seen before is leaked in the time pattern, so this must not
+/// be used with secret exponents.
+#[derive(Clone, Debug)]
+pub struct CachedResidue {
+    /// Base of the exponentiations.
+    base: BoxedResidue,
+
+    /// Results of `base.pow(exponent)`, keyed by the minimal big endian encoding of `exponent`.
+    cache: BTreeMap<Vec<u8>, BoxedResidue>,
+}
+
+impl CachedResidue {
+    /// Wraps `base` with an empty cache.
+    pub fn new(base: BoxedResidue) -> Self {
+        Self {
+            base,
+            cache: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the base of the exponentiations.
+    pub fn base(&self) -> &BoxedResidue {
+        &self.base
+    }
+
+    /// Raises the base to the `exponent` power, computing it with [`BoxedResidue::pow`] only if
+    /// it isn't already cached.
+    pub fn pow(&mut self, exponent: &BoxedUint) -> BoxedResidue {
+        let base = &self.base;
+        self.cache
+            .entry(exponent.to_be_bytes_trimmed())
+            .or_insert_with(|| base.pow(exponent))
+            .clone()
+    }
+}
+
+impl From<BoxedResidue> for CachedResidue {
+    fn from(base: BoxedResidue) -> Self {
+        Self::new(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedResidue;
+    use crate::{
+        modular::{BoxedResidue, BoxedResidueParams},
+        BoxedUint,
+    };
+
+    fn base() -> BoxedResidue {
+        let params = BoxedResidueParams::new(BoxedUint::from(0xffff_ffff_ffff_ffc5u64)).unwrap();
+        BoxedResidue::new(BoxedUint::from(0xdead_beefu32), params)
+    }
+
+    #[test]
+    fn pow_matches_uncached() {
+        let mut cached = CachedResidue::new(base());
+        let exponent = BoxedUint::from(0x1234_5678_9abcu64);
+        let expected = base().pow(&exponent);
+
+        assert_eq!(cached.pow(&exponent), expected);
+        assert_eq!(cached.pow(&exponent), expected);
+        assert_eq!(cached.cache.len(), 1);
+        assert_eq!(cached.base(), &base());
+    }
+
+    #[test]
+    fn pow_keyed_by_value() {
+        let mut cached = CachedResidue::from(base());
+        let exponent = BoxedUint::from(65537u32);
+        let result = cached.pow(&exponent);
+
+        // The same value with a different precision is a cache hit...
+        assert_eq!(cached.pow(&exponent.widen(256)), result);
+        assert_eq!(cached.cache.len(), 1);
+
+        // ...while distinct values, including zero, get their own entries
+        let zero = BoxedUint::zero();
+        assert_eq!(cached.pow(&zero), base().pow(&zero));
+        assert_eq!(cached.pow(&BoxedUint::one()), base());
+        assert_eq!(cached.cache.len(), 3);
+        assert_eq!(cached.pow(&exponent), result);
+    }
+}
@@ -0,0 +1,323 @@
+//! Reusable modular exponentiation context for [`BoxedResidue`].
+
+use super::{mul::MontgomeryMultiplier, pow::PowerTable, BoxedResidue, BoxedResidueParams};
+use crate::BoxedUint;
+use core::fmt;
+
+/// Montgomery exponentiation engine for [`BoxedResidue`]s which share the same parameters.
+///
+/// [`BoxedResidue::pow`] allocates a table of precomputed powers and a scratch buffer on every
+/// call. This engine instead keeps them around between calls, only growing them when a larger
+/// window or additional bases are needed, and selects the window size for each exponentiation
+/// based on the precision of the exponent.
+pub struct BoxedMontgomeryEngine {
+    /// Residue parameters.
+    residue_params: BoxedResidueParams,
+
+    /// Double-width buffer used by the Montgomery multiplier.
+    product: BoxedUint,
+
+    /// Precomputed powers of the bases and the window size.
+    table: PowerTable,
+}
+
+impl BoxedMontgomeryEngine {
+    /// Create a new exponentiation engine for residues with the given parameters.
+    pub fn new(residue_params: BoxedResidueParams) -> Self {
+        let bits_precision = residue_params.bits_precision();
+
+        Self {
+            residue_params,
+            product: BoxedUint::zero_with_precision(bits_precision * 2),
+            table: PowerTable::new(bits_precision),
+        }
+    }
+
+    /// Returns the parameter struct used to initialize this engine.
+    pub fn params(&self) -> &BoxedResidueParams {
+        &self.residue_params
+    }
+
+    /// Raises `base` to the `exponent` power.
+    ///
+    /// NOTE: the precision of `exponent` may be leaked in the time pattern.
+    pub fn pow(&mut self, base: &BoxedResidue, exponent: &BoxedUint) -> BoxedResidue {
+        debug_assert_eq!(base.params(), &self.residue_params);
+
+        let montgomery_form = self.pow_montgomery_form(1, |_| (&base.montgomery_form, exponent));
+
+        BoxedResidue {
+            montgomery_form,
+            residue_params: base.residue_params.clone(),
+        }
+    }
+
+    /// Raises `base` to the `exponent` power like [`BoxedMontgomeryEngine::pow`], but writes the
+    /// result to `out` rather than allocating a new [`BoxedResidue`].
+    ///
+    /// `out` must have the same parameters as this engine, e.g. it can be the result of a previous
+    /// call. Once the engine's buffers have grown to fit the largest window, repeated calls don't
+    /// allocate at all.
+    ///
+    /// NOTE: the precision of `exponent` may be leaked in the time pattern.
+    pub fn pow_into(&mut self, base: &BoxedResidue, exponent: &BoxedUint, out: &mut BoxedResidue) {
+        debug_assert_eq!(base.params(), &self.residue_params);
+        debug_assert_eq!(out.params(), &self.residue_params);
+
+        self.pow_montgomery_form_into(
+            1,
+            |_| (&base.montgomery_form, exponent),
+            &mut out.montgomery_form,
+        );
+    }
+
+    /// Computes the product of each base raised to its respective exponent, sharing the squarings
+    /// between all of the terms.
+    ///
+    /// Returns one if `bases_and_exponents` is empty.
+    ///
+    /// NOTE: the number of terms and the largest precision of the exponents may be leaked in the
+    /// time pattern.
+    pub fn pow_product(
+        &mut self,
+        bases_and_exponents: &[(BoxedResidue, BoxedUint)],
+    ) -> BoxedResidue {
+        debug_assert!(bases_and_exponents
+            .iter()
+            .all(|(base, _)| base.params() == &self.residue_params));
+
+        let montgomery_form = self.pow_montgomery_form(bases_and_exponents.len(), |i| {
+            let (base, exponent) = &bases_and_exponents[i];
+            (&base.montgomery_form, exponent)
+        });
+
+        BoxedResidue {
+            montgomery_form,
+            residue_params: self.residue_params.clone().into(),
+        }
+    }
+
+    /// Computes the (reduced) square of `x`.
+    pub fn square(&mut self, x: &BoxedResidue) -> BoxedResidue {
+        debug_assert_eq!(x.params(), &self.residue_params);
+
+        let mut montgomery_form = x.montgomery_form.clone();
+        let mut multiplier = MontgomeryMultiplier::with_buffer(
+            core::mem::take(&mut self.product),
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        );
+        multiplier.square_assign(&mut montgomery_form);
+        self.product = multiplier.into_buffer();
+
+        BoxedResidue {
+            montgomery_form,
+            residue_params: x.residue_params.clone(),
+        }
+    }
+
+    /// Fixed-window multi-exponentiation in Montgomery form, where `term(i)` returns the `i`-th
+    /// base and exponent.
+    fn pow_montgomery_form<'b, F>(&mut self, nterms: usize, term: F) -> BoxedUint
+    where
+        F: Fn(usize) -> (&'b BoxedUint, &'b BoxedUint),
+    {
+        let mut z = BoxedUint::zero_with_precision(self.residue_params.bits_precision());
+        self.pow_montgomery_form_into(nterms, term, &mut z);
+        z
+    }
+
+    /// Like [`Self::pow_montgomery_form`], but writes the result to `z`, which must have the
+    /// precision of the modulus.
+    fn pow_montgomery_form_into<'b, F>(&mut self, nterms: usize, term: F, z: &mut BoxedUint)
+    where
+        F: Fn(usize) -> (&'b BoxedUint, &'b BoxedUint),
+    {
+        debug_assert_eq!(z.bits_precision(), self.residue_params.bits_precision());
+
+        let exponent_bits = (0..nterms)
+            .map(|i| term(i).1.bits_precision())
+            .max()
+            .unwrap_or(0);
+
+        let residue_params = &self.residue_params;
+        let mut multiplier = MontgomeryMultiplier::with_buffer(
+            core::mem::take(&mut self.product),
+            &residue_params.modulus,
+            residue_params.mod_neg_inv,
+        );
+
+        self.table.pow_into(
+            &mut multiplier,
+            &residue_params.r,
+            exponent_bits,
+            nterms,
+            |i| {
+                let (base, exponent) = term(i);
+                (base, exponent.as_limbs())
+            },
+            z,
+        );
+
+        self.product = multiplier.into_buffer();
+    }
+}
+
+impl fmt::Debug for BoxedMontgomeryEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Omit the scratch buffers, which may contain powers of secret bases
+        f.debug_struct("BoxedMontgomeryEngine")
+            .field("residue_params", &self.residue_params)
+            .field("window", &self.table.window)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedMontgomeryEngine;
+    use crate::{
+        modular::{BoxedResidue, BoxedResidueParams},
+        BoxedUint,
+    };
+    use hex_literal::hex;
+
+    fn params() -> BoxedResidueParams {
+        let modulus = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            256,
+        )
+        .unwrap();
+        BoxedResidueParams::new(modulus).unwrap()
+    }
+
+    #[test]
+    fn pow_matches_boxed_residue() {
+        let params = params();
+        let mut engine = BoxedMontgomeryEngine::new(params.clone());
+        let base = BoxedResidue::new(BoxedUint::from(0x1234_5678u32).widen(256), params.clone());
+
+        // Repeated calls with both small and large exponents share the engine's buffers
+        for exponent_bits in [64, 256, 4096, 256, 128] {
+            let exponent = BoxedUint::max(exponent_bits).wrapping_sub(&BoxedUint::from(12345u32));
+            assert_eq!(
+                engine.pow(&base, &exponent).retrieve(),
+                base.pow(&exponent).retrieve()
+            );
+        }
+
+        assert_eq!(
+            engine.pow(&base, &BoxedUint::zero()).retrieve(),
+            BoxedUint::one_with_precision(256)
+        );
+    }
+
+    #[test]
+    fn pow_product() {
+        let params = params();
+        let mut engine = BoxedMontgomeryEngine::new(params.clone());
+
+        let a = BoxedResidue::new(BoxedUint::from(3u8).widen(256), params.clone());
+        let b = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params.clone());
+        let e1 = BoxedUint::max(256).wrapping_sub(&BoxedUint::from(7u8));
+        let e2 = BoxedUint::max(128);
+
+        let expected = a.pow(&e1).mul(&b.pow(&e2));
+        let actual = engine.pow_product(&[(a.clone(), e1.clone()), (b.clone(), e2.clone())]);
+        assert_eq!(actual.retrieve(), expected.retrieve());
+
+        // Repeated call after an intervening single-base exponentiation
+        assert_eq!(engine.pow(&a, &e1).retrieve(), a.pow(&e1).retrieve());
+        let actual = engine.pow_product(&[(a, e1), (b, e2)]);
+        assert_eq!(actual.retrieve(), expected.retrieve());
+
+        assert_eq!(
+            engine.pow_product(&[]).retrieve(),
+            BoxedUint::one_with_precision(256)
+        );
+    }
+
+    #[test]
+    fn square() {
+        let params = params();
+        let mut engine = BoxedMontgomeryEngine::new(params.clone());
+        let x = BoxedResidue::new(BoxedUint::max(256).shr_vartime(3), params);
+
+        assert_eq!(engine.square(&x).retrieve(), x.square().retrieve());
+
+        let x2 = engine.square(&x);
+        assert_eq!(
+            engine.square(&x2).retrieve(),
+            x.square().square().retrieve()
+        );
+    }
+
+    #[test]
+    fn reuses_buffers() {
+        let params = params();
+        let mut engine = BoxedMontgomeryEngine::new(params.clone());
+        let base = BoxedResidue::new(BoxedUint::from(5u8).widen(256), params);
+
+        engine.pow(&base, &BoxedUint::max(4096));
+        assert_eq!(engine.table.window, 6);
+        let ptr = engine.table.powers.as_ptr();
+        let len = engine.table.powers.len();
+
+        // A smaller window fits in the existing table
+        engine.pow(&base, &BoxedUint::max(256));
+        assert_eq!(engine.table.window, 4);
+        assert_eq!(engine.table.powers.as_ptr(), ptr);
+        assert_eq!(engine.table.powers.len(), len);
+
+        engine.pow(&base, &BoxedUint::max(4096));
+        assert_eq!(engine.table.powers.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn reuses_buffers_across_bases() {
+        let params = params();
+        let mut engine = BoxedMontgomeryEngine::new(params.clone());
+
+        let mut base = BoxedResidue::new(BoxedUint::from(7u8).widen(256), params);
+        let exponent = BoxedUint::max(256).wrapping_sub(&BoxedUint::from(0xdead_beefu32));
+        let mut ptrs = None;
+
+        for _ in 0..16 {
+            let result = engine.pow(&base, &exponent);
+            assert_eq!(result, base.pow(&exponent));
+
+            // Neither the power table nor the multiplier's buffer are reallocated
+            let current = (
+                engine.table.powers.as_ptr(),
+                engine.product.as_limbs().as_ptr(),
+            );
+            assert_eq!(*ptrs.get_or_insert(current), current);
+
+            base = result;
+        }
+    }
+
+    #[test]
+    fn pow_into() {
+        let params = params();
+        let mut engine = BoxedMontgomeryEngine::new(params.clone());
+
+        let base = BoxedResidue::new(BoxedUint::from(11u8).widen(256), params.clone());
+        let mut out = BoxedResidue::zero(params);
+        let ptr = out.as_montgomery().as_limbs().as_ptr();
+
+        for exponent in [
+            BoxedUint::max(256),
+            BoxedUint::max(64),
+            BoxedUint::zero(),
+            BoxedUint::max(4096).wrapping_sub(&BoxedUint::from(12345u32)),
+        ] {
+            engine.pow_into(&base, &exponent, &mut out);
+            assert_eq!(out, engine.pow(&base, &exponent));
+            assert_eq!(out.retrieve(), base.pow(&exponent).retrieve());
+
+            // The result is written in place
+            assert_eq!(out.as_montgomery().as_limbs().as_ptr(), ptr);
+        }
+    }
+}
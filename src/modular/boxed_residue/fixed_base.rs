@@ -0,0 +1,218 @@
+//! Fixed-base exponentiation of [`BoxedResidue`]s using the comb method.
+
+use super::{mul::MontgomeryMultiplier, BoxedResidue, BoxedResidueParams};
+use crate::{BoxedUint, Limb, Word};
+use alloc::vec::Vec;
+use core::{borrow::Borrow, fmt};
+
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+/// Maximum number of teeth in the comb, i.e. the number of exponent bits consumed at once.
+///
+/// The precomputed table has `1 << COMB_TEETH` entries.
+const COMB_TEETH: u32 = 6;
+
+/// A [`BoxedResidue`] base with precomputed powers, for efficiently raising the same base to many
+/// different exponents.
+///
+/// Constructed using [`BoxedResidue::precompute_base`]. Exponentiation uses the comb method, which
+/// requires roughly `max_exponent_bits / COMB_TEETH` squarings and multiplications per call, as
+/// opposed to `max_exponent_bits` squarings for [`BoxedResidue::pow`].
+#[derive(Clone)]
+pub struct BoxedResidueFixedBase {
+    /// `table[j]` contains the product of `base^(2^(i * spacing))` for every bit `i` set in `j`,
+    /// in Montgomery form.
+    table: Vec<BoxedUint>,
+
+    /// Number of teeth in the comb.
+    teeth: u32,
+
+    /// Distance in bits between adjacent teeth of the comb.
+    spacing: u32,
+
+    /// Residue parameters.
+    #[cfg(not(feature = "std"))]
+    residue_params: BoxedResidueParams,
+
+    /// Residue parameters.
+    // Uses `Arc` when `std` is available.
+    #[cfg(feature = "std")]
+    residue_params: Arc<BoxedResidueParams>,
+}
+
+impl BoxedResidue {
+    /// Precompute a table of powers of `self` which can be used to raise it to exponents of up to
+    /// `max_exponent_bits` bits.
+    pub fn precompute_base(&self, max_exponent_bits: u32) -> BoxedResidueFixedBase {
+        let teeth = COMB_TEETH.min(max_exponent_bits).max(1);
+        let spacing = (max_exponent_bits + teeth - 1) / teeth;
+
+        let mut multiplier = MontgomeryMultiplier::from(self.residue_params.borrow());
+        let mut table = Vec::with_capacity(1 << teeth);
+        table.push(self.residue_params.r.clone()); // 1 in Montgomery form
+
+        // `tooth` contains `base^(2^(i * spacing))`
+        let mut tooth = self.montgomery_form.clone();
+        for i in 0..teeth {
+            if i > 0 {
+                for _ in 0..spacing {
+                    multiplier.square_assign(&mut tooth);
+                }
+            }
+
+            for j in 0..(1 << i) {
+                let entry = multiplier.mul(&table[j], &tooth);
+                table.push(entry);
+            }
+        }
+
+        BoxedResidueFixedBase {
+            table,
+            teeth,
+            spacing,
+            residue_params: self.residue_params.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for BoxedResidueFixedBase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Omit the table, which contains powers of a possibly secret base
+        f.debug_struct("BoxedResidueFixedBase")
+            .field("teeth", &self.teeth)
+            .field("spacing", &self.spacing)
+            .field("residue_params", &self.residue_params)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BoxedResidueFixedBase {
+    /// Raises the precomputed base to the `exponent` power.
+    ///
+    /// `exponent` must have at most the `max_exponent_bits` the table was computed with (rounded
+    /// up to a multiple of the number of teeth). This is checked in debug builds, while in release
+    /// builds any excess bits are ignored.
+    pub fn pow(&self, exponent: &BoxedUint) -> BoxedResidue {
+        let params: &BoxedResidueParams = self.residue_params.borrow();
+        debug_assert!(exponent.bits() <= self.teeth * self.spacing);
+
+        let mut multiplier = MontgomeryMultiplier::from(params);
+        let mut z = params.r.clone(); // 1 in Montgomery form
+        let mut power = BoxedUint::zero_with_precision(params.bits_precision());
+
+        for k in (0..self.spacing).rev() {
+            if k != self.spacing - 1 {
+                multiplier.square_assign(&mut z);
+            }
+
+            let mut idx: Word = 0;
+            for i in 0..self.teeth {
+                idx |= exponent_bit(exponent, i * self.spacing + k) << i;
+            }
+
+            power.ct_select_into(&self.table, idx);
+            multiplier.mul_assign(&mut z, &power);
+        }
+
+        BoxedResidue {
+            montgomery_form: z,
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
+    /// Returns the parameter struct used to initialize the base.
+    pub fn params(&self) -> &BoxedResidueParams {
+        &self.residue_params
+    }
+}
+
+/// Get the bit of `exponent` at position `pos` as a [`Word`], treating bits beyond its precision as
+/// zero.
+fn exponent_bit(exponent: &BoxedUint, pos: u32) -> Word {
+    exponent
+        .limbs
+        .get((pos / Limb::BITS) as usize)
+        .map_or(0, |limb| (limb.0 >> (pos % Limb::BITS)) & 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::{BoxedResidue, BoxedResidueParams},
+        BoxedUint,
+    };
+    use hex_literal::hex;
+
+    fn base() -> BoxedResidue {
+        let modulus = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            256,
+        )
+        .unwrap();
+        let params = BoxedResidueParams::new(modulus).unwrap();
+        BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params)
+    }
+
+    #[test]
+    fn pow_matches_boxed_residue() {
+        let base = base();
+
+        for max_exponent_bits in [1, 5, 64, 255, 256] {
+            let fixed_base = base.precompute_base(max_exponent_bits);
+
+            // `2^max_exponent_bits - 1`
+            let max = BoxedUint::one_with_precision(512)
+                .shl_vartime(max_exponent_bits)
+                .wrapping_sub(&BoxedUint::one())
+                .shorten(256);
+
+            for exponent in [
+                BoxedUint::zero_with_precision(256),
+                BoxedUint::one_with_precision(256),
+                max.shr_vartime(max_exponent_bits / 2),
+                max,
+            ] {
+                assert_eq!(
+                    fixed_base.pow(&exponent).retrieve(),
+                    base.pow(&exponent).retrieve()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pow_repeated() {
+        let base = base();
+        let fixed_base = base.precompute_base(256);
+
+        let mut exponent = BoxedUint::from_be_slice(
+            &hex!("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685"),
+            256,
+        )
+        .unwrap();
+
+        for _ in 0..8 {
+            assert_eq!(
+                fixed_base.pow(&exponent).retrieve(),
+                base.pow(&exponent).retrieve()
+            );
+            exponent = exponent
+                .wrapping_mul(&exponent)
+                .wrapping_add(&BoxedUint::one());
+        }
+    }
+
+    #[test]
+    fn pow_narrow_exponent() {
+        let base = base();
+        let fixed_base = base.precompute_base(256);
+
+        // The exponent may have a smaller precision than `max_exponent_bits`
+        let exponent = BoxedUint::from(0x1234_5678_9abc_def0u64);
+        assert_eq!(
+            fixed_base.pow(&exponent).retrieve(),
+            base.pow(&exponent).retrieve()
+        );
+    }
+}
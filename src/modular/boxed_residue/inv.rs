@@ -1,13 +1,55 @@
 //! Multiplicative inverses of boxed residue.
 
 use super::BoxedResidue;
-use crate::{modular::reduction::montgomery_reduction_boxed_mut, traits::Invert};
-use subtle::CtOption;
+use crate::{modular::reduction::montgomery_reduction_boxed_mut, traits::Invert, BoxedUint};
+use alloc::vec::Vec;
+use subtle::{Choice, CtOption};
 
 impl BoxedResidue {
     /// Computes the residue `self^-1` representing the multiplicative inverse of `self`.
     /// I.e. `self * self^-1 = 1`.
     pub fn invert(&self) -> CtOption<Self> {
+        let (value, is_some) = self.invert_with_choice();
+        CtOption::new(value, is_some)
+    }
+
+    /// Computes the multiplicative inverses of all of the `elements` using Montgomery's trick, i.e.
+    /// with a single inversion and `3 * (elements.len() - 1)` multiplications, returning them in
+    /// the same order.
+    ///
+    /// All of the elements must have the same parameters. Returns `None` if any of them is not
+    /// invertible.
+    pub fn batch_invert(elements: &[Self]) -> CtOption<Vec<Self>> {
+        let Some(first) = elements.first() else {
+            return CtOption::new(Vec::new(), Choice::from(1));
+        };
+
+        // prefix[i] = elements[0] * ... * elements[i]
+        let mut prefix = Vec::with_capacity(elements.len());
+        prefix.push(first.clone());
+        for element in &elements[1..] {
+            debug_assert_eq!(element.residue_params, first.residue_params);
+            let product = prefix[prefix.len() - 1].mul(element);
+            prefix.push(product);
+        }
+
+        // Invert the product of all of the elements, then peel off one element at a time:
+        // (elements[0] * ... * elements[i])^-1 * (elements[0] * ... * elements[i-1]) = elements[i]^-1
+        let (mut inverse, is_some) = prefix[prefix.len() - 1].invert_with_choice();
+        let mut inverses = Vec::with_capacity(elements.len());
+        for i in (1..elements.len()).rev() {
+            inverses.push(inverse.mul(&prefix[i - 1]));
+            inverse = inverse.mul(&elements[i]);
+        }
+        inverses.push(inverse);
+        inverses.reverse();
+
+        CtOption::new(inverses, is_some)
+    }
+
+    /// Computes the inverse of `self`, along with the truthy value if it exists. The first value is
+    /// meaningless otherwise.
+    pub(super) fn invert_with_choice(&self) -> (Self, Choice) {
         let (mut inverse, is_some) = self
             .montgomery_form
             .inv_odd_mod(&self.residue_params.modulus);
@@ -26,7 +68,25 @@ impl BoxedResidue {
             residue_params: self.residue_params.clone(),
         };
 
-        CtOption::new(value, is_some)
+        (value, is_some)
+    }
+
+    /// Computes the multiplicative inverse of `self` like [`BoxedResidue::invert`], or if `self` is
+    /// not invertible, returns `gcd(self, modulus)` as the error.
+    ///
+    /// The returned gcd is a nontrivial factor of the modulus unless `self` is zero, in which case
+    /// it is the modulus itself.
+    ///
+    /// NOTE: computing the inverse is constant-time, but whether it succeeded is leaked by the
+    /// time pattern, and the gcd in the failure case is computed in variable-time.
+    pub fn invert_or_factor(&self) -> Result<Self, BoxedUint> {
+        let inverse = self.invert();
+
+        if inverse.is_some().into() {
+            return Ok(inverse.unwrap());
+        }
+
+        Err(self.residue_params.modulus.gcd_vartime(&self.retrieve()))
     }
 }
 
@@ -36,3 +96,83 @@ impl Invert for BoxedResidue {
         self.invert()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::{BoxedResidue, BoxedResidueParams},
+        BoxedUint, NonZero,
+    };
+    use alloc::vec::Vec;
+
+    /// The primes `2^32 - 5` and `2^32 - 17`.
+    const P: u64 = 0xffff_fffb;
+    const Q: u64 = 0xffff_ffef;
+
+    fn params() -> BoxedResidueParams {
+        BoxedResidueParams::new(BoxedUint::from(P * Q)).unwrap()
+    }
+
+    #[test]
+    fn invert_or_factor_invertible() {
+        let x = BoxedResidue::new(BoxedUint::from(123_456_789u64), params());
+        let inverse = x.invert_or_factor().unwrap();
+
+        assert_eq!(inverse, x.invert().unwrap());
+        assert_eq!(x.mul(&inverse).retrieve(), BoxedUint::one());
+    }
+
+    #[test]
+    fn invert_or_factor_shares_factor() {
+        let modulus = BoxedUint::from(P * Q);
+
+        for (x, factor) in [(3 * P, P), (Q, Q), (P * 12345, P)] {
+            let x = BoxedResidue::new(BoxedUint::from(x), params());
+            let gcd = x.invert_or_factor().unwrap_err();
+
+            assert_eq!(gcd, BoxedUint::from(factor));
+            let gcd = NonZero::new(gcd).unwrap();
+            assert!(bool::from(modulus.rem_vartime(&gcd).is_zero()));
+        }
+    }
+
+    #[test]
+    fn invert_or_factor_zero() {
+        let x = BoxedResidue::zero(params());
+        assert_eq!(x.invert_or_factor().unwrap_err(), BoxedUint::from(P * Q));
+    }
+
+    #[test]
+    fn batch_invert() {
+        let one = BoxedUint::one_with_precision(64);
+        let elements: Vec<_> = [2, 3, 123_456_789, P * Q - 1, 2]
+            .into_iter()
+            .map(|x| BoxedResidue::new(BoxedUint::from(x), params()))
+            .collect();
+
+        let inverses = BoxedResidue::batch_invert(&elements).unwrap();
+        assert_eq!(inverses.len(), elements.len());
+
+        for (element, inverse) in elements.iter().zip(&inverses) {
+            // Multiplication isn't guaranteed to fully reduce, so compare the retrieved values
+            assert_eq!(inverse.retrieve(), element.invert().unwrap().retrieve());
+            assert_eq!(element.mul(inverse).retrieve(), one);
+        }
+
+        let single = BoxedResidue::batch_invert(&elements[..1]).unwrap();
+        assert_eq!(single, [elements[0].invert().unwrap()]);
+        assert!(BoxedResidue::batch_invert(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn batch_invert_not_invertible() {
+        for x in [0, 3 * P] {
+            let elements = [
+                BoxedResidue::new(BoxedUint::from(2u8), params()),
+                BoxedResidue::new(BoxedUint::from(x), params()),
+                BoxedResidue::new(BoxedUint::from(5u8), params()),
+            ];
+            assert!(bool::from(BoxedResidue::batch_invert(&elements).is_none()));
+        }
+    }
+}
@@ -6,11 +6,17 @@
 //! Originally (c) 2014 The Rust Project Developers, dual licensed Apache 2.0+MIT.
 
 use super::{BoxedResidue, BoxedResidueParams};
-use crate::{traits::Square, BoxedUint, Limb, WideWord, Word};
+use crate::{
+    modular::reduction::almost_montgomery_reduction_boxed_mut,
+    traits::Square,
+    uint::mul::{mul_limbs, square_limbs, KARATSUBA_MIN_LIMBS},
+    BoxedUint, Limb, WideWord, Word,
+};
 use core::{
     borrow::Borrow,
     ops::{Mul, MulAssign},
 };
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -29,6 +35,34 @@ impl BoxedResidue {
         }
     }
 
+    /// Multiplies by `rhs` using lazy reduction, skipping the final conditional subtraction of the
+    /// modulus.
+    ///
+    /// The modulus must be less than a quarter of `2^bits_precision`, i.e. its two most
+    /// significant bits must be zero. Given operands whose Montgomery forms are in `[0, 2*modulus)`
+    /// the result is also in `[0, 2*modulus)`, so lazy multiplications can be chained, followed by
+    /// a single [`BoxedResidue::normalize`].
+    ///
+    /// Note that other operations such as addition and subtraction require normalized operands.
+    pub fn mul_lazy(&self, rhs: &Self) -> Self {
+        debug_assert_eq!(&self.residue_params, &rhs.residue_params);
+
+        let montgomery_form = MontgomeryMultiplier::from(self.residue_params.borrow())
+            .mul_lazy(&self.montgomery_form, &rhs.montgomery_form);
+
+        Self {
+            montgomery_form,
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
+    /// Brings the Montgomery form of a residue in `[0, 2*modulus)`, such as the output of
+    /// [`BoxedResidue::mul_lazy`], into `[0, modulus)`.
+    pub fn normalize(&mut self) {
+        MontgomeryMultiplier::from(self.residue_params.borrow())
+            .normalize(&mut self.montgomery_form);
+    }
+
     /// Computes the (reduced) square of a residue.
     pub fn square(&self) -> Self {
         let montgomery_form =
@@ -39,8 +73,69 @@ impl BoxedResidue {
             residue_params: self.residue_params.clone(),
         }
     }
+
+    /// Multiplies by the single-word `scalar`, e.g. a small cofactor.
+    ///
+    /// Multiplying the Montgomery form by `scalar` directly gives the Montgomery form of the
+    /// product, so this only needs a single-limb multiplication and reduction (see
+    /// [`BoxedUint::mul_limb_mod`]), or for tiny scalars just repeated modular additions, rather
+    /// than a full Montgomery multiplication.
+    ///
+    /// This is variable-time with respect to `scalar`, which is assumed to be public.
+    pub fn mul_scalar(&self, scalar: Word) -> Self {
+        let modulus = &self.residue_params.modulus;
+
+        let montgomery_form = if scalar <= MUL_SCALAR_MAX_ADDITIONS {
+            let mut acc = BoxedUint::zero_with_precision(modulus.bits_precision());
+            for _ in 0..scalar {
+                acc = acc.add_mod(&self.montgomery_form, modulus);
+            }
+            acc
+        } else {
+            self.montgomery_form.mul_limb_mod(Limb(scalar), modulus)
+        };
+
+        Self {
+            montgomery_form,
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
+    /// Computes the product of all of the `residues`, which is [`BoxedResidue::one`] for an empty
+    /// slice.
+    ///
+    /// This reuses a single multiplication buffer for the whole product, and like
+    /// [`BoxedResidue::mul`] the intermediate Montgomery forms are only almost reduced, with
+    /// the full reduction deferred until the result is retrieved. The result is the same as
+    /// folding the residues using [`BoxedResidue::mul`].
+    ///
+    /// Returns `None` if any of the residues has parameters other than `residue_params`.
+    pub fn product(residue_params: &BoxedResidueParams, residues: &[Self]) -> CtOption<Self> {
+        let mut multiplier = MontgomeryMultiplier::from(residue_params);
+        let mut montgomery_form = residue_params.r.clone();
+        let mut is_some = Choice::from(1);
+
+        for residue in residues {
+            // Residues with other parameters may have a different precision, so they're skipped
+            // rather than multiplied. The parameters are public, so this doesn't leak secrets.
+            let same_params = residue.params() == residue_params;
+            is_some &= Choice::from(u8::from(same_params));
+            if same_params {
+                multiplier.mul_assign(&mut montgomery_form, &residue.montgomery_form);
+            }
+        }
+
+        let product = Self {
+            montgomery_form,
+            residue_params: residue_params.clone().into(),
+        };
+        CtOption::new(product, is_some)
+    }
 }
 
+/// Largest scalar for which [`BoxedResidue::mul_scalar`] uses repeated additions.
+const MUL_SCALAR_MAX_ADDITIONS: Word = 4;
+
 impl Mul<&BoxedResidue> for &BoxedResidue {
     type Output = BoxedResidue;
     fn mul(self, rhs: &BoxedResidue) -> BoxedResidue {
@@ -114,6 +209,27 @@ impl<'a> MontgomeryMultiplier<'a> {
         }
     }
 
+    /// Create a new Montgomery multiplier which reuses a previously allocated `product` buffer,
+    /// which must have twice the precision of `modulus`.
+    pub(super) fn with_buffer(
+        product: BoxedUint,
+        modulus: &'a BoxedUint,
+        mod_neg_inv: Limb,
+    ) -> Self {
+        debug_assert_eq!(product.bits_precision(), modulus.bits_precision() * 2);
+
+        Self {
+            product,
+            modulus,
+            mod_neg_inv,
+        }
+    }
+
+    /// Consume this multiplier, returning its internal product buffer so it can be reused.
+    pub(super) fn into_buffer(mut self) -> BoxedUint {
+        core::mem::take(&mut self.product)
+    }
+
     /// Perform an "Almost Montgomery Multiplication".
     pub(super) fn mul(&mut self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
         let mut ret = a.clone();
@@ -122,11 +238,28 @@ impl<'a> MontgomeryMultiplier<'a> {
     }
 
     /// Perform an "Almost Montgomery Multiplication", assigning the product to `a`.
+    ///
+    /// Multiplication and reduction are interleaved for small operands. Large ones instead
+    /// compute the full product first so it can use Karatsuba multiplication, and then reduce it,
+    /// which gives an identical result.
+    #[inline]
     pub(super) fn mul_assign(&mut self, a: &mut BoxedUint, b: &BoxedUint) {
         debug_assert_eq!(a.bits_precision(), self.modulus.bits_precision());
         debug_assert_eq!(b.bits_precision(), self.modulus.bits_precision());
 
         self.clear_product();
+
+        if a.limbs.len() >= KARATSUBA_MIN_LIMBS {
+            mul_limbs(&a.limbs, &b.limbs, &mut self.product.limbs);
+            almost_montgomery_reduction_boxed_mut(
+                &mut self.product,
+                self.modulus,
+                self.mod_neg_inv,
+                a,
+            );
+            return;
+        }
+
         montgomery_mul(
             self.product.as_words_mut(),
             a.as_words(),
@@ -138,30 +271,73 @@ impl<'a> MontgomeryMultiplier<'a> {
             .copy_from_slice(&self.product.limbs[..a.limbs.len()]);
     }
 
-    /// Perform a squaring "Almost Montgomery Multiplication".
-    pub(super) fn square(&mut self, a: &BoxedUint) -> BoxedUint {
+    /// Perform a Montgomery multiplication without the final conditional subtraction.
+    ///
+    /// Requires `4 * modulus < 2^bits_precision`. If `a` and `b` are in `[0, 2*modulus)`, then so
+    /// is the result, which can be brought into `[0, modulus)` using [`Self::normalize`].
+    pub(super) fn mul_lazy(&mut self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
         let mut ret = a.clone();
-        self.square_assign(&mut ret);
+        self.mul_lazy_assign(&mut ret, b);
         ret
     }
 
-    /// Perform a squaring using "Almost Montgomery Multiplication"
-    pub(super) fn square_assign(&mut self, a: &mut BoxedUint) {
+    /// Perform a Montgomery multiplication without the final conditional subtraction, assigning
+    /// the product to `a`.
+    ///
+    /// See [`Self::mul_lazy`] for the input and output ranges.
+    #[inline]
+    pub(super) fn mul_lazy_assign(&mut self, a: &mut BoxedUint, b: &BoxedUint) {
         debug_assert_eq!(a.bits_precision(), self.modulus.bits_precision());
+        debug_assert_eq!(b.bits_precision(), self.modulus.bits_precision());
+        debug_assert!(self.modulus.bits() <= self.modulus.bits_precision() - 2);
 
         self.clear_product();
-        montgomery_mul(
+        let carry = montgomery_mul_lazy(
             self.product.as_words_mut(),
             a.as_words(),
-            a.as_words(),
+            b.as_words(),
             self.modulus.as_words(),
             self.mod_neg_inv.into(),
         );
+        debug_assert_eq!(carry, 0);
+
         a.limbs
-            .copy_from_slice(&self.product.limbs[..a.limbs.len()]);
+            .copy_from_slice(&self.product.limbs[a.limbs.len()..]);
+    }
+
+    /// Reduce `x` in `[0, 2*modulus)` into `[0, modulus)` by conditionally subtracting the
+    /// modulus in constant time.
+    pub(super) fn normalize(&self, x: &mut BoxedUint) {
+        debug_assert_eq!(x.bits_precision(), self.modulus.bits_precision());
+
+        let (diff, borrow) = x.sbb(self.modulus, Limb::ZERO);
+        x.conditional_assign(&diff, borrow.ct_eq(&Limb::ZERO));
+    }
+
+    /// Perform a squaring "Almost Montgomery Multiplication".
+    pub(super) fn square(&mut self, a: &BoxedUint) -> BoxedUint {
+        let mut ret = a.clone();
+        self.square_assign(&mut ret);
+        ret
+    }
+
+    /// Perform a squaring using "Almost Montgomery Multiplication".
+    ///
+    /// Rather than interleaving multiplication and reduction like [`Self::mul_assign`] does for
+    /// small operands, this computes the full square first so it can exploit the symmetry
+    /// `a_i * a_j == a_j * a_i`, roughly halving the number of partial products. The result is
+    /// identical to `self.mul_assign(a, a)`.
+    #[inline]
+    pub(super) fn square_assign(&mut self, a: &mut BoxedUint) {
+        debug_assert_eq!(a.bits_precision(), self.modulus.bits_precision());
+
+        self.clear_product();
+        square_limbs(&a.limbs, &mut self.product.limbs);
+        almost_montgomery_reduction_boxed_mut(&mut self.product, self.modulus, self.mod_neg_inv, a);
     }
 
     /// Clear the internal product buffer.
+    #[inline]
     fn clear_product(&mut self) {
         self.product
             .limbs
@@ -193,6 +369,25 @@ pub(crate) fn montgomery_mul(z: &mut [Word], x: &[Word], y: &[Word], m: &[Word],
     // This code assumes x, y, m are all the same length (required by addMulVVW and the for loop).
     // It also assumes that x, y are already reduced mod m, or else the result will not be properly
     // reduced.
+    let c = montgomery_mul_lazy(z, x, y, m, k);
+    let n = m.len();
+
+    // Compute the difference unconditionally, then select it in constant time if there was a
+    // carry, i.e. `mask` is all ones
+    let (first, second) = z.split_at_mut(n);
+    sub_vv(first, second, m);
+    let mask = c.wrapping_neg();
+    for (diff, &upper) in first.iter_mut().zip(second.iter()) {
+        *diff = upper ^ (mask & (*diff ^ upper));
+    }
+}
+
+/// Compute the interleaved Montgomery multiplication and reduction of `x` and `y` into the upper
+/// half of `z`, without any final subtraction of the modulus.
+///
+/// Returns the carry out of the most significant limb, which is either 0 or 1.
+#[cfg(feature = "alloc")]
+fn montgomery_mul_lazy(z: &mut [Word], x: &[Word], y: &[Word], m: &[Word], k: Word) -> Word {
     let mut c: Word = 0;
     let n = m.len();
 
@@ -205,19 +400,13 @@ pub(crate) fn montgomery_mul(z: &mut [Word], x: &[Word], y: &[Word], m: &[Word],
         let c2 = add_mul_vvw(&mut z[i..n + i], x, y[i]);
         let t = z[i].wrapping_mul(k);
         let c3 = add_mul_vvw(&mut z[i..n + i], m, t);
-        let cx = c.wrapping_add(c2);
-        let cy = cx.wrapping_add(c3);
+        let (cx, o1) = c.overflowing_add(c2);
+        let (cy, o2) = cx.overflowing_add(c3);
         z[n + i] = cy;
-        c = (cx < c2 || cy < c3) as Word;
+        c = Word::from(o1 | o2);
     }
 
-    // TODO(tarcieri): eliminate branch
-    let (first, second) = z.split_at_mut(n);
-    if c == 0 {
-        first.swap_with_slice(&mut second[..]);
-    } else {
-        sub_vv(first, second, m);
-    }
+    c
 }
 
 #[inline]
@@ -250,10 +439,9 @@ fn sub_vv(z: &mut [Word], x: &[Word], y: &[Word]) -> Word {
 /// z1<<_W + z0 = x+y+c, with c == 0 or 1
 #[inline(always)]
 fn add_ww(x: Word, y: Word, c: Word) -> (Word, Word) {
-    let yc = y.wrapping_add(c);
-    let z0 = x.wrapping_add(yc);
-    let z1 = (z0 < x || yc < y) as Word;
-    (z1, z0)
+    let (yc, o1) = y.overflowing_add(c);
+    let (z0, o2) = x.overflowing_add(yc);
+    (Word::from(o1 | o2), z0)
 }
 
 /// z1 << _W + z0 = x * y + c
@@ -262,3 +450,262 @@ fn mul_add_www(x: Word, y: Word, c: Word) -> (Word, Word) {
     let z = x as WideWord * y as WideWord + c as WideWord;
     ((z >> Word::BITS) as Word, z as Word)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{montgomery_mul, montgomery_mul_lazy, MontgomeryMultiplier};
+    use crate::{
+        modular::{BoxedResidue, BoxedResidueParams},
+        BoxedUint, Limb, NonZero, Word,
+    };
+    use alloc::{vec, vec::Vec};
+    use hex_literal::hex;
+
+    #[test]
+    fn square_matches_mul() {
+        let params = BoxedResidueParams::new(
+            BoxedUint::from_be_slice(
+                &hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+                256,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let mut multiplier = MontgomeryMultiplier::from(&params);
+
+        for x in [
+            BoxedUint::zero_with_precision(256),
+            BoxedUint::one_with_precision(256),
+            params.modulus().wrapping_sub(&BoxedUint::one()),
+            // Not reduced, which exercises the final conditional subtraction
+            BoxedUint::max(256),
+            BoxedUint::from_be_slice(
+                &hex!("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56"),
+                256,
+            )
+            .unwrap(),
+        ] {
+            assert_eq!(multiplier.square(&x), multiplier.mul(&x, &x));
+        }
+    }
+
+    /// Modulus whose two most significant bits are clear, as required by `mul_lazy`.
+    fn lazy_params() -> BoxedResidueParams {
+        BoxedResidueParams::new(
+            BoxedUint::from_be_slice(
+                &hex!("3fffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+                256,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn mul_lazy_chain_matches_mul() {
+        let params = lazy_params();
+        let mut multiplier = MontgomeryMultiplier::from(&params);
+
+        let x = BoxedUint::from_be_slice(
+            &hex!("04acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56"),
+            256,
+        )
+        .unwrap();
+        let y = params.modulus().wrapping_sub(&BoxedUint::one());
+
+        let mut eager = x.clone();
+        let mut lazy = x.clone();
+
+        for _ in 0..32 {
+            multiplier.mul_assign(&mut eager, &y);
+            multiplier.mul_lazy_assign(&mut lazy, &y);
+            multiplier.mul_assign(&mut eager, &x);
+            multiplier.mul_lazy_assign(&mut lazy, &x);
+
+            // The lazy result stays within `[0, 2*modulus)`
+            assert!(lazy < params.modulus().shl_vartime(1));
+        }
+
+        multiplier.normalize(&mut eager);
+        multiplier.normalize(&mut lazy);
+        assert_eq!(eager, lazy);
+    }
+
+    #[test]
+    fn montgomery_mul_final_subtraction() {
+        let modulus = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+            256,
+        )
+        .unwrap();
+        let modulus_nz = NonZero::new(modulus.clone()).unwrap();
+        let k = Limb::montgomery_inv(modulus.as_limbs()[0]).0;
+        let x = BoxedUint::from_be_slice(
+            &hex!("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56"),
+            256,
+        )
+        .unwrap();
+
+        let mut carries = [false; 2];
+        for (a, b) in [
+            (BoxedUint::max(256), BoxedUint::max(256)),
+            (x.clone(), BoxedUint::max(256)),
+            (x.clone(), x.clone()),
+            (BoxedUint::one_with_precision(256), x.clone()),
+            (BoxedUint::zero_with_precision(256), x.clone()),
+        ] {
+            let mut z = vec![0; 2 * modulus.nlimbs()];
+            let carry =
+                montgomery_mul_lazy(&mut z, a.as_words(), b.as_words(), modulus.as_words(), k);
+            carries[usize::try_from(carry).unwrap()] = true;
+
+            let mut z = vec![0; 2 * modulus.nlimbs()];
+            montgomery_mul(&mut z, a.as_words(), b.as_words(), modulus.as_words(), k);
+            let result = BoxedUint::from_words(z[..z.len() / 2].iter().copied());
+
+            // `result * R == a * b (mod modulus)`
+            assert_eq!(
+                result.widen(512).shl_vartime(256).rem(&modulus_nz),
+                a.mul(&b).rem(&modulus_nz)
+            );
+        }
+
+        // Both sides of the final conditional subtraction are exercised
+        assert_eq!(carries, [true, true]);
+    }
+
+    #[test]
+    fn mul_above_karatsuba_threshold_matches_interleaved() {
+        for modulus in [BoxedUint::max(4096), BoxedUint::max(4096).shr_vartime(1)] {
+            let params = BoxedResidueParams::new(modulus.clone()).unwrap();
+            let mut multiplier = MontgomeryMultiplier::from(&params);
+            let k = Limb::montgomery_inv(modulus.as_limbs()[0]).0;
+            let x = BoxedUint::max(4096)
+                .shr_vartime(3)
+                .wrapping_sub(&BoxedUint::from(0xdead_beefu32));
+
+            for (a, b) in [
+                (BoxedUint::max(4096), BoxedUint::max(4096)),
+                (x.clone(), BoxedUint::max(4096)),
+                (x.clone(), x.clone()),
+                (BoxedUint::one_with_precision(4096), x.clone()),
+                (BoxedUint::zero_with_precision(4096), x.clone()),
+            ] {
+                let mut z = vec![0; 2 * modulus.nlimbs()];
+                montgomery_mul(&mut z, a.as_words(), b.as_words(), modulus.as_words(), k);
+                let expected = BoxedUint::from_words(z[..z.len() / 2].iter().copied());
+
+                assert_eq!(multiplier.mul(&a, &b), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn residue_mul_lazy() {
+        let params = lazy_params();
+        let x = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params.clone());
+        let y = BoxedResidue::new(params.modulus().wrapping_sub(&BoxedUint::one()), params);
+
+        let mut lazy = x.mul_lazy(&y).mul_lazy(&y).mul_lazy(&x);
+        lazy.normalize();
+        assert_eq!(lazy.retrieve(), (&x * &y * &y * &x).retrieve());
+        assert!(lazy.as_montgomery() < lazy.params().modulus());
+    }
+
+    #[test]
+    fn product() {
+        let params = lazy_params();
+        let residues: Vec<_> = [
+            BoxedUint::from(0xdead_beefu32).widen(256),
+            params.modulus().wrapping_sub(&BoxedUint::one()),
+            BoxedUint::from(2u8).widen(256),
+            BoxedUint::max(256),
+        ]
+        .into_iter()
+        .map(|x| BoxedResidue::new(x, params.clone()))
+        .collect();
+
+        let product = BoxedResidue::product(&params, &residues).unwrap();
+        let folded = residues[1..]
+            .iter()
+            .fold(residues[0].clone(), |acc, x| acc.mul(x));
+        assert_eq!(product, folded);
+
+        let modulus = NonZero::new(params.modulus().clone()).unwrap();
+        let expected = residues
+            .iter()
+            .fold(BoxedUint::one_with_precision(256), |acc, x| {
+                acc.mul_mod(&x.retrieve(), &modulus)
+            });
+        assert_eq!(product.retrieve(), expected);
+    }
+
+    #[test]
+    fn mul_scalar() {
+        let params = lazy_params();
+        let x = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params.clone());
+        let y = BoxedResidue::new(
+            params.modulus().wrapping_sub(&BoxedUint::one()),
+            params.clone(),
+        );
+
+        for residue in [x, y] {
+            let mut expected = BoxedResidue::zero(params.clone());
+            for k in 0..=8 {
+                assert_eq!(
+                    residue.mul_scalar(k).retrieve(),
+                    expected.retrieve(),
+                    "k = {k}"
+                );
+                expected = &expected + &residue;
+            }
+
+            let k = Word::MAX;
+            assert_eq!(
+                residue.mul_scalar(k).retrieve(),
+                (&residue * &BoxedResidue::new(BoxedUint::from(k).widen(256), params.clone()))
+                    .retrieve()
+            );
+        }
+    }
+
+    #[test]
+    fn product_empty() {
+        let params = lazy_params();
+        let product = BoxedResidue::product(&params, &[]).unwrap();
+        assert_eq!(product.retrieve(), BoxedUint::one_with_precision(256));
+        assert_eq!(product, BoxedResidue::one(params));
+    }
+
+    #[test]
+    fn product_mismatched_params() {
+        let params = lazy_params();
+        let other = BoxedResidueParams::new(BoxedUint::from(0xffff_fffbu32).widen(256)).unwrap();
+        let residues = [
+            BoxedResidue::one(params.clone()),
+            BoxedResidue::one(other.clone()),
+        ];
+        assert!(bool::from(
+            BoxedResidue::product(&params, &residues).is_none()
+        ));
+        assert!(bool::from(
+            BoxedResidue::product(&other, &residues[1..]).is_some()
+        ));
+    }
+
+    #[test]
+    fn product_mismatched_precision() {
+        let params = lazy_params();
+        let other = BoxedResidueParams::new(BoxedUint::from(0xffff_fffbu32).widen(128)).unwrap();
+        let residues = [
+            BoxedResidue::new(BoxedUint::from(2u8).widen(256), params.clone()),
+            BoxedResidue::one(other.clone()),
+        ];
+        assert!(bool::from(
+            BoxedResidue::product(&params, &residues).is_none()
+        ));
+        assert!(bool::from(
+            BoxedResidue::product(&other, &residues).is_none()
+        ));
+    }
+}
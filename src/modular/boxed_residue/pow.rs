@@ -1,28 +1,156 @@
 //! Modular exponentiation support for [`BoxedResidue`].
 
-use super::{mul::MontgomeryMultiplier, BoxedResidue};
+use super::{mul::MontgomeryMultiplier, BoxedResidue, BoxedResidueParams};
 use crate::{BoxedUint, Limb, PowBoundedExp, Word};
 use alloc::vec::Vec;
-use subtle::ConstantTimeEq;
+use core::{borrow::Borrow, fmt};
+use subtle::{Choice, CtOption};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Errors which can occur in [`BoxedResidue::try_pow`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PowError {
+    /// The modulus of the residue params is even, so they weren't created through
+    /// [`BoxedResidueParams::new`].
+    EvenModulus,
+}
+
+impl fmt::Display for PowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EvenModulus => write!(f, "residue modulus is even"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PowError {}
 
 impl BoxedResidue {
     /// Raises to the `exponent` power.
+    ///
+    /// This allocates a table of precomputed powers and a multiplication buffer on every call. To
+    /// perform many exponentiations with the same parameters, use a [`BoxedMontgomeryEngine`],
+    /// which reuses them between calls.
+    ///
+    /// [`BoxedMontgomeryEngine`]: crate::modular::BoxedMontgomeryEngine
     pub fn pow(&self, exponent: &BoxedUint) -> Self {
+        debug_assert!(bool::from(self.residue_params.modulus.is_odd()));
+        debug_assert!(exponent.bits_precision() > 0);
         let ret = self.pow_bounded_exp(exponent, exponent.bits_precision());
         debug_assert!(ret.retrieve() < self.residue_params.modulus);
         ret
     }
 
+    /// Raises to the `exponent` power like [`BoxedResidue::pow`], after checking that the
+    /// parameters are valid.
+    ///
+    /// Returns [`PowError::EvenModulus`] if the modulus is even.
+    pub fn try_pow(&self, exponent: &BoxedUint) -> Result<Self, PowError> {
+        if !bool::from(self.residue_params.modulus.is_odd()) {
+            return Err(PowError::EvenModulus);
+        }
+
+        Ok(self.pow(exponent))
+    }
+
+    /// Raises to the `-exponent` power if `negative` is `true`, i.e. computes `self^-1` and raises
+    /// that to the `exponent` power, or to the `exponent` power like [`BoxedResidue::pow`]
+    /// otherwise.
+    ///
+    /// Returns `None` if `negative` is `true` and `self` isn't invertible.
+    ///
+    /// NOTE: `negative` may be leaked in the time pattern.
+    pub fn pow_signed(&self, exponent: &BoxedUint, negative: bool) -> CtOption<Self> {
+        let (base, is_some) = if negative {
+            self.invert_with_choice()
+        } else {
+            (self.clone(), Choice::from(1))
+        };
+
+        CtOption::new(base.pow(exponent), is_some)
+    }
+
+    /// Raises to the `exponent` power in constant time.
+    ///
+    /// Unlike [`BoxedResidue::pow`] this doesn't special-case the most significant window of the
+    /// exponent: every window of `exponent.bits_precision()` bits is processed with the same
+    /// sequence of squarings, table lookup and multiplication, so the running time depends only
+    /// on the precisions of `self` and `exponent`. This costs one extra window of squarings of 1.
+    ///
+    /// Intended for secret exponents, e.g. private-key operations, where not even the magnitude
+    /// of the exponent should leak.
+    pub fn pow_ct(&self, exponent: &BoxedUint) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form_ct(
+                &self.montgomery_form,
+                exponent,
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
+    /// Raises to the `exponent` power, processing exactly `width` bits of the exponent.
+    ///
+    /// The exponent is zero-padded or truncated to `width` bits regardless of its own precision,
+    /// so the running time depends only on `width` and the precision of `self`, rather than on
+    /// the allocation size of `exponent`. Bits of `exponent` above `width` are ignored.
+    pub fn pow_fixed_width(&self, exponent: &BoxedUint, width: u32) -> Self {
+        let exponent = if width > exponent.bits_precision() {
+            exponent.widen(width)
+        } else {
+            exponent.shorten(width)
+        };
+
+        self.pow_bounded_exp(&exponent, width)
+    }
+
+    /// Raises to the `2^k` power, i.e. squares `k` times.
+    ///
+    /// This is much cheaper than [`BoxedResidue::pow`] with a `2^k` exponent when `k` is large,
+    /// e.g. for repeated squaring in timelock puzzles. Returns `self` if `k == 0`.
+    ///
+    /// NOTE: `k` may be leaked in the time pattern.
+    pub fn pow2k(&self, k: u32) -> Self {
+        let mut multiplier = MontgomeryMultiplier::from(self.residue_params.borrow());
+        let mut montgomery_form = self.montgomery_form.clone();
+
+        for _ in 0..k {
+            multiplier.square_assign(&mut montgomery_form);
+        }
+
+        Self {
+            montgomery_form,
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
     /// Raises to the `exponent` power,
     /// with `exponent_bits` representing the number of (least significant) bits
     /// to take into account for the exponent.
     ///
     /// NOTE: `exponent_bits` may be leaked in the time pattern.
     pub fn pow_bounded_exp(&self, exponent: &BoxedUint, exponent_bits: u32) -> Self {
+        self.pow_bounded_exp_limbs(exponent.as_limbs(), exponent_bits)
+    }
+
+    /// Raises to the power of the exponent stored in little-endian order in `exponent_limbs`,
+    /// like [`BoxedResidue::pow_bounded_exp`], without requiring it to be wrapped in a
+    /// [`BoxedUint`].
+    ///
+    /// Panics if `exponent_limbs` holds fewer than `exponent_bits` bits.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_bounded_exp_limbs(&self, exponent_limbs: &[Limb], exponent_bits: u32) -> Self {
         Self {
             montgomery_form: pow_montgomery_form(
                 &self.montgomery_form,
-                exponent,
+                exponent_limbs,
                 exponent_bits,
                 &self.residue_params.modulus,
                 &self.residue_params.r,
@@ -31,6 +159,54 @@ impl BoxedResidue {
             residue_params: self.residue_params.clone(),
         }
     }
+
+    /// Raises `base_mont` to the `exponent` power like [`BoxedResidue::pow_bounded_exp`], but
+    /// operating directly on a raw value in Montgomery form rather than a [`BoxedResidue`].
+    ///
+    /// `base_mont` must already be in Montgomery form under `params`, e.g. as obtained from
+    /// [`BoxedResidueParams::to_montgomery`] or [`BoxedResidue::as_montgomery`], and the result is
+    /// in Montgomery form as well. This avoids wrapping and unwrapping values when chaining
+    /// several operations.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_montgomery_form(
+        base_mont: &BoxedUint,
+        exponent: &BoxedUint,
+        exponent_bits: u32,
+        params: &BoxedResidueParams,
+    ) -> BoxedUint {
+        debug_assert_eq!(base_mont.bits_precision(), params.bits_precision());
+        pow_montgomery_form(
+            base_mont,
+            exponent.as_limbs(),
+            exponent_bits,
+            &params.modulus,
+            &params.r,
+            params.mod_neg_inv,
+        )
+    }
+
+    /// Computes `base.pow(exponent)` for each `(base, exponent)` pair, returning the results in
+    /// the same order.
+    ///
+    /// The bases may each have distinct parameters. When the `rayon` feature is enabled the
+    /// exponentiations are performed in parallel, otherwise they are performed sequentially.
+    pub fn pow_batch(pairs: &[(Self, BoxedUint)]) -> Vec<Self> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            pairs
+                .par_iter()
+                .map(|(base, exponent)| base.pow(exponent))
+                .collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        pairs
+            .iter()
+            .map(|(base, exponent)| base.pow(exponent))
+            .collect()
+    }
 }
 
 impl PowBoundedExp<BoxedUint> for BoxedResidue {
@@ -45,7 +221,7 @@ impl PowBoundedExp<BoxedUint> for BoxedResidue {
 /// NOTE: this value is leaked in the time pattern.
 fn pow_montgomery_form(
     x: &BoxedUint,
-    exponent: &BoxedUint,
+    exponent: &[Limb],
     exponent_bits: u32,
     modulus: &BoxedUint,
     r: &BoxedUint,
@@ -55,12 +231,229 @@ fn pow_montgomery_form(
         return r.clone(); // 1 in Montgomery form
     }
 
+    assert!(
+        exponent.len() * Limb::BITS as usize >= exponent_bits as usize,
+        "exponent has fewer than `exponent_bits` bits"
+    );
+
+    let mut multiplier = MontgomeryMultiplier::new(modulus, mod_neg_inv);
+
+    // Building the table takes at least one multiplication per entry, which short exponents don't
+    // make up for, so they avoid the table's memory and setup cost altogether
+    if exponent_bits <= SMALL_EXPONENT_BITS {
+        return pow_montgomery_form_small(&mut multiplier, x, exponent, exponent_bits, r);
+    }
+
+    let mut z = r.clone();
+    PowerTable::new(r.bits_precision()).pow_into(
+        &mut multiplier,
+        r,
+        exponent_bits,
+        1,
+        |_| (x, exponent),
+        &mut z,
+    );
+    z
+}
+
+/// Buffers for fixed-window (multi-)exponentiation, which can be reused between calls.
+pub(super) struct PowerTable {
+    /// Precomputed powers of each base, with `1 << window` entries per base.
+    pub(super) powers: Vec<BoxedUint>,
+
+    /// Output of constant-time lookups into `powers`.
+    pub(super) power: BoxedUint,
+
+    /// Window size used by the most recent exponentiation.
+    pub(super) window: u32,
+}
+
+impl PowerTable {
+    /// Create an empty table for values with the given precision.
+    pub(super) fn new(bits_precision: u32) -> Self {
+        Self {
+            powers: Vec::new(),
+            power: BoxedUint::zero_with_precision(bits_precision),
+            window: 0,
+        }
+    }
+
+    /// Fixed-window multi-exponentiation in Montgomery form, where `term(i)` returns the `i`-th
+    /// base and the little-endian limbs of its exponent, writing the product of the powers to `z`.
+    ///
+    /// Only the `exponent_bits` least significant bits of each exponent are taken into account,
+    /// and limbs beyond the end of an exponent are treated as zero. The window size is selected
+    /// based on `exponent_bits`, and the table is only grown when a larger window or additional
+    /// bases are needed.
+    ///
+    /// NOTE: `nterms` and `exponent_bits` may be leaked in the time pattern.
+    pub(super) fn pow_into<'b, F>(
+        &mut self,
+        multiplier: &mut MontgomeryMultiplier<'_>,
+        r: &BoxedUint,
+        exponent_bits: u32,
+        nterms: usize,
+        term: F,
+        z: &mut BoxedUint,
+    ) where
+        F: Fn(usize) -> (&'b BoxedUint, &'b [Limb]),
+    {
+        debug_assert_eq!(z.bits_precision(), r.bits_precision());
+
+        z.limbs.copy_from_slice(&r.limbs); // 1 in Montgomery form
+        if exponent_bits == 0 || nterms == 0 {
+            return;
+        }
+
+        let window = window_size(exponent_bits);
+        let table_len = 1 << window;
+        self.window = window;
+
+        // Only grow the table: its existing entries are overwritten below
+        if self.powers.len() < nterms * table_len {
+            let placeholder = BoxedUint::zero_with_precision(r.bits_precision());
+            self.powers.resize(nterms * table_len, placeholder);
+        }
+
+        // powers[i * table_len + j] contains base_i^j
+        for (i, powers) in self
+            .powers
+            .chunks_exact_mut(table_len)
+            .take(nterms)
+            .enumerate()
+        {
+            let (base, _) = term(i);
+            powers[0].limbs.copy_from_slice(&r.limbs); // 1 in Montgomery form
+            powers[1].limbs.copy_from_slice(&base.limbs);
+
+            for j in 2..table_len {
+                let (prev, rest) = powers.split_at_mut(j);
+                rest[0].limbs.copy_from_slice(&prev[j - 1].limbs);
+                multiplier.mul_assign(&mut rest[0], base);
+            }
+        }
+
+        let nwindows = (exponent_bits + window - 1) / window;
+
+        for window_num in (0..nwindows).rev() {
+            // Squaring 1 is a no-op, so the most significant window skips it
+            if window_num != nwindows - 1 {
+                for _ in 0..window {
+                    multiplier.square_assign(z);
+                }
+            }
+
+            // The most significant window may extend past `exponent_bits`
+            let pos = window_num * window;
+            let len = window.min(exponent_bits - pos);
+
+            for (i, powers) in self.powers.chunks_exact(table_len).take(nterms).enumerate() {
+                let (_, exponent) = term(i);
+                let idx = window_bits(exponent, pos, len);
+
+                // Constant-time lookup in the array of powers
+                self.power.ct_select_into(powers, idx);
+                multiplier.mul_assign(z, &self.power);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for PowerTable {
+    fn drop(&mut self) {
+        self.powers.iter_mut().for_each(Zeroize::zeroize);
+        self.power.zeroize();
+    }
+}
+
+/// Select the window size for an exponent with the given number of bits.
+///
+/// Each additional bit of window halves the number of multiplications by table entries, but
+/// doubles both the size of the table and the cost of each constant-time lookup. The thresholds
+/// are those OpenSSL uses for its constant-time exponentiation.
+const fn window_size(exponent_bits: u32) -> u32 {
+    if exponent_bits > 937 {
+        6
+    } else if exponent_bits > 306 {
+        5
+    } else if exponent_bits > 89 {
+        4
+    } else if exponent_bits > 22 {
+        3
+    } else {
+        1
+    }
+}
+
+/// Extract the `len` bits of `exponent` starting at bit `pos`, treating bits beyond its end as
+/// zero.
+///
+/// Only `pos` and `len` (which are public) affect the memory access pattern.
+fn window_bits(exponent: &[Limb], pos: u32, len: u32) -> Word {
+    let limb_num = (pos / Limb::BITS) as usize;
+    let bit = pos % Limb::BITS;
+    let limb = |i: usize| exponent.get(i).map_or(0, |limb| limb.0);
+
+    let mut bits = limb(limb_num) >> bit;
+    if bit + len > Limb::BITS {
+        bits |= limb(limb_num + 1) << (Limb::BITS - bit);
+    }
+
+    bits & ((1 << len) - 1)
+}
+
+/// Largest `exponent_bits` for which [`pow_montgomery_form`] uses square-and-multiply rather than a
+/// table of precomputed powers.
+const SMALL_EXPONENT_BITS: u32 = 16;
+
+/// Performs modular exponentiation using square-and-multiply, without a table of precomputed
+/// powers. A multiplication is performed for every bit, so only `exponent_bits` is leaked in the
+/// time pattern.
+fn pow_montgomery_form_small(
+    multiplier: &mut MontgomeryMultiplier<'_>,
+    x: &BoxedUint,
+    exponent: &[Limb],
+    exponent_bits: u32,
+    r: &BoxedUint,
+) -> BoxedUint {
+    let mut z = r.clone(); // 1 in Montgomery form
+    let mut product = r.clone();
+
+    for i in (0..exponent_bits).rev() {
+        multiplier.square_assign(&mut z);
+
+        product.limbs.copy_from_slice(&z.limbs);
+        multiplier.mul_assign(&mut product, x);
+
+        let limb = exponent[(i / Limb::BITS) as usize].0;
+        let bit = Choice::from(((limb >> (i % Limb::BITS)) & 1) as u8);
+        z.conditional_assign(&product, bit);
+    }
+
+    z
+}
+
+/// Performs modular exponentiation using fixed 4-bit windows, processing every bit of `exponent`.
+///
+/// The sequence of operations depends only on `exponent.bits_precision()`.
+fn pow_montgomery_form_ct(
+    x: &BoxedUint,
+    exponent: &BoxedUint,
+    modulus: &BoxedUint,
+    r: &BoxedUint,
+    mod_neg_inv: Limb,
+) -> BoxedUint {
     const WINDOW: u32 = 4;
     const WINDOW_MASK: Word = (1 << WINDOW) - 1;
 
+    // `Limb::BITS` is a multiple of `WINDOW`, so windows never straddle limbs
+    const WINDOWS_PER_LIMB: u32 = Limb::BITS / WINDOW;
+
     let mut multiplier = MontgomeryMultiplier::new(modulus, mod_neg_inv);
 
-    // powers[i] contains x^i
+    // powers[i] contains x^i. Each entry is zeroized on drop when the `zeroize` feature is
+    // enabled, as they are derived from the (potentially secret) base.
     let mut powers = Vec::with_capacity(1 << WINDOW);
     powers.push(r.clone()); // 1 in Montgomery form
     powers.push(x.clone());
@@ -69,45 +462,319 @@ fn pow_montgomery_form(
         powers.push(multiplier.mul(&powers[i - 1], x));
     }
 
-    let starting_limb = ((exponent_bits - 1) / Limb::BITS) as usize;
-    let starting_bit_in_limb = (exponent_bits - 1) % Limb::BITS;
-    let starting_window = starting_bit_in_limb / WINDOW;
-    let starting_window_mask = (1 << (starting_bit_in_limb % WINDOW + 1)) - 1;
-
     let mut z = r.clone(); // 1 in Montgomery form
-    let mut power = powers[0].clone();
+    let mut power = BoxedUint::zero_with_precision(r.bits_precision());
+
+    for limb in exponent.as_limbs().iter().rev() {
+        for window_num in (0..WINDOWS_PER_LIMB).rev() {
+            for _ in 0..WINDOW {
+                multiplier.square_assign(&mut z);
+            }
 
-    for limb_num in (0..=starting_limb).rev() {
-        let w = exponent.as_limbs()[limb_num].0;
+            let idx = (limb.0 >> (window_num * WINDOW)) & WINDOW_MASK;
 
-        let mut window_num = if limb_num == starting_limb {
-            starting_window + 1
-        } else {
-            Limb::BITS / WINDOW
-        };
+            // Constant-time lookup in the array of powers
+            power.ct_select_into(&powers, idx);
+            multiplier.mul_assign(&mut z, &power);
+        }
+    }
 
-        while window_num > 0 {
-            window_num -= 1;
+    z
+}
 
-            let mut idx = (w >> (window_num * WINDOW)) & WINDOW_MASK;
+#[cfg(test)]
+mod tests {
+    use super::{window_bits, window_size, PowError};
+    use crate::{
+        modular::{BoxedResidue, BoxedResidueParams},
+        BoxedUint, Limb,
+    };
+    use alloc::vec::Vec;
+    use hex_literal::hex;
 
-            if limb_num == starting_limb && window_num == starting_window {
-                idx &= starting_window_mask;
-            } else {
-                for _ in 1..=WINDOW {
-                    multiplier.square_assign(&mut z);
-                }
-            }
+    fn params() -> BoxedResidueParams {
+        BoxedResidueParams::new(
+            BoxedUint::from_be_slice(
+                &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+                256,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
 
-            // Constant-time lookup in the array of powers
-            power.limbs.copy_from_slice(&powers[0].limbs);
-            for i in 1..(1 << WINDOW) {
-                power.conditional_assign(&powers[i as usize], i.ct_eq(&idx));
+    #[test]
+    fn window_sizes() {
+        assert_eq!(window_size(64), 3);
+        assert_eq!(window_size(256), 4);
+        assert_eq!(window_size(2048), 6);
+        assert_eq!(window_size(4096), 6);
+    }
+
+    #[test]
+    fn window_bits_across_limbs() {
+        let exponent = BoxedUint::from_be_slice(
+            &hex!("0123456789abcdeffedcba9876543210f0e1d2c3b4a5968778695a4b3c2d1e0f"),
+            256,
+        )
+        .unwrap();
+
+        for pos in 0..exponent.bits_precision() + 8 {
+            for len in 1..=6 {
+                let expected = (0..len).fold(0, |acc, i| {
+                    let bit = exponent
+                        .as_limbs()
+                        .get(((pos + i) / Limb::BITS) as usize)
+                        .map_or(0, |limb| (limb.0 >> ((pos + i) % Limb::BITS)) & 1);
+                    acc | (bit << i)
+                });
+                assert_eq!(window_bits(exponent.as_limbs(), pos, len), expected);
             }
+        }
+    }
 
-            multiplier.mul_assign(&mut z, &power);
+    #[test]
+    fn pow_fixed_width() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+        let exponent = BoxedUint::from_be_slice(
+            &hex!("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685"),
+            256,
+        )
+        .unwrap();
+        let expected = base.pow(&exponent);
+
+        assert_eq!(base.pow_fixed_width(&exponent, 256), expected);
+
+        // Exponent with excess precision, or a width which exceeds it
+        assert_eq!(base.pow_fixed_width(&exponent.widen(1024), 256), expected);
+        assert_eq!(base.pow_fixed_width(&exponent, 300), expected);
+        assert_eq!(base.pow_fixed_width(&exponent.widen(512), 640), expected);
+
+        // Truncated exponent
+        let one = BoxedUint::one_with_precision(256);
+        let mask = one.shl(100).wrapping_sub(&one);
+        assert_eq!(
+            base.pow_fixed_width(&exponent, 100),
+            base.pow(&exponent.bitand(&mask))
+        );
+        assert_eq!(
+            base.pow_fixed_width(&exponent, 0),
+            BoxedResidue::one(params())
+        );
+    }
+
+    #[test]
+    fn pow_bounded_exp_all_widths() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+        let exponent = BoxedUint::from_be_slice(
+            &hex!("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685"),
+            256,
+        )
+        .unwrap();
+        let one = BoxedUint::one_with_precision(256);
+
+        // Covers both the square-and-multiply path for short exponents and the windowed one
+        for exponent_bits in 1..=256 {
+            // `one << 256` is zero, so the mask is all ones in that case
+            let mask = one.shl(exponent_bits).wrapping_sub(&one);
+            let truncated = exponent.bitand(&mask);
+
+            assert_eq!(
+                base.pow_bounded_exp(&exponent, exponent_bits),
+                base.pow_ct(&truncated),
+                "exponent_bits = {exponent_bits}"
+            );
         }
     }
 
-    z
+    #[test]
+    fn pow_bounded_exp_limbs() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+        let exponent = BoxedUint::from_be_slice(
+            &hex!("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685"),
+            256,
+        )
+        .unwrap();
+        let limbs = exponent.as_limbs();
+
+        for exponent_bits in [0, 1, 16, 17, 64, 100, 255, 256] {
+            assert_eq!(
+                base.pow_bounded_exp_limbs(limbs, exponent_bits),
+                base.pow_bounded_exp(&exponent, exponent_bits),
+                "exponent_bits = {exponent_bits}"
+            );
+        }
+
+        // Only the limbs covering `exponent_bits` are needed
+        assert_eq!(
+            base.pow_bounded_exp_limbs(&limbs[..1], 64),
+            base.pow_bounded_exp(&BoxedUint::from(limbs[0].0), 64)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn pow_bounded_exp_limbs_too_short() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+        base.pow_bounded_exp_limbs(&[Limb::MAX], 65);
+    }
+
+    #[test]
+    fn pow_signed() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+        let one = BoxedUint::one_with_precision(256);
+
+        for exponent in [
+            BoxedUint::zero_with_precision(256),
+            BoxedUint::one_with_precision(256),
+            BoxedUint::from(65537u32).widen(256),
+            BoxedUint::from(0x1234_5678_9abc_def0u64),
+        ] {
+            let positive = base.pow_signed(&exponent, false).unwrap();
+            assert_eq!(positive, base.pow(&exponent));
+
+            let negative = base.pow_signed(&exponent, true).unwrap();
+            assert_eq!(
+                negative.retrieve(),
+                base.invert().unwrap().pow(&exponent).retrieve()
+            );
+            assert_eq!(negative.mul(&positive).retrieve(), one);
+        }
+
+        let zero = BoxedResidue::zero(params());
+        let exponent = BoxedUint::from(3u8);
+        assert!(bool::from(zero.pow_signed(&exponent, true).is_none()));
+        assert_eq!(
+            zero.pow_signed(&exponent, false).unwrap().retrieve(),
+            BoxedUint::zero_with_precision(256)
+        );
+    }
+
+    #[test]
+    fn try_pow() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+        let exponent = BoxedUint::from(65537u32);
+        assert_eq!(base.try_pow(&exponent), Ok(base.pow(&exponent)));
+
+        // Params with an even modulus can't be constructed through the public API
+        let mut even_params = params();
+        even_params.modulus = even_params.modulus.wrapping_add(&BoxedUint::one());
+        let base = BoxedResidue::from_montgomery(base.to_montgomery(), even_params);
+        assert_eq!(base.try_pow(&exponent), Err(PowError::EvenModulus));
+    }
+
+    #[test]
+    fn pow_montgomery_form() {
+        let params = params();
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params.clone());
+        let exponent = BoxedUint::from(0x1234_5678_9abc_def0u64);
+
+        let base_mont = params.to_montgomery(&BoxedUint::from(0xdead_beefu32).widen(256));
+        assert_eq!(&base_mont, base.as_montgomery());
+
+        let actual = BoxedResidue::pow_montgomery_form(&base_mont, &exponent, 64, &params);
+        assert_eq!(&actual, base.pow(&exponent).as_montgomery());
+        assert_eq!(
+            params.from_montgomery(&actual),
+            base.pow(&exponent).retrieve()
+        );
+    }
+
+    #[test]
+    fn pow_ct_matches_pow() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+
+        for exponent in [
+            BoxedUint::zero_with_precision(256),
+            BoxedUint::one_with_precision(256),
+            BoxedUint::from(0x1234_5678u32).widen(256),
+            BoxedUint::max(256),
+            BoxedUint::from_be_slice(
+                &hex!("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685"),
+                256,
+            )
+            .unwrap(),
+        ] {
+            assert_eq!(
+                base.pow_ct(&exponent).retrieve(),
+                base.pow(&exponent).retrieve()
+            );
+        }
+    }
+
+    #[test]
+    fn pow_ct_leading_zeros() {
+        let base = BoxedResidue::new(BoxedUint::from(3u8).widen(256), params());
+
+        // Padding the exponent with zero limbs only adds squarings of 1
+        let exponent = BoxedUint::from(0xffff_ffff_ffff_fffdu64);
+        assert_eq!(
+            base.pow_ct(&exponent).retrieve(),
+            base.pow_ct(&exponent.widen(512)).retrieve()
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn pow_ct_random() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let params = params();
+
+        for _ in 0..8 {
+            let base = BoxedResidue::new(BoxedUint::random(&mut rng, 256), params.clone());
+            let exponent = BoxedUint::random(&mut rng, 256);
+            assert_eq!(
+                base.pow_ct(&exponent).retrieve(),
+                base.pow(&exponent).retrieve()
+            );
+        }
+    }
+
+    #[test]
+    fn pow2k() {
+        let base = BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params());
+        assert_eq!(base.pow2k(0), base);
+
+        for k in [1, 2, 5, 64, 100, 255] {
+            let exponent = BoxedUint::one_with_precision(256).shl_vartime(k);
+            assert_eq!(base.pow2k(k).retrieve(), base.pow(&exponent).retrieve());
+        }
+
+        // Exponents too large to construct at this precision
+        assert_eq!(base.pow2k(300), base.pow2k(256).pow2k(44));
+    }
+
+    #[test]
+    fn pow_batch_matches_pow() {
+        let exponent = BoxedUint::from(65537u32);
+        let pairs: Vec<_> = [0xffff_fffbu64, 0xffff_ffff_ffff_ffc5, 0xffff_ffff_0000_0001]
+            .iter()
+            .enumerate()
+            .map(|(i, &modulus)| {
+                let params = BoxedResidueParams::new(BoxedUint::from(modulus)).unwrap();
+                let base = BoxedResidue::new(BoxedUint::from(0xdead_beef + i as u64), params);
+                (base, exponent.clone())
+            })
+            .chain([(
+                BoxedResidue::new(BoxedUint::from(0xdead_beefu32).widen(256), params()),
+                exponent.clone(),
+            )])
+            .collect();
+
+        let results = BoxedResidue::pow_batch(&pairs);
+        assert_eq!(results.len(), pairs.len());
+        for ((base, exponent), result) in pairs.iter().zip(&results) {
+            assert_eq!(result, &base.pow(exponent));
+        }
+
+        // Results follow the order of the inputs
+        let reversed: Vec<_> = pairs.iter().rev().cloned().collect();
+        let reversed_results = BoxedResidue::pow_batch(&reversed);
+        assert!(reversed_results.iter().eq(results.iter().rev()));
+
+        assert!(BoxedResidue::pow_batch(&[]).is_empty());
+    }
 }
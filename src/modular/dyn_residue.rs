@@ -9,11 +9,11 @@ mod sub;
 
 use super::{
     div_by_2::div_by_2,
-    reduction::montgomery_reduction,
+    reduction::{montgomery_reduction, MontgomeryParams},
     residue::{Residue, ResidueParams},
     Retrieve,
 };
-use crate::{Integer, Limb, Uint, Word};
+use crate::{Limb, Uint};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// Parameters to efficiently go to/from the Montgomery form for an odd modulus provided at runtime.
@@ -37,26 +37,19 @@ impl<const LIMBS: usize> DynResidueParams<LIMBS> {
     ///
     /// Returns a `CtOption` that is `None` if the provided modulus is not odd.
     pub fn new(modulus: &Uint<LIMBS>) -> CtOption<Self> {
-        let r = Uint::MAX.const_rem(modulus).0.wrapping_add(&Uint::ONE);
-        let r2 = Uint::const_rem_wide(r.square_wide(), modulus).0;
-
-        // Since we are calculating the inverse modulo (Word::MAX+1),
-        // we can take the modulo right away and calculate the inverse of the first limb only.
-        let modulus_lo = Uint::<1>::from_words([modulus.limbs[0].0]);
-        let mod_neg_inv =
-            Limb(Word::MIN.wrapping_sub(modulus_lo.inv_mod2k_vartime(Word::BITS).limbs[0].0));
-
-        let r3 = montgomery_reduction(&r2.square_wide(), modulus, mod_neg_inv);
+        // The parameters are meaningless for an even modulus, which is rejected below
+        let montgomery_params = MontgomeryParams::compute(modulus);
+        let r3 = montgomery_params.reduce(&montgomery_params.r2().square_wide());
 
         let params = Self {
             modulus: *modulus,
-            r,
-            r2,
+            r: *montgomery_params.r(),
+            r2: *montgomery_params.r2(),
             r3,
-            mod_neg_inv,
+            mod_neg_inv: montgomery_params.mod_neg_inv(),
         };
 
-        CtOption::new(params, modulus.is_odd())
+        CtOption::new(params, modulus.ct_is_odd().into())
     }
 
     /// Returns the modulus which was used to initialize these parameters.
@@ -1,10 +1,98 @@
 //! Modular reduction implementation.
 
-use crate::{Limb, Uint, WideWord, Word};
+use crate::{CtChoice, Limb, Uint, WideWord, Word};
+use subtle::CtOption;
 
 #[cfg(feature = "alloc")]
 use {crate::BoxedUint, subtle::Choice};
 
+/// Precomputed parameters for Montgomery reduction modulo an odd modulus provided at runtime.
+///
+/// This bundles the arguments of [`montgomery_reduction`] along with the constants needed to move
+/// values into and out of Montgomery form, for use in hot loops on the fixed-width path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MontgomeryParams<const LIMBS: usize> {
+    /// The constant modulus
+    modulus: Uint<LIMBS>,
+    /// Parameter used in Montgomery reduction
+    r: Uint<LIMBS>,
+    /// R^2, used to move into Montgomery form
+    r2: Uint<LIMBS>,
+    /// The lowest limbs of -(MODULUS^-1) mod R
+    /// We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
+    mod_neg_inv: Limb,
+}
+
+impl<const LIMBS: usize> MontgomeryParams<LIMBS> {
+    /// Computes the reduction parameters for the given `modulus` if it is odd.
+    ///
+    /// Returns a `CtOption` that is `None` if the provided modulus is not odd.
+    pub fn new(modulus: &Uint<LIMBS>) -> CtOption<Self> {
+        CtOption::new(Self::compute(modulus), modulus.ct_is_odd().into())
+    }
+
+    /// Computes the reduction parameters for the given `modulus` in a `const` context, e.g.:
+    ///
+    /// ```
+    /// use crypto_bigint::{modular::MontgomeryParams, U256};
+    ///
+    /// const N: U256 =
+    ///     U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+    /// const PARAMS: MontgomeryParams<{ U256::LIMBS }> = MontgomeryParams::new_const(N);
+    /// ```
+    ///
+    /// Panics if the modulus is even.
+    pub const fn new_const(modulus: Uint<LIMBS>) -> Self {
+        assert!(modulus.ct_is_odd().is_true_vartime(), "modulus must be odd");
+        Self::compute(&modulus)
+    }
+
+    /// Computes the parameters for `modulus`, which are meaningless if it is even.
+    pub(crate) const fn compute(modulus: &Uint<LIMBS>) -> Self {
+        let r = Uint::MAX.const_rem(modulus).0.wrapping_add(&Uint::ONE);
+        let r2 = Uint::const_rem_wide(r.square_wide(), modulus).0;
+
+        // A surrogate value of `1` is used for an even modulus, which must be rejected by the
+        // caller.
+        let modulus_lo = Limb::ct_select(Limb::ONE, modulus.limbs[0], modulus.ct_is_odd());
+        let mod_neg_inv = Limb::montgomery_inv(modulus_lo);
+
+        Self {
+            modulus: *modulus,
+            r,
+            r2,
+            mod_neg_inv,
+        }
+    }
+
+    /// Returns the modulus.
+    pub const fn modulus(&self) -> &Uint<LIMBS> {
+        &self.modulus
+    }
+
+    /// Returns `R mod modulus`, i.e. the Montgomery form of one.
+    pub const fn r(&self) -> &Uint<LIMBS> {
+        &self.r
+    }
+
+    /// Returns `R^2 mod modulus`, which is used to move values into Montgomery form.
+    pub const fn r2(&self) -> &Uint<LIMBS> {
+        &self.r2
+    }
+
+    /// Returns the lowest limb of `-(modulus^-1) mod R`.
+    pub const fn mod_neg_inv(&self) -> Limb {
+        self.mod_neg_inv
+    }
+
+    /// Performs Montgomery reduction of `lower_upper` using these parameters.
+    ///
+    /// See [`montgomery_reduction`] for the requirements on the input.
+    pub const fn reduce(&self, lower_upper: &(Uint<LIMBS>, Uint<LIMBS>)) -> Uint<LIMBS> {
+        montgomery_reduction(lower_upper, &self.modulus, self.mod_neg_inv)
+    }
+}
+
 /// Returns `(hi, lo)` such that `hi * R + lo = x * y + z + w`.
 #[inline(always)]
 const fn muladdcarry(x: Word, y: Word, z: Word, w: Word) -> (Word, Word) {
@@ -60,26 +148,84 @@ pub const fn montgomery_reduction<const LIMBS: usize>(
     modulus: &Uint<LIMBS>,
     mod_neg_inv: Limb,
 ) -> Uint<LIMBS> {
+    let (upper, overflow) = montgomery_reduction_unreduced(lower_upper, modulus, mod_neg_inv);
+
+    // Final reduction (at this point, the value is at most 2 * modulus,
+    // so `meta_carry` is either 0 or 1)
+    let meta_carry = Limb(overflow.if_true_word(1));
+    upper.sub_mod_with_carry(meta_carry, modulus, modulus)
+}
+
+/// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+///
+/// This version omits the final conditional subtraction performed by [`montgomery_reduction`],
+/// for use in lazy reduction. Under the same input requirements, the reduced value is
+/// `upper + overflow * R` in `[0, 2 * modulus)`, where `(upper, overflow)` is the returned pair.
+///
+/// `overflow` is the truthy value if the value doesn't fit in `Uint<LIMBS>`, in which case the
+/// final subtraction of the modulus is always needed. Applying
+/// [`Uint::sub_mod_with_carry`] with the corresponding carry produces the fully reduced result.
+pub const fn montgomery_reduction_unreduced<const LIMBS: usize>(
+    lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> (Uint<LIMBS>, CtChoice) {
     let (mut lower, mut upper) = *lower_upper;
     let meta_carry =
         impl_montgomery_reduction!(upper.limbs, lower.limbs, &modulus.limbs, mod_neg_inv, LIMBS);
 
     // Division is simply taking the upper half of the limbs
-    // Final reduction (at this point, the value is at most 2 * modulus,
-    // so `meta_carry` is either 0 or 1)
-    upper.sub_mod_with_carry(meta_carry, modulus, modulus)
+    (upper, CtChoice::from_word_lsb(meta_carry.0))
 }
 
 /// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
 ///
-/// This version writes the result into the provided [`BoxedUint`].
+/// Unlike [`montgomery_reduction`], which requires the input to be less than `modulus * R` (e.g. a
+/// single product of two reduced values), this version supports inputs less than
+/// `k * modulus * R`, such as a sum of `k` products of reduced values, so long as the input fits in
+/// `(Uint<LIMBS>, Uint<LIMBS>)`.
+///
+/// The result of the reduction is less than `(k + 1) * modulus`, so the final reduction performs
+/// `k` conditional subtractions of the modulus. `k` may be leaked in the time pattern.
+pub const fn montgomery_reduction_wide<const LIMBS: usize>(
+    lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+    k: u32,
+) -> Uint<LIMBS> {
+    let (mut lower, mut upper) = *lower_upper;
+    let mut meta_carry =
+        impl_montgomery_reduction!(upper.limbs, lower.limbs, &modulus.limbs, mod_neg_inv, LIMBS);
+
+    // The reduced value is `meta_carry * R + upper`, where `meta_carry` is either 0 or 1
+    let mut i = 0;
+    while i < k {
+        let (diff, borrow) = upper.sbb(modulus, Limb::ZERO);
+
+        // The value is at least `modulus` iff the borrow can be absorbed by `meta_carry`
+        let (new_meta_carry, underflow) = meta_carry.sbb(Limb::ZERO, borrow);
+        let ge = CtChoice::from_word_mask(underflow.0).not();
+
+        upper = Uint::ct_select(&upper, &diff, ge);
+        meta_carry = Limb::ct_select(meta_carry, new_meta_carry, ge);
+        i += 1;
+    }
+
+    debug_assert!(meta_carry.0 == 0);
+    upper
+}
+
+/// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+///
+/// This version writes the result into the provided [`BoxedUint`], and returns the truthy value if
+/// the final subtraction of the modulus was performed.
 #[cfg(feature = "alloc")]
 pub(crate) fn montgomery_reduction_boxed_mut(
     x: &mut BoxedUint,
     modulus: &BoxedUint,
     mod_neg_inv: Limb,
     out: &mut BoxedUint,
-) {
+) -> Choice {
     debug_assert_eq!(x.nlimbs(), modulus.nlimbs() * 2);
     debug_assert_eq!(out.nlimbs(), modulus.nlimbs());
 
@@ -95,7 +241,32 @@ pub(crate) fn montgomery_reduction_boxed_mut(
 
     // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
     // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
-    out.conditional_adc_assign(modulus, Choice::from((borrow.0 & 1) as u8));
+    let borrow = Choice::from((borrow.0 & 1) as u8);
+    out.conditional_adc_assign(modulus, borrow);
+    !borrow
+}
+
+/// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+///
+/// This version performs an "almost Montgomery reduction": the modulus is only subtracted when the
+/// intermediate result overflows, so `out` is guaranteed to be less than `2^bits_precision`, but
+/// may not be fully reduced. This matches the output of `montgomery_mul`.
+#[cfg(feature = "alloc")]
+pub(crate) fn almost_montgomery_reduction_boxed_mut(
+    x: &mut BoxedUint,
+    modulus: &BoxedUint,
+    mod_neg_inv: Limb,
+    out: &mut BoxedUint,
+) {
+    debug_assert_eq!(x.nlimbs(), modulus.nlimbs() * 2);
+    debug_assert_eq!(out.nlimbs(), modulus.nlimbs());
+
+    let (lower, upper) = x.limbs.split_at_mut(modulus.nlimbs());
+    let meta_carry =
+        impl_montgomery_reduction!(upper, lower, &modulus.limbs, mod_neg_inv, modulus.nlimbs());
+
+    out.limbs.copy_from_slice(upper);
+    out.conditional_sbb_assign(modulus, Choice::from((meta_carry.0 & 1) as u8));
 }
 
 /// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
@@ -112,3 +283,153 @@ pub(crate) fn montgomery_reduction_boxed(
     montgomery_reduction_boxed_mut(x, modulus, mod_neg_inv, &mut ret);
     ret
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        montgomery_reduction, montgomery_reduction_unreduced, montgomery_reduction_wide,
+        MontgomeryParams,
+    };
+    use crate::{Limb, Uint, U256};
+
+    /// Sum the wide products of `pairs`.
+    fn sum_of_products(pairs: &[(U256, U256)]) -> (U256, U256) {
+        let mut lo = U256::ZERO;
+        let mut hi = U256::ZERO;
+
+        for (a, b) in pairs {
+            let (plo, phi) = a.mul_wide(b);
+            let carry;
+            (lo, carry) = lo.adc(&plo, Limb::ZERO);
+            (hi, _) = hi.adc(&phi, carry);
+        }
+
+        (lo, hi)
+    }
+
+    #[test]
+    fn reduces_sum_of_products() {
+        // An odd modulus small enough for four products to fit in the wide input
+        let modulus =
+            U256::from_be_hex("3fffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        let mod_neg_inv = Limb::montgomery_inv(modulus.as_limbs()[0]);
+
+        let mut values = [U256::ZERO; 8];
+        for (i, v) in values.iter_mut().enumerate() {
+            // Values close to the modulus maximize the size of the sum
+            *v = modulus.wrapping_sub(&U256::from_u64(i as u64 * 0x1234_5678 + 1));
+        }
+
+        for k in 1..=4 {
+            let pairs: [(U256, U256); 4] = core::array::from_fn(|i| (values[i], values[i + 4]));
+            let pairs = &pairs[..k];
+
+            let expected = pairs.iter().fold(U256::ZERO, |acc, (a, b)| {
+                let product = montgomery_reduction(&a.mul_wide(b), &modulus, mod_neg_inv);
+                acc.add_mod(&product, &modulus)
+            });
+
+            let actual =
+                montgomery_reduction_wide(&sum_of_products(pairs), &modulus, mod_neg_inv, k as u32);
+            assert_eq!(actual, expected);
+            assert!(actual < modulus);
+        }
+    }
+
+    #[test]
+    fn matches_montgomery_reduction() {
+        let modulus =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        let mod_neg_inv = Limb::montgomery_inv(modulus.as_limbs()[0]);
+        let a = modulus.wrapping_sub(&Uint::ONE);
+
+        let x = a.mul_wide(&a);
+        assert_eq!(
+            montgomery_reduction_wide(&x, &modulus, mod_neg_inv, 1),
+            montgomery_reduction(&x, &modulus, mod_neg_inv)
+        );
+    }
+
+    #[test]
+    fn unreduced_matches_montgomery_reduction() {
+        let modulus =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        let mod_neg_inv = Limb::montgomery_inv(modulus.as_limbs()[0]);
+        let a = modulus.wrapping_sub(&Uint::ONE);
+        let b = U256::from_u64(0x1234_5678_9abc_def0);
+        let c = modulus.wrapping_sub(&U256::from_u64(0x1234_5678_9abd));
+
+        let mut overflowed = false;
+        for x in [
+            a.mul_wide(&a),
+            a.mul_wide(&b),
+            a.mul_wide(&c),
+            b.mul_wide(&b),
+            (U256::ONE, U256::ZERO),
+            (U256::ZERO, a),
+        ] {
+            let (upper, overflow) = montgomery_reduction_unreduced(&x, &modulus, mod_neg_inv);
+            overflowed |= overflow.is_true_vartime();
+
+            let carry = Limb::from(u8::from(overflow.is_true_vartime()));
+            assert_eq!(
+                upper.sub_mod_with_carry(carry, &modulus, &modulus),
+                montgomery_reduction(&x, &modulus, mod_neg_inv)
+            );
+        }
+
+        // The overflowing case is exercised since `modulus` is close to `R`
+        assert!(overflowed);
+    }
+
+    #[test]
+    fn params_reduce_matches_montgomery_reduction() {
+        let modulus =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        let params = MontgomeryParams::new(&modulus).unwrap();
+        let mod_neg_inv = Limb::montgomery_inv(modulus.as_limbs()[0]);
+        assert_eq!(params.modulus(), &modulus);
+        assert_eq!(params.mod_neg_inv(), mod_neg_inv);
+
+        let a = modulus.wrapping_sub(&Uint::ONE);
+        let b = U256::from_u64(0x1234_5678_9abc_def0);
+        for x in [a.mul_wide(&a), a.mul_wide(&b), (U256::ONE, U256::ZERO)] {
+            assert_eq!(
+                params.reduce(&x),
+                montgomery_reduction(&x, &modulus, mod_neg_inv)
+            );
+        }
+
+        // Reducing `R` and `R^2` yields one and the Montgomery form of one respectively
+        assert_eq!(params.reduce(&(*params.r(), U256::ZERO)), U256::ONE);
+        assert_eq!(params.reduce(&(*params.r2(), U256::ZERO)), *params.r());
+    }
+
+    #[test]
+    fn params_new_const() {
+        const MODULUS: U256 =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        const PARAMS: MontgomeryParams<{ U256::LIMBS }> = MontgomeryParams::new_const(MODULUS);
+
+        assert_eq!(PARAMS, MontgomeryParams::new(&MODULUS).unwrap());
+        assert_eq!(PARAMS.reduce(&(*PARAMS.r(), U256::ZERO)), U256::ONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn params_new_const_even_modulus() {
+        MontgomeryParams::new_const(U256::from_u8(2));
+    }
+
+    #[test]
+    fn params_reject_even_modulus() {
+        for modulus in [
+            U256::ZERO,
+            U256::from_u8(2),
+            U256::MAX.wrapping_sub(&U256::ONE),
+        ] {
+            assert!(bool::from(MontgomeryParams::new(&modulus).is_none()));
+        }
+        assert!(bool::from(MontgomeryParams::new(&U256::MAX).is_some()));
+    }
+}
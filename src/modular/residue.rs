@@ -13,7 +13,7 @@ use core::{fmt::Debug, marker::PhantomData};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "rand_core")]
-use crate::{rand_core::CryptoRngCore, NonZero, Random, RandomMod};
+use crate::{rand_core::RngCore, uint::rand::random_mod_core, NonZero, Random};
 
 #[cfg(feature = "serde")]
 use {
@@ -203,8 +203,11 @@ where
     MOD: ResidueParams<LIMBS>,
 {
     #[inline]
-    fn random(rng: &mut impl CryptoRngCore) -> Self {
-        Self::new(&Uint::random_mod(rng, &NonZero::from_uint(MOD::MODULUS)))
+    fn random(rng: &mut impl RngCore) -> Self {
+        let mut n = Uint::ZERO;
+        let modulus = NonZero::from_uint(MOD::MODULUS);
+        random_mod_core(rng, &mut n, &modulus, modulus.bits_vartime());
+        Self::new(&n)
     }
 }
 
@@ -15,40 +15,21 @@ macro_rules! impl_modulus {
     ($name:ident, $uint_type:ty, $value:expr) => {
         #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
         pub struct $name {}
+        impl $name {
+            const MONTGOMERY_PARAMS: $crate::modular::MontgomeryParams<{ <$uint_type>::LIMBS }> =
+                $crate::modular::MontgomeryParams::new_const(<$uint_type>::from_be_hex($value));
+        }
         impl<const DLIMBS: usize> $crate::modular::ResidueParams<{ <$uint_type>::LIMBS }> for $name
         where
             $uint_type: $crate::ConcatMixed<MixedOutput = $crate::Uint<DLIMBS>>,
         {
             const LIMBS: usize = <$uint_type>::LIMBS;
-            const MODULUS: $uint_type = {
-                let res = <$uint_type>::from_be_hex($value);
-
-                // Check that the modulus is odd
-                if res.as_limbs()[0].0 & 1 == 0 {
-                    panic!("modulus must be odd");
-                }
-
-                res
-            };
-            const R: $uint_type = $crate::Uint::MAX
-                .const_rem(&Self::MODULUS)
-                .0
-                .wrapping_add(&$crate::Uint::ONE);
-            const R2: $uint_type =
-                $crate::Uint::const_rem_wide(Self::R.square_wide(), &Self::MODULUS).0;
-            const MOD_NEG_INV: $crate::Limb = $crate::Limb(
-                $crate::Word::MIN.wrapping_sub(
-                    Self::MODULUS
-                        .inv_mod2k_vartime($crate::Word::BITS)
-                        .as_limbs()[0]
-                        .0,
-                ),
-            );
-            const R3: $uint_type = $crate::modular::montgomery_reduction(
-                &Self::R2.square_wide(),
-                &Self::MODULUS,
-                Self::MOD_NEG_INV,
-            );
+            // `MontgomeryParams::new_const` panics if the modulus is even
+            const MODULUS: $uint_type = *Self::MONTGOMERY_PARAMS.modulus();
+            const R: $uint_type = *Self::MONTGOMERY_PARAMS.r();
+            const R2: $uint_type = *Self::MONTGOMERY_PARAMS.r2();
+            const MOD_NEG_INV: $crate::Limb = Self::MONTGOMERY_PARAMS.mod_neg_inv();
+            const R3: $uint_type = Self::MONTGOMERY_PARAMS.reduce(&Self::R2.square_wide());
         }
     };
 }
@@ -228,4 +228,31 @@ mod tests {
 
         assert_eq!(res, expected);
     }
+
+    #[cfg(all(feature = "alloc", feature = "rand"))]
+    #[test]
+    fn test_powmod_matches_boxed() {
+        use crate::{
+            modular::{BoxedResidue, BoxedResidueParams},
+            BoxedUint, NonZero, Random,
+        };
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let params = BoxedResidueParams::new(Modulus::MODULUS.into()).unwrap();
+
+        let modulus = NonZero::new(Modulus::MODULUS).unwrap();
+
+        for _ in 0..10 {
+            let base = U256::random(&mut rng).rem(&modulus);
+            let exponent = U256::random(&mut rng);
+
+            let expected = BoxedResidue::new(base.into(), params.clone())
+                .pow(&exponent.into())
+                .retrieve();
+            let actual = const_residue!(base, Modulus).pow(&exponent).retrieve();
+            assert_eq!(BoxedUint::from(actual), expected);
+        }
+    }
 }
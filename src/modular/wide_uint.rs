@@ -0,0 +1,121 @@
+//! Wide accumulator for sums of products which are reduced at once.
+
+use super::reduction::montgomery_reduction;
+use crate::{Limb, Uint};
+
+/// Double-width accumulator for sums of products of [`Uint`]s, e.g. inner products over a field,
+/// allowing a single Montgomery reduction for the whole sum rather than one per product.
+///
+/// Carries out of the `2 * Uint::BITS` bits of the `(lower, upper)` pair are kept in an extra
+/// [`Limb`], so (up to `Word::MAX` of them) arbitrary products can be accumulated.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WideUint<const LIMBS: usize> {
+    lower: Uint<LIMBS>,
+    upper: Uint<LIMBS>,
+    carry: Limb,
+}
+
+impl<const LIMBS: usize> WideUint<LIMBS> {
+    /// The accumulator containing zero.
+    pub const ZERO: Self = Self {
+        lower: Uint::ZERO,
+        upper: Uint::ZERO,
+        carry: Limb::ZERO,
+    };
+
+    /// Adds the double-width value `lower_upper` (e.g. as returned by [`Uint::mul_wide`]) to the
+    /// accumulator.
+    pub fn add_assign_wide(&mut self, lower_upper: &(Uint<LIMBS>, Uint<LIMBS>)) {
+        let (lower, carry) = self.lower.adc(&lower_upper.0, Limb::ZERO);
+        let (upper, carry) = self.upper.adc(&lower_upper.1, carry);
+        self.lower = lower;
+        self.upper = upper;
+        self.carry = self.carry.wrapping_add(carry);
+    }
+
+    /// Adds the product `a * b` to the accumulator.
+    pub fn add_mul(&mut self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) {
+        self.add_assign_wide(&a.mul_wide(b));
+    }
+
+    /// Returns the accumulated value as `(lower, upper, carry)`, where the value is
+    /// `lower + upper * 2^BITS + carry * 2^(2 * BITS)`.
+    pub const fn as_parts(&self) -> (Uint<LIMBS>, Uint<LIMBS>, Limb) {
+        (self.lower, self.upper, self.carry)
+    }
+
+    /// Performs Montgomery reduction of the accumulated value, i.e. computes
+    /// `self * R^-1 mod modulus` where `R = 2^BITS`.
+    ///
+    /// If the accumulated products are of values in Montgomery form, the result is the Montgomery
+    /// form of their sum of products.
+    pub const fn reduce(&self, modulus: &Uint<LIMBS>, mod_neg_inv: Limb) -> Uint<LIMBS> {
+        // Reduce everything above the lower half modulo `modulus` first, so that the input to the
+        // Montgomery reduction is less than `modulus * R` regardless of how many products were
+        // accumulated; this doesn't change the result as it's a multiple of `R`.
+        let (upper, _) = Uint::const_rem_wide((self.upper, Uint::from_word(self.carry.0)), modulus);
+        montgomery_reduction(&(self.lower, upper), modulus, mod_neg_inv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WideUint;
+    use crate::{
+        impl_modulus,
+        modular::residue::{Residue, ResidueParams},
+        Limb, U256,
+    };
+
+    impl_modulus!(
+        Modulus,
+        U256,
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+    );
+
+    type Fe = Residue<Modulus, { U256::LIMBS }>;
+
+    #[test]
+    fn zero() {
+        assert_eq!(WideUint::default(), WideUint::<{ U256::LIMBS }>::ZERO);
+        assert_eq!(
+            WideUint::ZERO.reduce(&Modulus::MODULUS, Modulus::MOD_NEG_INV),
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn inner_product() {
+        let xs: [Fe; 5] =
+            core::array::from_fn(|i| Fe::new(&U256::MAX.wrapping_sub(&U256::from_u64(i as u64))));
+        let ys: [Fe; 5] = core::array::from_fn(|i| Fe::new(&U256::from_u64(0xdead_beef << i)));
+
+        let mut acc = WideUint::ZERO;
+        let mut expected = Fe::ZERO;
+        for (x, y) in xs.iter().zip(&ys) {
+            acc.add_mul(x.as_montgomery(), y.as_montgomery());
+            expected += x * y;
+        }
+
+        let result = acc.reduce(&Modulus::MODULUS, Modulus::MOD_NEG_INV);
+        assert_eq!(&result, expected.as_montgomery());
+    }
+
+    #[test]
+    fn carries_beyond_double_width() {
+        // Each `MAX * MAX` product is just below `2^512`, so these overflow into the carry limb
+        let mut acc = WideUint::ZERO;
+        for _ in 0..3 {
+            acc.add_mul(&U256::MAX, &U256::MAX);
+        }
+        assert_eq!(acc.as_parts().2, Limb::from(2u8));
+
+        // `MAX` is congruent to its reduction, so treat that as a Montgomery form and sum squares
+        let max = Fe::from_montgomery(U256::MAX.wrapping_sub(&Modulus::MODULUS));
+        let square = max.square();
+        let expected = square + square + square;
+
+        let result = acc.reduce(&Modulus::MODULUS, Modulus::MOD_NEG_INV);
+        assert_eq!(&result, expected.as_montgomery());
+    }
+}
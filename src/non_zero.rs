@@ -12,7 +12,7 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use crate::{ArrayEncoding, ByteArray};
 
 #[cfg(feature = "rand_core")]
-use {crate::Random, rand_core::CryptoRngCore};
+use {crate::Random, rand_core::RngCore};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{
@@ -153,7 +153,7 @@ where
     T: Random + Zero,
 {
     /// Generate a random `NonZero<T>`.
-    fn random(mut rng: &mut impl CryptoRngCore) -> Self {
+    fn random(mut rng: &mut impl RngCore) -> Self {
         // Use rejection sampling to eliminate zero values.
         // While this method isn't constant-time, the attacker shouldn't learn
         // anything about unrelated outputs so long as `rng` is a CSRNG.
@@ -367,12 +367,35 @@ impl<T: Serialize + Zero> Serialize for NonZero<T> {
     }
 }
 
-#[cfg(all(test, feature = "serde"))]
+#[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::{NonZero, U64};
-    use bincode::ErrorKind;
+    use crate::{NonZero, Uint, U128};
 
+    #[cfg(feature = "serde")]
+    use {crate::U64, bincode::ErrorKind};
+
+    #[test]
+    fn new() {
+        assert!(bool::from(NonZero::new(U128::ZERO).is_none()));
+        assert!(bool::from(NonZero::new(U128::ONE).is_some()));
+        assert!(bool::from(NonZero::new(U128::MAX).is_some()));
+
+        let (_, is_some) = NonZero::<U128>::const_new(Uint::ZERO);
+        assert!(!is_some.is_true_vartime());
+    }
+
+    #[test]
+    fn deref() {
+        let n = U128::from_u64(0x0011_2233_4455_6677);
+        let nz = NonZero::new(n).unwrap();
+        assert_eq!(*nz, n);
+        assert_eq!(nz.as_ref(), &n);
+        assert_eq!(nz.wrapping_add(&U128::ONE), n.wrapping_add(&U128::ONE));
+        assert_eq!(nz.get(), n);
+    }
+
+    #[cfg(feature = "serde")]
     #[test]
     fn serde() {
         let test =
@@ -390,6 +413,7 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn serde_owned() {
         let test =
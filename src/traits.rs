@@ -12,7 +12,7 @@ use subtle::{
 };
 
 #[cfg(feature = "rand_core")]
-use rand_core::CryptoRngCore;
+use rand_core::{CryptoRngCore, RngCore};
 
 /// Integers whose representation takes a bounded amount of space.
 pub trait Bounded {
@@ -164,10 +164,17 @@ pub trait Constants: ZeroConstant {
 }
 
 /// Random number generation support.
+///
+/// This accepts any [`RngCore`], including non-cryptographic generators which are convenient for
+/// testing, so the output is only as unpredictable as `rng` is. Anything secret, e.g. private
+/// keys, must be generated with a [`CryptoRngCore`], as required by [`RandomMod`] and the prime
+/// generation APIs.
 #[cfg(feature = "rand_core")]
 pub trait Random: Sized {
-    /// Generate a cryptographically secure random value.
-    fn random(rng: &mut impl CryptoRngCore) -> Self;
+    /// Generate a random value.
+    ///
+    /// The value is cryptographically secure only if `rng` is a CSRNG.
+    fn random(rng: &mut impl RngCore) -> Self;
 }
 
 /// Modular random number generation support.
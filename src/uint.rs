@@ -16,13 +16,16 @@ mod cmp;
 mod concat;
 mod div;
 pub(crate) mod div_limb;
-mod encoding;
-mod from;
+pub(crate) mod encoding;
+pub(crate) mod from;
+mod gcd;
 mod inv_mod;
 pub(crate) mod mul;
 mod mul_mod;
 mod neg;
 mod neg_mod;
+mod pow_mod;
+pub(crate) mod reciprocal;
 mod resize;
 mod shl;
 mod shr;
@@ -36,11 +39,19 @@ mod array;
 #[cfg(feature = "alloc")]
 pub(crate) mod boxed;
 #[cfg(feature = "rand_core")]
-mod rand;
+pub(crate) mod rand;
 
-use crate::{Bounded, Constants, Encoding, FixedInteger, Integer, Limb, Word, ZeroConstant};
-use core::fmt;
-use subtle::{Choice, ConditionallySelectable};
+use crate::{
+    Bounded, Constants, Encoding, FixedInteger, Integer, Limb, WideWord, Word, ZeroConstant,
+};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "alloc")]
+use {crate::BoxedUint, alloc::vec::Vec};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -65,9 +76,7 @@ use zeroize::DefaultIsZeroes;
 ///
 /// [RLP]: https://eth.wiki/fundamentals/rlp
 // TODO(tarcieri): make generic around a specified number of bits.
-// Our PartialEq impl only differs from the default one by being constant-time, so this is safe
-#[allow(clippy::derived_hash_with_manual_eq)]
-#[derive(Copy, Clone, Hash)]
+#[derive(Copy, Clone)]
 pub struct Uint<const LIMBS: usize> {
     /// Inner limb array. Stored from least significant to most significant.
     pub(crate) limbs: [Limb; LIMBS],
@@ -80,6 +89,12 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// The value `1`.
     pub const ONE: Self = Self::from_u8(1);
 
+    /// The value `2`.
+    pub const TWO: Self = Self::from_u8(2);
+
+    /// The value `3`.
+    pub const THREE: Self = Self::from_u8(3);
+
     /// Maximum value this [`Uint`] can express.
     pub const MAX: Self = Self {
         limbs: [Limb::MAX; LIMBS],
@@ -105,6 +120,8 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Create a [`Uint`] from an array of [`Word`]s (i.e. word-sized unsigned
     /// integers).
+    ///
+    /// The words are in little-endian order, i.e. the least significant word comes first.
     #[inline]
     pub const fn from_words(arr: [Word; LIMBS]) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -118,6 +135,25 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Self { limbs }
     }
 
+    /// Create a [`Uint`] from a slice of little-endian [`Word`]s.
+    ///
+    /// Slices shorter than `LIMBS` are zero-extended. Longer slices are accepted as long as the
+    /// excess words are all zero, otherwise `None` is returned since the value doesn't fit.
+    pub fn from_words_le(words: &[Word]) -> CtOption<Self> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut fits = Choice::from(1);
+
+        for (i, &word) in words.iter().enumerate() {
+            if i < LIMBS {
+                limbs[i] = Limb(word);
+            } else {
+                fits &= word.ct_eq(&0);
+            }
+        }
+
+        CtOption::new(Self { limbs }, fits)
+    }
+
     /// Create an array of [`Word`]s (i.e. word-sized unsigned integers) from
     /// a [`Uint`].
     #[inline]
@@ -134,6 +170,8 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Borrow the inner limbs as an array of [`Word`]s.
+    ///
+    /// The words are in little-endian order, i.e. the least significant word comes first.
     pub const fn as_words(&self) -> &[Word; LIMBS] {
         // SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
         #[allow(trivial_casts, unsafe_code)]
@@ -143,6 +181,8 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Borrow the inner limbs as a mutable array of [`Word`]s.
+    ///
+    /// The words are in little-endian order, i.e. the least significant word comes first.
     pub fn as_words_mut(&mut self) -> &mut [Word; LIMBS] {
         // SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
         #[allow(trivial_casts, unsafe_code)]
@@ -165,6 +205,41 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     pub const fn to_limbs(self) -> [Limb; LIMBS] {
         self.limbs
     }
+
+    /// Copy the limbs of this [`Uint`] into a [`Vec`], e.g. for code which is generic over
+    /// [`Uint`] and `BoxedUint`.
+    #[cfg(feature = "alloc")]
+    pub fn to_limb_vec(&self) -> Vec<Limb> {
+        self.limbs.to_vec()
+    }
+
+    /// Convert this [`Uint`] into a [`BoxedUint`] with the same value and a precision of
+    /// [`Uint::BITS`].
+    #[cfg(feature = "alloc")]
+    pub fn to_boxed(&self) -> BoxedUint {
+        BoxedUint::from(*self)
+    }
+
+    /// Construct a [`Uint`] by applying `f` to each of the limbs of `self`.
+    ///
+    /// This isn't a `const fn`, but is handy for prototyping limb-wise operations. It's
+    /// constant-time as long as `f` is.
+    pub fn map_limbs(&self, f: impl Fn(Limb) -> Limb) -> Self {
+        Self {
+            limbs: self.limbs.map(f),
+        }
+    }
+
+    /// Construct a [`Uint`] by applying `f` to each pair of corresponding limbs of `self` and
+    /// `other`.
+    ///
+    /// This isn't a `const fn`, but is handy for prototyping limb-wise operations. It's
+    /// constant-time as long as `f` is.
+    pub fn zip_limbs(&self, other: &Self, f: impl Fn(Limb, Limb) -> Limb) -> Self {
+        Self {
+            limbs: core::array::from_fn(|i| f(self.limbs[i], other.limbs[i])),
+        }
+    }
 }
 
 impl<const LIMBS: usize> AsRef<[Word; LIMBS]> for Uint<LIMBS> {
@@ -201,6 +276,12 @@ impl<const LIMBS: usize> ConditionallySelectable for Uint<LIMBS> {
 
         Self { limbs }
     }
+
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        for i in 0..LIMBS {
+            self.limbs[i].conditional_assign(&other.limbs[i], choice);
+        }
+    }
 }
 
 impl<const LIMBS: usize> Bounded for Uint<LIMBS> {
@@ -253,34 +334,217 @@ impl<const LIMBS: usize> ZeroConstant for Uint<LIMBS> {
     const ZERO: Self = Self::ZERO;
 }
 
+/// Hashes the numeric value, so equal [`Uint`]s and `BoxedUint`s hash the same regardless of
+/// their precision.
+impl<const LIMBS: usize> Hash for Uint<LIMBS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_limbs(&self.limbs, state);
+    }
+}
+
+/// Hash the integer represented by the little endian `limbs`, ignoring any most significant zero
+/// limbs.
+///
+/// This is variable-time with respect to the number of such limbs.
+pub(crate) fn hash_limbs<H: Hasher>(limbs: &[Limb], state: &mut H) {
+    let len = limbs
+        .iter()
+        .rposition(|limb| limb.0 != 0)
+        .map_or(0, |i| i + 1);
+    limbs[..len].hash(state);
+}
+
 impl<const LIMBS: usize> fmt::Debug for Uint<LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Uint(0x{self:X})")
+        write!(f, "Uint(0x")?;
+        for limb in self.limbs.iter().rev() {
+            write!(f, "{limb:X}")?;
+        }
+        write!(f, ")")
     }
 }
 
 impl<const LIMBS: usize> fmt::Display for Uint<LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::UpperHex::fmt(self, f)
+        let mut limbs = self.limbs;
+        let mut chunks = [[0; 2]; LIMBS];
+        fmt_decimal(&mut limbs, &mut chunks, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Binary for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_radix(&self.limbs, f, 1, "0b", false)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Octal for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_radix(&self.limbs, f, 3, "0o", false)
     }
 }
 
 impl<const LIMBS: usize> fmt::LowerHex for Uint<LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for limb in self.limbs.iter().rev() {
-            fmt::LowerHex::fmt(limb, f)?;
-        }
-        Ok(())
+        fmt_radix(&self.limbs, f, 4, "0x", false)
     }
 }
 
 impl<const LIMBS: usize> fmt::UpperHex for Uint<LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for limb in self.limbs.iter().rev() {
-            fmt::UpperHex::fmt(limb, f)?;
+        fmt_radix(&self.limbs, f, 4, "0x", true)
+    }
+}
+
+/// Format the integer represented by the little endian `limbs` in base `2^bits_per_digit`,
+/// honoring the alternate (`#`), width, fill, alignment and zero-padding flags of the formatter
+/// in the same way as the primitive integer types.
+///
+/// Leading zeros are suppressed, and the integer zero is formatted as a single `0` digit.
+pub(crate) fn fmt_radix(
+    limbs: &[Limb],
+    f: &mut fmt::Formatter<'_>,
+    bits_per_digit: u32,
+    prefix: &str,
+    upper: bool,
+) -> fmt::Result {
+    use core::fmt::Write;
+
+    const LOWER: &[u8; 16] = b"0123456789abcdef";
+    const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+    let mask = (1 << bits_per_digit) - 1;
+    let digit = |i: u32| -> usize {
+        let pos = i * bits_per_digit;
+        let (idx, offset) = ((pos / Limb::BITS) as usize, pos % Limb::BITS);
+        let mut d = limbs.get(idx).map_or(0, |limb| limb.0 >> offset);
+
+        // Digits may straddle two limbs when `bits_per_digit` doesn't divide `Limb::BITS`
+        if offset + bits_per_digit > Limb::BITS {
+            d |= limbs
+                .get(idx + 1)
+                .map_or(0, |limb| limb.0 << (Limb::BITS - offset));
+        }
+
+        (d & mask) as usize
+    };
+
+    let total_bits = limbs.len() as u32 * Limb::BITS;
+    let ndigits = (total_bits + bits_per_digit - 1) / bits_per_digit;
+    let len = (0..ndigits)
+        .rev()
+        .find(|&i| digit(i) != 0)
+        .map_or(1, |i| i + 1);
+
+    let prefix = if f.alternate() { prefix } else { "" };
+    let digits = if upper { UPPER } else { LOWER };
+    fmt_padded(f, prefix, len as usize, |f| {
+        for i in (0..len).rev() {
+            f.write_char(digits[digit(i)] as char)?;
         }
         Ok(())
+    })
+}
+
+/// Largest power of ten which fits in a [`Word`], along with its number of zeros.
+#[cfg(target_pointer_width = "32")]
+const DECIMAL_CHUNK: (Word, usize) = (1_000_000_000, 9);
+
+/// Largest power of ten which fits in a [`Word`], along with its number of zeros.
+#[cfg(target_pointer_width = "64")]
+const DECIMAL_CHUNK: (Word, usize) = (10_000_000_000_000_000_000, 19);
+
+/// Format the integer represented by the little endian `limbs` in decimal, honoring the width,
+/// fill, alignment and zero-padding flags of the formatter in the same way as the primitive
+/// integer types.
+///
+/// The integer is split into base-[`DECIMAL_CHUNK`] digits by repeated division, so `limbs` is
+/// overwritten and `chunks` must have at least as many entries as there are limbs, which is
+/// enough since every chunk accounts for more than half a limb.
+pub(crate) fn fmt_decimal(
+    limbs: &mut [Limb],
+    chunks: &mut [[Word; 2]],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let (divisor, chunk_digits) = DECIMAL_CHUNK;
+    let mut nchunks = 0;
+
+    loop {
+        let mut rem: Word = 0;
+        for limb in limbs.iter_mut().rev() {
+            let x = ((rem as WideWord) << Word::BITS) | (limb.0 as WideWord);
+            limb.0 = (x / divisor as WideWord) as Word;
+            rem = (x % divisor as WideWord) as Word;
+        }
+
+        chunks[nchunks / 2][nchunks % 2] = rem;
+        nchunks += 1;
+
+        if limbs.iter().all(|limb| limb.0 == 0) {
+            break;
+        }
+    }
+
+    let chunk = |i: usize| chunks[i / 2][i % 2];
+    let top = chunk(nchunks - 1);
+    let mut top_digits = 1;
+    let mut rest = top / 10;
+    while rest != 0 {
+        top_digits += 1;
+        rest /= 10;
+    }
+
+    let len = top_digits + (nchunks - 1) * chunk_digits;
+    fmt_padded(f, "", len, |f| {
+        write!(f, "{top}")?;
+        for i in (0..nchunks - 1).rev() {
+            write!(f, "{:0width$}", chunk(i), width = chunk_digits)?;
+        }
+        Ok(())
+    })
+}
+
+/// Write `len` digits using `write_digits` along with `prefix`, padded according to the width,
+/// fill, alignment and zero-padding flags of the formatter.
+fn fmt_padded(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    len: usize,
+    write_digits: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    use core::fmt::{Alignment, Write};
+
+    let padding = f
+        .width()
+        .map_or(0, |width| width.saturating_sub(prefix.len() + len));
+
+    // Zero-padding goes between the prefix and the digits, and overrides the alignment
+    let (pre, post) = match f.align() {
+        _ if f.sign_aware_zero_pad() => (0, 0),
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        _ => (padding, 0),
+    };
+
+    for _ in 0..pre {
+        f.write_char(f.fill())?;
+    }
+
+    f.write_str(prefix)?;
+
+    if f.sign_aware_zero_pad() {
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+    }
+
+    write_digits(f)?;
+
+    for _ in 0..post {
+        f.write_char(f.fill())?;
     }
+
+    Ok(())
 }
 
 #[cfg(feature = "serde")]
@@ -312,6 +576,8 @@ where
     }
 }
 
+/// NOTE: [`Uint`] is `Copy` and therefore can't implement `ZeroizeOnDrop`: copies must be
+/// zeroized explicitly, e.g. using `zeroize::Zeroizing`.
 #[cfg(feature = "zeroize")]
 impl<const LIMBS: usize> DefaultIsZeroes for Uint<LIMBS> {}
 
@@ -414,15 +680,52 @@ pub use extra_sizes::*;
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::{Encoding, U128};
+    use crate::{Encoding, Word, U128};
     use subtle::ConditionallySelectable;
 
     #[cfg(feature = "alloc")]
-    use alloc::format;
+    use {crate::U256, alloc::format};
 
     #[cfg(feature = "serde")]
     use crate::U64;
 
+    #[test]
+    fn small_constants() {
+        assert_eq!(U128::TWO, U128::ONE << 1);
+        assert_eq!(U128::THREE, U128::TWO.wrapping_add(&U128::ONE));
+        assert_eq!(U128::ZERO, U128::from_u8(0));
+
+        const N: U128 = U128::from_u8(255);
+        assert_eq!(N, U128::from_be_hex("000000000000000000000000000000FF"));
+    }
+
+    #[test]
+    fn to_limbs_round_trip() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let limbs = n.to_limbs();
+        assert_eq!(&limbs, n.as_limbs());
+        assert_eq!(U128::new(limbs), n);
+
+        #[cfg(feature = "alloc")]
+        {
+            let vec = n.to_limb_vec();
+            assert_eq!(vec.as_slice(), n.as_limbs());
+            assert_eq!(crate::BoxedUint::from(vec), crate::BoxedUint::from(n));
+            assert_eq!(&*crate::BoxedUint::from(n).into_limbs(), n.as_limbs());
+        }
+    }
+
+    #[test]
+    fn map_zip_limbs() {
+        let a = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let b = U128::from_be_hex("f0f0f0f0f0f0f0f00f0f0f0f0f0f0f0f");
+
+        assert_eq!(a.map_limbs(|limb| !limb), !a);
+        assert_eq!(a.map_limbs(|limb| limb), a);
+        assert_eq!(a.zip_limbs(&b, |x, y| x.bitand(y)), a & b);
+        assert_eq!(a.zip_limbs(&b, |x, y| x.bitxor(y)), a ^ b);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn debug() {
@@ -438,23 +741,98 @@ mod tests {
     #[cfg(feature = "alloc")]
     #[test]
     fn display() {
-        let hex = "AAAAAAAABBBBBBBBCCCCCCCCDDDDDDDD";
-        let n = U128::from_be_hex(hex);
-
         use alloc::string::ToString;
-        assert_eq!(hex, n.to_string());
 
-        let hex = "AAAAAAAABBBBBBBB0000000000000000";
-        let n = U128::from_be_hex(hex);
-        assert_eq!(hex, n.to_string());
+        assert_eq!(U128::ZERO.to_string(), "0");
+        assert_eq!(U128::ONE.to_string(), "1");
+        assert_eq!(
+            U128::from_u64(10_000_000_000_000_000_000).to_string(),
+            "10000000000000000000"
+        );
+        assert_eq!(U128::from_u64(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(
+            U256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
 
-        let hex = "AAAAAAAABBBBBBBB00000000DDDDDDDD";
-        let n = U128::from_be_hex(hex);
-        assert_eq!(hex, n.to_string());
+        for hex in [
+            "AAAAAAAABBBBBBBBCCCCCCCCDDDDDDDD",
+            "AAAAAAAABBBBBBBB0000000000000000",
+            "AAAAAAAABBBBBBBB00000000DDDDDDDD",
+            "000000000000000000000000DDDDDDDD",
+        ] {
+            let n = U128::from_be_hex(hex);
+            let expected = u128::from_str_radix(hex, 16).unwrap().to_string();
+            assert_eq!(n.to_string(), expected);
+        }
+    }
 
-        let hex = "AAAAAAAABBBBBBBB0CCCCCCCDDDDDDDD";
-        let n = U128::from_be_hex(hex);
-        assert_eq!(hex, n.to_string());
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display_flags() {
+        let n = U128::from_u64(1234);
+        assert_eq!(format!("{:>8}", n), "    1234");
+        assert_eq!(format!("{:<8}|", n), "1234    |");
+        assert_eq!(format!("{:08}", n), "00001234");
+        assert_eq!(format!("{:2}", n), "1234");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fmt_hex() {
+        let hex = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+        let n = U256::from_be_hex(hex);
+        assert_eq!(format!("{:x}", n), hex.to_lowercase());
+        assert_eq!(format!("{:X}", n), hex);
+
+        let hex = "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0";
+        let n = U256::from_be_hex(hex);
+        assert_eq!(format!("{:x}", n), hex.to_lowercase());
+
+        // Leading zeros are suppressed
+        let n =
+            U256::from_be_hex("0000000000000000000000000000000000000000000000000000000000c0ffee");
+        assert_eq!(format!("{:x}", n), "c0ffee");
+        assert_eq!(format!("{:#X}", n), "0xC0FFEE");
+        assert_eq!(format!("{:x}", U256::ZERO), "0");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fmt_flags() {
+        let n = U128::from_u64(0xc0ffee);
+        assert_eq!(format!("{:#x}", n), "0xc0ffee");
+        assert_eq!(format!("{:010x}", n), "0000c0ffee");
+        assert_eq!(format!("{:#010x}", n), "0x00c0ffee");
+        assert_eq!(format!("{:>8x}", n), "  c0ffee");
+        assert_eq!(format!("{:<8x}|", n), "c0ffee  |");
+        assert_eq!(format!("{:*^10x}", n), "**c0ffee**");
+        assert_eq!(format!("{:4x}", n), "c0ffee");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fmt_matches_u128() {
+        for x in [
+            0u128,
+            1,
+            7,
+            8,
+            0o1234567,
+            0xc0ffee,
+            u128::from(u64::MAX),
+            u128::from(u64::MAX) + 1,
+            u128::MAX,
+        ] {
+            let n = U128::from_u128(x);
+            assert_eq!(format!("{:b}", n), format!("{:b}", x));
+            assert_eq!(format!("{:#b}", n), format!("{:#b}", x));
+            assert_eq!(format!("{:o}", n), format!("{:o}", x));
+            assert_eq!(format!("{:#o}", n), format!("{:#o}", x));
+            assert_eq!(format!("{:x}", n), format!("{:x}", x));
+            assert_eq!(format!("{:#X}", n), format!("{:#X}", x));
+            assert_eq!(format!("{:#050o}", n), format!("{:#050o}", x));
+        }
     }
 
     #[test]
@@ -485,6 +863,74 @@ mod tests {
         assert_eq!(b, select_1);
     }
 
+    #[test]
+    fn conditional_assign() {
+        let a = U128::from_be_hex("00002222444466668888AAAACCCCEEEE");
+        let b = U128::from_be_hex("11113333555577779999BBBBDDDDFFFF");
+
+        let mut n = a;
+        n.conditional_assign(&b, 0.into());
+        assert_eq!(n, a);
+
+        n.conditional_assign(&b, 1.into());
+        assert_eq!(n, b);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn conditional_assign_matches_ct_select() {
+        use crate::{CtChoice, Random, U256};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..16 {
+            let a = U256::random(&mut rng);
+            let b = U256::random(&mut rng);
+
+            for c in [CtChoice::FALSE, CtChoice::TRUE] {
+                let mut n = a;
+                n.conditional_assign(&b, c.into());
+                assert_eq!(n, U256::ct_select(&a, &b, c));
+            }
+        }
+    }
+
+    #[test]
+    fn as_words() {
+        let n = U128::from_be_hex("80000000000000000000000000000001");
+        let words = n.as_words();
+        assert_eq!(words[0], 1);
+        assert_eq!(words[U128::LIMBS - 1], 1 << (Word::BITS - 1));
+        assert_eq!(U128::from_words(*words), n);
+    }
+
+    #[test]
+    fn as_words_mut() {
+        let mut n = U128::ZERO;
+        n.as_words_mut()[0] = 1;
+        n.as_words_mut()[U128::LIMBS - 1] |= 1 << (Word::BITS - 1);
+        assert_eq!(n, U128::from_be_hex("80000000000000000000000000000001"));
+    }
+
+    #[test]
+    fn from_words_le() {
+        let n = U128::from_be_hex("80000000000000000000000000000001");
+        assert_eq!(U128::from_words_le(n.as_words()).unwrap(), n);
+
+        // Short slices are zero-extended
+        assert_eq!(U128::from_words_le(&[]).unwrap(), U128::ZERO);
+        assert_eq!(U128::from_words_le(&[5]).unwrap(), U128::from_u8(5));
+
+        // Excess words must be zero
+        let mut words = [0; U128::LIMBS + 2];
+        words[..U128::LIMBS].copy_from_slice(n.as_words());
+        assert_eq!(U128::from_words_le(&words).unwrap(), n);
+        words[U128::LIMBS + 1] = 1;
+        assert!(bool::from(U128::from_words_le(&words).is_none()));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde() {
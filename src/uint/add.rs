@@ -1,11 +1,29 @@
 //! [`Uint`] addition operations.
 
 use crate::{Checked, CheckedAdd, CtChoice, Limb, Uint, Wrapping, Zero};
-use core::ops::{Add, AddAssign};
+use core::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `a + b + carry`, returning the result along with the new carry.
+    ///
+    /// As with [`Limb::adc`], the incoming `carry` must be `0` or `1` and the returned carry is
+    /// always `0` or `1`, so additions of multi-word integers can be chained by passing the carry
+    /// out of the lower half into the addition of the upper half:
+    ///
+    /// ```
+    /// use crypto_bigint::{Limb, U128};
+    ///
+    /// // (MAX, 0) + (1, 0) as two-word little endian integers
+    /// let (lo, carry) = U128::MAX.adc(&U128::ONE, Limb::ZERO);
+    /// assert_eq!((lo, carry), (U128::ZERO, Limb::ONE));
+    ///
+    /// let (hi, carry) = U128::ZERO.adc(&U128::ZERO, carry);
+    /// assert_eq!((hi, carry), (U128::ONE, Limb::ZERO));
+    /// ```
     #[inline(always)]
     pub const fn adc(&self, rhs: &Self, mut carry: Limb) -> (Self, Limb) {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -32,6 +50,13 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         self.adc(rhs, Limb::ZERO).0
     }
 
+    /// Perform wrapping addition, returning the wrapped result along with the truthy value if an
+    /// overflow has occurred, like [`u64::overflowing_add`].
+    pub const fn overflowing_add(&self, rhs: &Self) -> (Self, CtChoice) {
+        let (res, carry) = self.adc(rhs, Limb::ZERO);
+        (res, CtChoice::from_word_lsb(carry.0))
+    }
+
     /// Perform wrapping addition, returning the truthy value as the second element of the tuple
     /// if an overflow has occurred.
     pub(crate) const fn conditional_wrapping_add(
@@ -154,9 +179,41 @@ impl<const LIMBS: usize> AddAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS
     }
 }
 
+/// Sums the values using [`Uint::wrapping_add`], i.e. modulo `2^BITS`.
+///
+/// The sum of an empty iterator is zero. Use [`Checked`] to detect overflow.
+impl<const LIMBS: usize> Sum for Uint<LIMBS> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc.wrapping_add(&x))
+    }
+}
+
+/// Sums the values using [`Uint::wrapping_add`], i.e. modulo `2^BITS`.
+///
+/// The sum of an empty iterator is zero. Use [`Checked`] to detect overflow.
+impl<'a, const LIMBS: usize> Sum<&'a Uint<LIMBS>> for Uint<LIMBS> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc.wrapping_add(x))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{CheckedAdd, Limb, U128};
+    use crate::{CheckedAdd, Limb, U128, U256};
+
+    #[test]
+    fn sum() {
+        let values = [U256::ONE, U256::from_u8(2), U256::from_u8(3)];
+        assert_eq!(values.iter().sum::<U256>(), U256::from_u8(6));
+        assert_eq!(values.into_iter().sum::<U256>(), U256::from_u8(6));
+        assert_eq!(core::iter::empty::<U256>().sum::<U256>(), U256::ZERO);
+
+        // Overflow wraps around
+        assert_eq!(
+            [U256::MAX, U256::from_u8(2)].iter().sum::<U256>(),
+            U256::ONE
+        );
+    }
 
     #[test]
     fn adc_no_carry() {
@@ -203,4 +260,58 @@ mod tests {
         let result = U128::MAX.checked_add(&U128::ONE);
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn overflowing_add() {
+        let (res, overflow) = U128::MAX.overflowing_add(&U128::ONE);
+        assert_eq!(res, U128::ZERO);
+        assert!(overflow.is_true_vartime());
+
+        let (res, overflow) = U128::MAX.overflowing_add(&U128::MAX);
+        assert_eq!(res, U128::MAX.wrapping_sub(&U128::ONE));
+        assert!(overflow.is_true_vartime());
+
+        let (res, overflow) = U128::ONE.overflowing_add(&U128::ONE);
+        assert_eq!(res, U128::from_u8(2));
+        assert!(!overflow.is_true_vartime());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn overflowing_add_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U128::random(&mut rng);
+            let b = U128::random(&mut rng);
+            let (res, overflow) = a.overflowing_add(&b);
+            assert_eq!(
+                (u128::from(res), overflow.is_true_vartime()),
+                u128::from(a).overflowing_add(u128::from(b))
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn saturating_add_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U128::random(&mut rng);
+            let b = U128::random(&mut rng);
+            assert_eq!(
+                u128::from(a.saturating_add(&b)),
+                u128::from(a).saturating_add(u128::from(b))
+            );
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! [`Uint`] modular addition operations.
 
-use crate::{AddMod, Limb, Uint};
+use crate::{AddMod, CtChoice, Limb, Uint};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self + rhs mod p`.
@@ -19,6 +19,34 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         w.wrapping_add(&p.bitand_limb(mask))
     }
 
+    /// Computes `self + addend mod p` if `choice` is truthy, and returns `self` otherwise.
+    ///
+    /// Assumes `self + addend` as unbounded integer is `< 2p`, and `self < p`.
+    pub const fn conditional_add_mod(&self, addend: &Self, p: &Self, choice: CtChoice) -> Self {
+        let addend = Self::ct_select(&Self::ZERO, addend, choice);
+        self.add_mod(&addend, p)
+    }
+
+    /// Computes `2 * self mod p`.
+    ///
+    /// Assumes `self < p`.
+    pub const fn double_mod(&self, p: &Self) -> Self {
+        self.add_mod(self, p)
+    }
+
+    /// Computes `self / 2 mod p`, i.e. the `x` such that `2 * x = self mod p`.
+    ///
+    /// Assumes `self < p` and that `p` is odd.
+    pub const fn halve_mod(&self, p: &Self) -> Self {
+        // If `self` is odd, `self + p` is even and congruent to `self`, so it can be halved exactly.
+        // The addition may carry out of the top limb, so the carry is shifted back in.
+        let is_odd = self.ct_is_odd();
+        let (sum, carry) = self.adc(&Self::ct_select(&Self::ZERO, p, is_odd), Limb::ZERO);
+        let mut ret = sum.shr1();
+        ret.limbs[LIMBS - 1].0 |= carry.0 << Limb::HI_BIT;
+        ret
+    }
+
     /// Computes `self + rhs mod p` for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -47,7 +75,7 @@ impl<const LIMBS: usize> AddMod for Uint<LIMBS> {
 
 #[cfg(all(test, feature = "rand"))]
 mod tests {
-    use crate::{Limb, NonZero, Random, RandomMod, Uint, U256};
+    use crate::{CtChoice, Limb, NonZero, Random, RandomMod, Uint, U256};
     use rand_core::SeedableRng;
 
     #[test]
@@ -66,6 +94,61 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn conditional_add_mod() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let p = NonZero::<U256>::random(&mut rng);
+
+        for _ in 0..100 {
+            let a = U256::random_mod(&mut rng, &p);
+            let b = U256::random_mod(&mut rng, &p);
+
+            assert_eq!(a.conditional_add_mod(&b, &p, CtChoice::FALSE), a);
+            assert_eq!(
+                a.conditional_add_mod(&b, &p, CtChoice::TRUE),
+                a.add_mod(&b, &p)
+            );
+        }
+    }
+
+    #[test]
+    fn double_halve_mod() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        // NIST P-256 modulus
+        let p =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        let p_nz = NonZero::new(p).unwrap();
+        let minus_one = p.wrapping_sub(&U256::ONE);
+
+        assert_eq!(
+            U256::ONE.halve_mod(&p),
+            p.shr_vartime(1).wrapping_add(&U256::ONE)
+        );
+        assert_eq!(minus_one.halve_mod(&p), p.shr_vartime(1));
+        assert_eq!(minus_one.double_mod(&p), minus_one.add_mod(&minus_one, &p));
+        let (double, carry) = U256::MAX.double();
+        assert_eq!(double, U256::MAX.wrapping_sub(&U256::ONE));
+        assert!(carry.is_true_vartime());
+
+        for _ in 0..100 {
+            let a = U256::random_mod(&mut rng, &p_nz);
+            let doubled = a.double_mod(&p);
+            assert_eq!(doubled, a.add_mod(&a, &p));
+            assert_eq!(doubled.halve_mod(&p), a);
+
+            let halved = a.halve_mod(&p);
+            assert!(halved < p);
+            assert_eq!(halved.double_mod(&p), a);
+
+            let (double, carry) = a.double();
+            assert_eq!(double, a.wrapping_add(&a));
+            assert_eq!(
+                carry.is_true_vartime(),
+                a.bit(U256::BITS - 1).is_true_vartime()
+            );
+        }
+    }
+
     macro_rules! test_add_mod_special {
         ($size:expr, $test_name:ident) => {
             #[test]
@@ -28,6 +28,14 @@ impl<const LIMBS: usize> Not for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> Not for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn not(self) -> Uint<LIMBS> {
+        Uint::not(self)
+    }
+}
+
 impl<const LIMBS: usize> Not for Wrapping<Uint<LIMBS>> {
     type Output = Self;
 
@@ -45,4 +53,13 @@ mod tests {
         assert_eq!(U128::ZERO.not(), U128::MAX);
         assert_eq!(U128::MAX.not(), U128::ZERO);
     }
+
+    #[test]
+    fn bitnot_operator() {
+        let n = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        let x = U128::from_u128(n);
+        assert_eq!(!x, x.not());
+        assert_eq!(!&x, x.not());
+        assert_eq!(u128::from(!x), !n);
+    }
 }
@@ -139,4 +139,29 @@ mod tests {
         let result = U128::ZERO.wrapping_xor(&U128::ONE);
         assert_eq!(result, U128::ONE);
     }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn bitxor_operators() {
+        let (a, b) = (
+            0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128,
+            0xffff_0000_ffff_0000_0f0f_0f0f_f0f0_f0f0u128,
+        );
+        let (x, y) = (U128::from_u128(a), U128::from_u128(b));
+        let expected = U128::from_u128(a ^ b);
+
+        assert_eq!(x.bitxor(&y), expected);
+        assert_eq!(x ^ y, expected);
+        assert_eq!(x ^ &y, expected);
+        assert_eq!(&x ^ y, expected);
+        assert_eq!(&x ^ &y, expected);
+
+        let mut z = x;
+        z ^= y;
+        assert_eq!(z, expected);
+
+        let mut z = x;
+        z ^= &y;
+        assert_eq!(z, expected);
+    }
 }
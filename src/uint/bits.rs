@@ -53,6 +53,45 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Limb::BITS * (i as u32 + 1) - limb.leading_zeros()
     }
 
+    /// Returns the base 2 logarithm of `self`, rounded down, i.e. `self.bits() - 1`.
+    ///
+    /// The computation is constant-time, but the zero check is not.
+    ///
+    /// Panics if `self` is zero.
+    #[inline]
+    pub const fn ilog2(&self) -> u32 {
+        assert!(
+            self.ct_is_nonzero().is_true_vartime(),
+            "argument of integer logarithm must be positive"
+        );
+        self.bits() - 1
+    }
+
+    /// Returns the logarithm of `self` with respect to an arbitrary `base`, rounded down.
+    ///
+    /// This is computed by repeated division, and is variable-time with respect to `self` and
+    /// `base`. Use [`Uint::ilog2`] for base 2.
+    ///
+    /// Panics if `self` is zero or `base` is less than 2.
+    pub const fn ilog(&self, base: &Self) -> u32 {
+        assert!(
+            self.bits_vartime() != 0,
+            "argument of integer logarithm must be positive"
+        );
+        assert!(
+            base.bits_vartime() > 1,
+            "base of integer logarithm must be at least 2"
+        );
+
+        let mut n = *self;
+        let mut log = 0;
+        while !Uint::ct_lt(&n, base).is_true_vartime() {
+            n = n.wrapping_div_vartime(base);
+            log += 1;
+        }
+        log
+    }
+
     /// Calculate the number of leading zeros in the binary representation of this number.
     pub const fn leading_zeros(&self) -> u32 {
         let limbs = self.as_limbs();
@@ -170,9 +209,45 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         count
     }
 
-    /// Sets the bit at `index` to 0 or 1 depending on the value of `bit_value`.
-    pub(crate) const fn set_bit(self, index: u32, bit_value: CtChoice) -> Self {
-        let mut result = self;
+    /// Calculate the number of ones in the binary representation of this number.
+    pub const fn count_ones(&self) -> u32 {
+        let limbs = self.as_limbs();
+
+        let mut count = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            count += limbs[i].0.count_ones();
+            i += 1;
+        }
+
+        count
+    }
+
+    /// Calculate the number of zeros in the binary representation of this number.
+    pub const fn count_zeros(&self) -> u32 {
+        Self::BITS - self.count_ones()
+    }
+
+    /// Reverses the order of all [`Uint::BITS`] bits, so the bit at position `i` moves to
+    /// position `Self::BITS - 1 - i`.
+    pub const fn bitreverse(&self) -> Self {
+        let mut limbs = [Limb::ZERO; LIMBS];
+
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = Limb(self.limbs[LIMBS - 1 - i].0.reverse_bits());
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Returns a copy of `self` with the bit at `index` set to 0 or 1 depending on the value of
+    /// `bit_value`.
+    ///
+    /// Indices out of range (`index >= Self::BITS`) leave the value unchanged.
+    pub const fn set_bit(&self, index: u32, bit_value: CtChoice) -> Self {
+        let mut result = *self;
         let limb_num = index / Limb::BITS;
         let index_in_limb = index % Limb::BITS;
         let index_mask = 1 << index_in_limb;
@@ -187,11 +262,32 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
         result
     }
+
+    /// Returns a copy of `self` with the bit at `index` set to 1.
+    ///
+    /// Indices out of range (`index >= Self::BITS`) leave the value unchanged.
+    pub const fn with_bit(&self, index: u32) -> Self {
+        self.set_bit(index, CtChoice::TRUE)
+    }
+
+    /// Returns `1 << bit`, i.e. the value with only the bit at index `bit` set.
+    ///
+    /// Like [`Uint::shl`], returns zero if `bit >= Self::BITS`.
+    pub const fn one_hot(bit: u32) -> Self {
+        Self::ZERO.with_bit(bit)
+    }
+
+    /// Returns a copy of `self` with the bit at `index` set to 0.
+    ///
+    /// Indices out of range (`index >= Self::BITS`) leave the value unchanged.
+    pub const fn clear_bit(&self, index: u32) -> Self {
+        self.set_bit(index, CtChoice::FALSE)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{CtChoice, U256};
+    use crate::{CtChoice, U128, U256};
 
     fn uint_with_bits_at(positions: &[u32]) -> U256 {
         let mut result = U256::ZERO;
@@ -225,6 +321,81 @@ mod tests {
         assert!(!u.bit(260).is_true_vartime());
     }
 
+    #[test]
+    fn bits() {
+        assert_eq!(U256::ZERO.bits(), 0);
+        assert_eq!(U256::ONE.bits(), 1);
+        assert_eq!((U256::ONE << 200).bits(), 201);
+        assert_eq!(uint_with_bits_at(&[3, 64, 127]).bits(), 128);
+        assert_eq!(U256::MAX.bits(), 256);
+    }
+
+    #[test]
+    fn bits_vartime() {
+        assert_eq!(U256::ZERO.bits_vartime(), 0);
+        assert_eq!(U256::ONE.bits_vartime(), 1);
+        assert_eq!((U256::ONE << 200).bits_vartime(), 201);
+        assert_eq!(uint_with_bits_at(&[3, 64, 127]).bits_vartime(), 128);
+        assert_eq!(U256::MAX.bits_vartime(), 256);
+    }
+
+    #[test]
+    fn ilog2() {
+        assert_eq!(U256::ONE.ilog2(), 0);
+        assert_eq!(U256::from_u8(2).ilog2(), 1);
+        assert_eq!(U256::from_u8(255).ilog2(), 7);
+        assert_eq!((U256::ONE << 200).ilog2(), 200);
+        assert_eq!(U256::MAX.ilog2(), 255);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_zero() {
+        U256::ZERO.ilog2();
+    }
+
+    #[test]
+    fn ilog() {
+        let ten = U256::from_u8(10);
+        assert_eq!(U256::ONE.ilog(&ten), 0);
+        assert_eq!(U256::from_u16(9).ilog(&ten), 0);
+        assert_eq!(U256::from_u16(10).ilog(&ten), 1);
+        assert_eq!(U256::from_u16(999).ilog(&ten), 2);
+        assert_eq!(U256::from_u16(1000).ilog(&ten), 3);
+        assert_eq!(U256::from_u8(5).ilog(&U256::MAX), 0);
+        assert_eq!(U256::MAX.ilog(&U256::MAX), 1);
+        assert_eq!(U256::MAX.ilog(&U256::from_u8(2)), U256::MAX.ilog2());
+        assert_eq!(U256::MAX.ilog(&U256::from_u8(16)), 63);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog_base_one() {
+        U256::from_u8(5).ilog(&U256::ONE);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn ilog_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let n = U128::random(&mut rng).shr_vartime(rng.next_u32() % 128);
+            let expected = u128::from(n);
+            if expected == 0 {
+                continue;
+            }
+
+            assert_eq!(n.ilog2(), expected.ilog2());
+            assert_eq!(n.ilog(&U128::from_u8(10)), expected.ilog10());
+            assert_eq!(n.ilog(&U128::from_u8(7)), expected.ilog(7));
+        }
+    }
+
     #[test]
     fn leading_zeros() {
         let u = uint_with_bits_at(&[256 - 16, 256 - 79, 256 - 207]);
@@ -333,6 +504,61 @@ mod tests {
         assert_eq!(u.trailing_ones_vartime(), 256);
     }
 
+    #[test]
+    fn count_ones() {
+        assert_eq!(U256::ZERO.count_ones(), 0);
+        assert_eq!(U256::ONE.count_ones(), 1);
+        assert_eq!(U256::MAX.count_ones(), 256);
+        assert_eq!(uint_with_bits_at(&[0, 16, 79, 150, 255]).count_ones(), 5);
+    }
+
+    #[test]
+    fn count_zeros() {
+        assert_eq!(U256::ZERO.count_zeros(), 256);
+        assert_eq!(U256::ONE.count_zeros(), 255);
+        assert_eq!(U256::MAX.count_zeros(), 0);
+        assert_eq!(uint_with_bits_at(&[0, 16, 79, 150, 255]).count_zeros(), 251);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn count_ones_matches_u128() {
+        use crate::{Random, U128};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let n = U128::random(&mut rng);
+            let expected = u128::from(n);
+            assert_eq!(n.count_ones(), expected.count_ones());
+            assert_eq!(n.count_zeros(), expected.count_zeros());
+        }
+    }
+
+    #[test]
+    fn bitreverse() {
+        for i in 0..U128::BITS {
+            let u = U128::ONE.shl_vartime(i);
+            assert_eq!(u.bitreverse(), U128::ONE.shl_vartime(U128::BITS - 1 - i));
+        }
+
+        for i in [0, 1, 63, 64, 100, 128, 200, 255] {
+            let u = uint_with_bits_at(&[i]);
+            assert_eq!(u.bitreverse(), uint_with_bits_at(&[U256::BITS - 1 - i]));
+        }
+
+        let u = uint_with_bits_at(&[16, 79, 150]);
+        assert_eq!(u.bitreverse(), uint_with_bits_at(&[105, 176, 239]));
+        assert_eq!(u.bitreverse().bitreverse(), u);
+        assert_eq!(U256::ZERO.bitreverse(), U256::ZERO);
+        assert_eq!(U256::MAX.bitreverse(), U256::MAX);
+
+        let n = U128::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+        assert_eq!(u128::from(n.bitreverse()), u128::from(n).reverse_bits());
+    }
+
     #[test]
     fn set_bit() {
         let u = uint_with_bits_at(&[16, 79, 150]);
@@ -359,4 +585,43 @@ mod tests {
             uint_with_bits_at(&[16, 79])
         );
     }
+
+    #[test]
+    fn set_bit_each_bit() {
+        for i in 0..U128::BITS {
+            let u = U128::ZERO.with_bit(i);
+            assert_eq!(u, U128::ONE.shl_vartime(i));
+            assert!(u.bit(i).is_true_vartime());
+            assert_eq!(u.count_ones(), 1);
+            assert_eq!(u.clear_bit(i), U128::ZERO);
+
+            let u = U128::MAX.clear_bit(i);
+            assert!(!u.bit(i).is_true_vartime());
+            assert_eq!(u.count_zeros(), 1);
+            assert_eq!(u.set_bit(i, CtChoice::TRUE), U128::MAX);
+        }
+    }
+
+    #[test]
+    fn set_bit_out_of_range() {
+        let u = U128::from_u64(0x1234_5678_9abc_def0);
+        assert_eq!(u.with_bit(U128::BITS), u);
+        assert_eq!(u.clear_bit(U128::BITS), u);
+        assert_eq!(u.set_bit(u32::MAX, CtChoice::TRUE), u);
+    }
+
+    #[test]
+    fn one_hot() {
+        for bit in [0, 1, 63, 64, 65, 127, 200, 255] {
+            assert_eq!(U256::one_hot(bit), U256::ONE << bit);
+        }
+        assert_eq!(U256::one_hot(256), U256::ZERO);
+        assert_eq!(U256::one_hot(u32::MAX), U256::ZERO);
+
+        const HI_BIT: U128 = U128::one_hot(127);
+        assert_eq!(
+            HI_BIT,
+            U128::from_be_hex("80000000000000000000000000000000")
+        );
+    }
 }
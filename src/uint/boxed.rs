@@ -6,31 +6,42 @@ mod bit_and;
 mod bit_or;
 mod bits;
 mod cmp;
+mod crt;
 mod ct;
 mod div;
 pub(crate) mod encoding;
+mod gcd;
 mod inv_mod;
 mod mul;
 mod mul_mod;
 mod neg;
+mod pow_mod;
 mod shl;
 mod shr;
+mod sqrt;
 mod sub;
 mod sub_mod;
 
 mod bit_not;
 mod bit_xor;
+#[cfg(feature = "hmac-drbg")]
+mod hmac_drbg;
 mod neg_mod;
 #[cfg(feature = "rand_core")]
+mod prime;
+#[cfg(feature = "rand_core")]
 mod rand;
 
-use crate::{Integer, Limb, NonZero, Uint, Word, Zero, U128, U64};
+use crate::{
+    uint::{fmt_decimal, fmt_radix, from::limbs_to_u128},
+    Integer, Limb, NonZero, TryFromUintError, Uint, Word, Zero, U128, U64,
+};
 use alloc::{boxed::Box, vec, vec::Vec};
 use core::{fmt, mem};
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "zeroize")]
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Fixed-precision heap-allocated big unsigned integer.
 ///
@@ -40,8 +51,7 @@ use zeroize::Zeroize;
 /// Unlike many other heap-allocated big integer libraries, this type is not
 /// arbitrary precision and will wrap at its fixed-precision rather than
 /// automatically growing.
-#[allow(clippy::derived_hash_with_manual_eq)]
-#[derive(Clone, Hash)]
+#[derive(Clone)]
 pub struct BoxedUint {
     /// Boxed slice containing limbs.
     ///
@@ -91,6 +101,11 @@ impl BoxedUint {
             .fold(Choice::from(1), |acc, limb| acc & limb.is_zero())
     }
 
+    /// Is this [`BoxedUint`] not equal to zero?
+    pub fn is_nonzero(&self) -> Choice {
+        !self.is_zero()
+    }
+
     /// Is this [`BoxedUint`] equal to one?
     pub fn is_one(&self) -> Choice {
         let mut iter = self.limbs.iter();
@@ -131,6 +146,8 @@ impl BoxedUint {
 
     /// Create a [`BoxedUint`] from an array of [`Word`]s (i.e. word-sized unsigned
     /// integers).
+    ///
+    /// The words are in little-endian order, i.e. the least significant word comes first.
     #[inline]
     pub fn from_words(words: impl IntoIterator<Item = Word>) -> Self {
         Self {
@@ -138,6 +155,51 @@ impl BoxedUint {
         }
     }
 
+    /// Create a [`BoxedUint`] from a slice of little-endian `u64` digits, e.g. as exported by
+    /// other bignum libraries.
+    ///
+    /// The precision is `64 * digits.len()` bits, including any trailing zero digits, which
+    /// don't affect the value. An empty slice is interpreted as zero.
+    pub fn from_digits_le(digits: &[u64]) -> Self {
+        if digits.is_empty() {
+            return Self::zero();
+        }
+
+        Self::from_words(
+            digits
+                .iter()
+                .flat_map(|&digit| U64::from_u64(digit).to_words()),
+        )
+    }
+
+    /// Create a double-width [`BoxedUint`] from the `lo` and `hi` halves of a value, i.e. with the
+    /// value `lo + hi * 2^Uint::<LIMBS>::BITS` and twice the precision.
+    ///
+    /// The halves are in the same order as the `(lower, upper)` pairs produced by
+    /// [`Uint::mul_wide`] and consumed by Montgomery reduction.
+    pub fn from_uint_pair<const LIMBS: usize>(lo: &Uint<LIMBS>, hi: &Uint<LIMBS>) -> Self {
+        let mut limbs = Vec::with_capacity(LIMBS * 2);
+        limbs.extend_from_slice(lo.as_limbs());
+        limbs.extend_from_slice(hi.as_limbs());
+        limbs.into()
+    }
+
+    /// Convert this [`BoxedUint`] into a [`Uint`] with `LIMBS` limbs, zero-extending it if it has
+    /// fewer limbs.
+    ///
+    /// Returns `None` if any of the limbs beyond `LIMBS` are nonzero, i.e. if the value does not
+    /// fit in [`Uint<LIMBS>`]. This check is constant-time with respect to the value.
+    pub fn to_uint<const LIMBS: usize>(&self) -> CtOption<Uint<LIMBS>> {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let nlimbs = self.nlimbs().min(LIMBS);
+        limbs[..nlimbs].copy_from_slice(&self.limbs[..nlimbs]);
+
+        let is_some = self.limbs[nlimbs..]
+            .iter()
+            .fold(Choice::from(1), |acc, limb| acc & limb.is_zero());
+        CtOption::new(Uint::new(limbs), is_some)
+    }
+
     /// Create a boxed slice of [`Word`]s (i.e. word-sized unsigned integers) from
     /// a [`BoxedUint`].
     #[inline]
@@ -146,6 +208,8 @@ impl BoxedUint {
     }
 
     /// Borrow the inner limbs as a slice of [`Word`]s.
+    ///
+    /// The words are in little-endian order, i.e. the least significant word comes first.
     pub fn as_words(&self) -> &[Word] {
         // SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
         #[allow(trivial_casts, unsafe_code)]
@@ -155,6 +219,8 @@ impl BoxedUint {
     }
 
     /// Borrow the inner limbs as a mutable slice of [`Word`]s.
+    ///
+    /// The words are in little-endian order, i.e. the least significant word comes first.
     pub fn as_words_mut(&mut self) -> &mut [Word] {
         // SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
         #[allow(trivial_casts, unsafe_code)]
@@ -173,14 +239,30 @@ impl BoxedUint {
         self.limbs.as_mut()
     }
 
+    /// Iterate over the limbs of this [`BoxedUint`].
+    ///
+    /// The limbs are in little-endian order, i.e. the least significant limb comes first.
+    pub fn iter_limbs(&self) -> impl DoubleEndedIterator<Item = &Limb> + ExactSizeIterator {
+        self.limbs.iter()
+    }
+
+    /// Iterate mutably over the limbs of this [`BoxedUint`].
+    ///
+    /// The limbs are in little-endian order, i.e. the least significant limb comes first.
+    pub fn iter_limbs_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut Limb> + ExactSizeIterator {
+        self.limbs.iter_mut()
+    }
+
     /// Convert this [`BoxedUint`] into its inner limbs.
     pub fn to_limbs(&self) -> Box<[Limb]> {
         self.limbs.clone()
     }
 
     /// Convert this [`BoxedUint`] into its inner limbs.
-    pub fn into_limbs(self) -> Box<[Limb]> {
-        self.limbs
+    pub fn into_limbs(mut self) -> Box<[Limb]> {
+        mem::take(&mut self.limbs)
     }
 
     /// Get the number of limbs in this [`BoxedUint`].
@@ -210,6 +292,21 @@ impl BoxedUint {
         ret
     }
 
+    /// Narrow this type's precision to the given number of bits, rounded up to a multiple of
+    /// [`Limb::BITS`].
+    ///
+    /// Returns `None` if any of the truncated limbs are nonzero, i.e. if the value does not fit
+    /// in the narrower precision.
+    ///
+    /// Panics if `at_least_bits_precision` is larger than the current precision.
+    pub fn narrow(&self, at_least_bits_precision: u32) -> CtOption<BoxedUint> {
+        let ret = self.shorten(at_least_bits_precision);
+        let is_some = self.limbs[ret.nlimbs()..]
+            .iter()
+            .fold(Choice::from(1), |acc, limb| acc & limb.is_zero());
+        CtOption::new(ret, is_some)
+    }
+
     /// Perform a carry chain-like operation over the limbs of the inputs,
     /// constructing a result from the returned limbs and carry which is
     /// widened to the same width as the widest input.
@@ -235,19 +332,31 @@ impl BoxedUint {
         (limbs.into(), carry)
     }
 
-    /// Iterate over the limbs of the inputs, applying the given function, and
-    /// constructing a result from the returned values.
+    /// Construct a [`BoxedUint`] with the same precision by applying `f` to each of the limbs of
+    /// `self`.
+    ///
+    /// This is handy for prototyping limb-wise operations. It's constant-time as long as `f` is.
+    pub fn map_limbs(&self, f: impl Fn(Limb) -> Limb) -> Self {
+        Self {
+            limbs: self.limbs.iter().copied().map(f).collect(),
+        }
+    }
+
+    /// Construct a [`BoxedUint`] by applying `f` to each pair of corresponding limbs of `self`
+    /// and `other`.
+    ///
+    /// If the precisions differ, the shorter input is zero-extended, so the result has the larger
+    /// of the two precisions.
+    ///
+    /// This is handy for prototyping limb-wise operations. It's constant-time as long as `f` is.
     #[inline]
-    fn map_limbs<F>(lhs: &Self, rhs: &Self, f: F) -> Self
-    where
-        F: Fn(Limb, Limb) -> Limb,
-    {
-        let nlimbs = cmp::max(lhs.nlimbs(), rhs.nlimbs());
+    pub fn zip_limbs(&self, other: &Self, f: impl Fn(Limb, Limb) -> Limb) -> Self {
+        let nlimbs = cmp::max(self.nlimbs(), other.nlimbs());
         let mut limbs = Vec::with_capacity(nlimbs);
 
         for i in 0..nlimbs {
-            let &a = lhs.limbs.get(i).unwrap_or(&Limb::ZERO);
-            let &b = rhs.limbs.get(i).unwrap_or(&Limb::ZERO);
+            let &a = self.limbs.get(i).unwrap_or(&Limb::ZERO);
+            let &b = other.limbs.get(i).unwrap_or(&Limb::ZERO);
             limbs.push(f(a, b));
         }
 
@@ -324,6 +433,22 @@ impl From<u128> for BoxedUint {
     }
 }
 
+impl TryFrom<&BoxedUint> for u64 {
+    type Error = TryFromUintError;
+
+    fn try_from(n: &BoxedUint) -> Result<u64, TryFromUintError> {
+        limbs_to_u128(&n.limbs).and_then(|n| u64::try_from(n).map_err(|_| TryFromUintError))
+    }
+}
+
+impl TryFrom<&BoxedUint> for u128 {
+    type Error = TryFromUintError;
+
+    fn try_from(n: &BoxedUint) -> Result<u128, TryFromUintError> {
+        limbs_to_u128(&n.limbs)
+    }
+}
+
 impl From<Limb> for BoxedUint {
     fn from(limb: Limb) -> Self {
         vec![limb; 1].into()
@@ -424,49 +549,210 @@ impl Zeroize for BoxedUint {
     }
 }
 
+/// Wipes the limbs when dropped, so temporaries holding secret-derived values (e.g. the tables
+/// used by exponentiation) are zeroized without any explicit calls.
+#[cfg(feature = "zeroize")]
+impl Drop for BoxedUint {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for BoxedUint {}
+
 impl fmt::Debug for BoxedUint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "BoxedUint(0x{self:X})")
+        write!(f, "BoxedUint(0x")?;
+
+        if self.limbs.is_empty() {
+            write!(f, "{:X}", Limb::ZERO)?;
+        }
+
+        for limb in self.limbs.iter().rev() {
+            write!(f, "{limb:X}")?;
+        }
+        write!(f, ")")
     }
 }
 
 impl fmt::Display for BoxedUint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::UpperHex::fmt(self, f)
+        let mut limbs = self.limbs.clone();
+        let mut chunks = vec![[0; 2]; self.nlimbs().max(1)];
+        fmt_decimal(&mut limbs, &mut chunks, f)
     }
 }
 
-impl fmt::LowerHex for BoxedUint {
+impl fmt::Binary for BoxedUint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.limbs.is_empty() {
-            return fmt::LowerHex::fmt(&Limb::ZERO, f);
-        }
+        fmt_radix(&self.limbs, f, 1, "0b", false)
+    }
+}
 
-        for limb in self.limbs.iter().rev() {
-            fmt::LowerHex::fmt(limb, f)?;
-        }
-        Ok(())
+impl fmt::Octal for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_radix(&self.limbs, f, 3, "0o", false)
     }
 }
 
-impl fmt::UpperHex for BoxedUint {
+impl fmt::LowerHex for BoxedUint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.limbs.is_empty() {
-            return fmt::LowerHex::fmt(&Limb::ZERO, f);
-        }
+        fmt_radix(&self.limbs, f, 4, "0x", false)
+    }
+}
 
-        for limb in self.limbs.iter().rev() {
-            fmt::UpperHex::fmt(limb, f)?;
-        }
-        Ok(())
+impl fmt::UpperHex for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_radix(&self.limbs, f, 4, "0x", true)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::BoxedUint;
-    use crate::Word;
+    use crate::{Encoding, Limb, TryFromUintError, Word, U128, U256};
     use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    #[test]
+    fn try_into_u64() {
+        assert_eq!(u64::try_from(&BoxedUint::from(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(
+            u64::try_from(&BoxedUint::from(u64::MAX).widen(256)),
+            Ok(u64::MAX)
+        );
+        assert_eq!(
+            u64::try_from(&BoxedUint::from(u64::MAX as u128 + 1)),
+            Err(TryFromUintError)
+        );
+    }
+
+    #[test]
+    fn try_into_u128() {
+        for n in [0, 1, u64::MAX as u128, u128::MAX] {
+            assert_eq!(u128::try_from(&BoxedUint::from(n)), Ok(n));
+            assert_eq!(u128::try_from(&BoxedUint::from(n).widen(512)), Ok(n));
+        }
+
+        // Values needing more limbs than fit in a `u128`
+        assert_eq!(u128::try_from(&BoxedUint::max(192)), Err(TryFromUintError));
+    }
+
+    #[test]
+    fn is_zero() {
+        let top = BoxedUint::one_with_precision(256).shl_vartime(255);
+        assert!(bool::from(BoxedUint::zero_with_precision(256).is_zero()));
+        assert!(!bool::from(BoxedUint::one_with_precision(256).is_zero()));
+        assert!(!bool::from(top.is_zero()));
+
+        assert!(!bool::from(
+            BoxedUint::zero_with_precision(256).is_nonzero()
+        ));
+        assert!(bool::from(BoxedUint::one_with_precision(256).is_nonzero()));
+        assert!(bool::from(top.is_nonzero()));
+    }
+
+    #[test]
+    fn is_one() {
+        let one = BoxedUint::one_with_precision(256);
+        assert!(bool::from(one.is_one()));
+        assert!(!bool::from(BoxedUint::zero_with_precision(256).is_one()));
+        assert!(!bool::from(one.shl_vartime(255).bitor(&one).is_one()));
+        assert!(!bool::from(BoxedUint::max(256).is_one()));
+    }
+
+    #[test]
+    fn from_uint_pair() {
+        let lo = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let hi = U128::from_be_hex("ffeeddccbbaa99887766554433221100");
+
+        let mut bytes = hi.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&lo.to_be_bytes());
+        let n = BoxedUint::from_uint_pair(&lo, &hi);
+        assert_eq!(n.bits_precision(), 256);
+        assert_eq!(n, BoxedUint::from_be_slice(&bytes, 256).unwrap());
+
+        // Matches the `(lower, upper)` order of `mul_wide`
+        let product = lo.mul_wide(&hi);
+        assert_eq!(
+            BoxedUint::from_uint_pair(&product.0, &product.1),
+            BoxedUint::from(lo).mul(&BoxedUint::from(hi))
+        );
+    }
+
+    #[test]
+    fn to_uint() {
+        let u = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let boxed = u.to_boxed();
+        assert_eq!(boxed.bits_precision(), U128::BITS);
+        assert_eq!(boxed, BoxedUint::from(u));
+        assert_eq!(Option::<U128>::from(boxed.to_uint()), Some(u));
+
+        // Zero-extends to more limbs, and truncates zero high limbs
+        assert_eq!(
+            Option::<U256>::from(boxed.to_uint()),
+            Some(u.resize::<{ U256::LIMBS }>())
+        );
+        assert_eq!(Option::<U128>::from(boxed.widen(512).to_uint()), Some(u));
+
+        // Oversized values
+        let oversized = boxed.widen(256).shl_vartime(128);
+        assert!(bool::from(oversized.to_uint::<{ U128::LIMBS }>().is_none()));
+        assert!(bool::from(
+            BoxedUint::max(256).to_uint::<{ U128::LIMBS }>().is_none()
+        ));
+        assert_eq!(
+            Option::<U256>::from(BoxedUint::max(256).to_uint()),
+            Some(U256::MAX)
+        );
+    }
+
+    #[test]
+    fn widen_narrow() {
+        let n = BoxedUint::from(0x0123_4567_89ab_cdefu64);
+        let wide = n.widen(256);
+        assert_eq!(wide.bits_precision(), 256);
+        assert_eq!(wide.to_words()[0], n.to_words()[0]);
+        assert_eq!(wide.narrow(64).unwrap(), n);
+        assert_eq!(wide.narrow(256).unwrap(), wide);
+
+        // High bits which would be lost
+        let lossy = BoxedUint::one_with_precision(256).shl_vartime(128);
+        assert!(bool::from(lossy.narrow(128).is_none()));
+        assert!(bool::from(lossy.narrow(192).is_some()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn narrow_larger_precision() {
+        let _ = BoxedUint::zero_with_precision(128).narrow(256);
+    }
+
+    #[test]
+    fn fmt() {
+        use alloc::format;
+
+        let n = BoxedUint::from(0xc0ffeeu32).widen(256);
+        assert_eq!(format!("{:x}", n), "c0ffee");
+        assert_eq!(format!("{:#X}", n), "0xC0FFEE");
+        assert_eq!(format!("{:#o}", n), format!("{:#o}", 0xc0ffeeu32));
+        assert_eq!(format!("{:b}", n), format!("{:b}", 0xc0ffeeu32));
+        assert_eq!(format!("{:#010x}", n), "0x00c0ffee");
+        assert_eq!(format!("{:x}", BoxedUint::zero()), "0");
+        assert_eq!(format!("{}", n), "12648430");
+        assert_eq!(format!("{:>10}", n), "  12648430");
+        assert_eq!(format!("{}", BoxedUint::zero()), "0");
+        assert_eq!(format!("{}", BoxedUint::zero_with_precision(0)), "0");
+        assert_eq!(
+            format!("{}", BoxedUint::max(256)),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(
+            format!("{:?}", n),
+            "BoxedUint(0x0000000000000000000000000000000000000000000000000000000000C0FFEE)"
+        );
+    }
 
     #[test]
     fn from_word_vec() {
@@ -475,4 +761,81 @@ mod tests {
         assert_eq!(uint.nlimbs(), 4);
         assert_eq!(uint.as_words(), words);
     }
+
+    #[test]
+    fn from_digits_le() {
+        let n = BoxedUint::from_be_slice(&hex!("80000000000000000000000000000001"), 128).unwrap();
+        let digits = [1, 1 << 63];
+        let m = BoxedUint::from_digits_le(&digits);
+        assert_eq!(m, n);
+        assert_eq!(BoxedUint::from_words(m.as_words().iter().copied()), n);
+
+        // Trailing zero digits widen the precision without changing the value
+        let m = BoxedUint::from_digits_le(&[1, 1 << 63, 0]);
+        assert_eq!(m.bits_precision(), 192);
+        assert_eq!(m, n.widen(192));
+        assert_eq!(m.bits(), 128);
+
+        assert_eq!(BoxedUint::from_digits_le(&[]), BoxedUint::zero());
+    }
+
+    #[test]
+    fn as_words() {
+        let n = BoxedUint::from_be_slice(&hex!("80000000000000000000000000000001"), 128).unwrap();
+        let words = n.as_words();
+        assert_eq!(words[0], 1);
+        assert_eq!(words[n.nlimbs() - 1], 1 << (Word::BITS - 1));
+        assert_eq!(BoxedUint::from_words(words.iter().copied()), n);
+    }
+
+    #[test]
+    fn map_zip_limbs() {
+        let a = BoxedUint::from(0x0011_2233_4455_6677_8899_aabb_ccdd_eeffu128);
+        let b = BoxedUint::from(0xf0f0_f0f0_f0f0_f0f0_0f0f_0f0f_0f0f_0f0fu128);
+
+        assert_eq!(a.map_limbs(|limb| !limb), a.not());
+        assert_eq!(a.map_limbs(|limb| limb).bits_precision(), 128);
+        assert_eq!(a.zip_limbs(&b, |x, y| x.bitand(y)), a.bitand(&b));
+
+        // The shorter input is zero-extended
+        let c = a.zip_limbs(&b.widen(256), |x, y| x.bitor(y));
+        assert_eq!(c.bits_precision(), 256);
+        assert_eq!(c, a.bitor(&b).widen(256));
+    }
+
+    #[test]
+    fn iter_limbs() {
+        let mut n =
+            BoxedUint::from_be_slice(&hex!("80000000000000000000000000000001"), 128).unwrap();
+        assert_eq!(n.iter_limbs().len(), n.nlimbs());
+        assert_eq!(n.iter_limbs().copied().collect::<Vec<_>>(), n.as_limbs());
+        assert_eq!(n.iter_limbs().next(), Some(&Limb::ONE));
+        assert_eq!(n.iter_limbs().last(), n.as_limbs().last());
+
+        let expected: Vec<_> = n
+            .iter_limbs()
+            .map(|limb| limb.wrapping_add(Limb::ONE))
+            .collect();
+        for limb in n.iter_limbs_mut() {
+            *limb = limb.wrapping_add(Limb::ONE);
+        }
+        assert_eq!(n.as_limbs(), expected);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::{Zeroize, ZeroizeOnDrop};
+
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<BoxedUint>();
+
+        let mut n = BoxedUint::max(256);
+        n.zeroize();
+        assert_eq!(n, BoxedUint::zero_with_precision(256));
+
+        // Moving the limbs out doesn't wipe them
+        let limbs = BoxedUint::max(128).into_limbs();
+        assert!(limbs.iter().all(|limb| *limb == Limb::MAX));
+    }
 }
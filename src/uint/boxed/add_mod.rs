@@ -1,6 +1,7 @@
 //! [`BoxedUint`] modular addition operations.
 
 use crate::{AddMod, BoxedUint, Limb};
+use subtle::{Choice, ConditionallySelectable};
 
 impl BoxedUint {
     /// Computes `self + rhs mod p`.
@@ -23,6 +24,14 @@ impl BoxedUint {
         // modulus.
         w.wrapping_add(&p.bitand_limb(mask))
     }
+
+    /// Computes `self + rhs mod p` if `choice` is truthy, otherwise returns `self`.
+    ///
+    /// Assumes `self` and `rhs` are `< p` and have the same precision as `p`.
+    pub fn conditional_add_mod(&self, rhs: &Self, p: &Self, choice: Choice) -> Self {
+        let mask = Limb::conditional_select(&Limb::ZERO, &Limb::MAX, choice);
+        self.add_mod(&rhs.bitand_limb(mask), p)
+    }
 }
 
 impl AddMod for BoxedUint {
@@ -37,6 +46,7 @@ impl AddMod for BoxedUint {
 mod tests {
     use super::BoxedUint;
     use hex_literal::hex;
+    use subtle::Choice;
 
     // TODO(tarcieri): proptests
 
@@ -67,4 +77,30 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn conditional_add_mod() {
+        let a = BoxedUint::from_be_slice(
+            &hex!("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56"),
+            256,
+        )
+        .unwrap();
+        let b = BoxedUint::from_be_slice(
+            &hex!("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251"),
+            256,
+        )
+        .unwrap();
+        let n = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+            256,
+        )
+        .unwrap();
+
+        let actual = a.conditional_add_mod(&b, &n, Choice::from(1));
+        assert_eq!(actual, a.add_mod(&b, &n));
+        assert!(actual < n);
+
+        let actual = a.conditional_add_mod(&b, &n, Choice::from(0));
+        assert_eq!(actual, a);
+    }
 }
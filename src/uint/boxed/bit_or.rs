@@ -8,7 +8,7 @@ impl BoxedUint {
     /// Computes bitwise `a & b`.
     #[inline(always)]
     pub fn bitor(&self, rhs: &Self) -> Self {
-        Self::map_limbs(self, rhs, |a, b| a.bitor(b))
+        self.zip_limbs(rhs, |a, b| a.bitor(b))
     }
 
     /// Perform wrapping bitwise `OR`.
@@ -9,7 +9,7 @@ impl BoxedUint {
     /// Computes bitwise `a ^ b`.
     #[inline(always)]
     pub fn bitxor(&self, rhs: &Self) -> Self {
-        Self::map_limbs(self, rhs, |a, b| a.bitxor(b))
+        self.zip_limbs(rhs, |a, b| a.bitxor(b))
     }
 
     /// Perform wrapping bitwise `XOR``.
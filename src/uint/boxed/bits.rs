@@ -55,6 +55,16 @@ impl BoxedUint {
         count
     }
 
+    /// Calculate the number of ones in the binary representation of this number.
+    pub fn count_ones(&self) -> u32 {
+        self.limbs.iter().map(|limb| limb.0.count_ones()).sum()
+    }
+
+    /// Calculate the number of zeros in the binary representation of this number.
+    pub fn count_zeros(&self) -> u32 {
+        self.bits_precision() - self.count_ones()
+    }
+
     /// Sets the bit at `index` to 0 or 1 depending on the value of `bit_value`.
     pub(crate) fn set_bit(&mut self, index: u32, bit_value: Choice) {
         let limb_num = (index / Limb::BITS) as usize;
@@ -101,6 +111,28 @@ mod tests {
         assert_eq!(87, n2.bits());
     }
 
+    #[test]
+    fn bits_vartime() {
+        assert_eq!(0, BoxedUint::zero().bits_vartime());
+        assert_eq!(1, BoxedUint::one_with_precision(256).bits_vartime());
+        assert_eq!(201, uint_with_bits_at(&[5, 200]).bits_vartime());
+        assert_eq!(128, BoxedUint::max(128).bits_vartime());
+    }
+
+    #[test]
+    fn count_ones() {
+        assert_eq!(0, BoxedUint::zero_with_precision(256).count_ones());
+        assert_eq!(128, BoxedUint::max(128).count_ones());
+        assert_eq!(3, uint_with_bits_at(&[16, 79, 150]).count_ones());
+    }
+
+    #[test]
+    fn count_zeros() {
+        assert_eq!(256, BoxedUint::zero_with_precision(256).count_zeros());
+        assert_eq!(0, BoxedUint::max(128).count_zeros());
+        assert_eq!(253, uint_with_bits_at(&[16, 79, 150]).count_zeros());
+    }
+
     #[test]
     fn set_bit() {
         let mut u = uint_with_bits_at(&[16, 79, 150]);
@@ -5,7 +5,8 @@
 pub(super) use core::cmp::{max, Ordering};
 
 use super::BoxedUint;
-use crate::{CtChoice, Limb};
+use crate::{uint::hash_limbs, CtChoice, Limb};
+use core::hash::{Hash, Hasher};
 use subtle::{
     Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
 };
@@ -29,26 +30,55 @@ impl ConstantTimeEq for BoxedUint {
 impl ConstantTimeGreater for BoxedUint {
     #[inline]
     fn ct_gt(&self, other: &Self) -> Choice {
-        let (_, borrow) = other.sbb(self, Limb::ZERO);
-        CtChoice::from_word_mask(borrow.0).into()
+        Self::lt_mask(other, self).into()
     }
 }
 
 impl ConstantTimeLess for BoxedUint {
     #[inline]
     fn ct_lt(&self, other: &Self) -> Choice {
-        let (_, borrow) = self.sbb(other, Limb::ZERO);
-        CtChoice::from_word_mask(borrow.0).into()
+        Self::lt_mask(self, other).into()
+    }
+}
+
+impl BoxedUint {
+    /// Returns the truthy value if `lhs < rhs`, treating the shorter operand as if it were
+    /// zero-extended to the precision of the longer one.
+    ///
+    /// Computes the borrow of `lhs - rhs` without allocating the difference.
+    #[inline]
+    fn lt_mask(lhs: &Self, rhs: &Self) -> CtChoice {
+        let limbs = max(lhs.nlimbs(), rhs.nlimbs());
+        let mut borrow = Limb::ZERO;
+
+        for i in 0..limbs {
+            let a = lhs.limbs.get(i).unwrap_or(&Limb::ZERO);
+            let b = rhs.limbs.get(i).unwrap_or(&Limb::ZERO);
+            borrow = a.sbb(*b, borrow).1;
+        }
+
+        CtChoice::from_word_mask(borrow.0)
     }
 }
 
 impl Eq for BoxedUint {}
+
+/// Compares by numeric value in constant time, zero-extending the operand with the smaller
+/// precision, so e.g. `5` with 64 bits of precision equals `5` with 256 bits of precision.
 impl PartialEq for BoxedUint {
     fn eq(&self, other: &Self) -> bool {
         self.ct_eq(other).into()
     }
 }
 
+/// Hashes the numeric value consistently with [`PartialEq`], so values which only differ in their
+/// precision hash the same.
+impl Hash for BoxedUint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_limbs(&self.limbs, state);
+    }
+}
+
 impl Ord for BoxedUint {
     fn cmp(&self, other: &Self) -> Ordering {
         let mut ret = Ordering::Equal;
@@ -87,6 +117,35 @@ mod tests {
         assert!(bool::from(b.ct_eq(&b)));
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        use crate::{U128, U256};
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let n = BoxedUint::from(0x1234_5678_9abc_def0u64);
+        let wide = n.widen(256);
+        assert_eq!(n, wide);
+        assert_eq!(hash(&n), hash(&wide));
+        assert_eq!(hash(&n), hash(&U128::from_u64(0x1234_5678_9abc_def0)));
+        assert_eq!(hash(&n), hash(&U256::from_u64(0x1234_5678_9abc_def0)));
+        assert_eq!(
+            hash(&BoxedUint::zero()),
+            hash(&BoxedUint::zero_with_precision(256))
+        );
+
+        assert_ne!(hash(&n), hash(&BoxedUint::one()));
+        assert_ne!(hash(&n), hash(&wide.shl_vartime(64)));
+        assert_ne!(hash(&BoxedUint::zero()), hash(&BoxedUint::one()));
+    }
+
     #[test]
     fn ct_gt() {
         let a = BoxedUint::zero();
@@ -143,4 +202,73 @@ mod tests {
         assert_eq!(c.cmp(&a), Ordering::Greater);
         assert_eq!(c.cmp(&b), Ordering::Greater);
     }
+
+    #[test]
+    fn mismatched_precision() {
+        let a = BoxedUint::one();
+        let b = BoxedUint::one().widen(256);
+        let c = BoxedUint::max(64).widen(192);
+        let d = BoxedUint::max(128);
+
+        assert!(bool::from(a.ct_eq(&b)));
+        assert!(bool::from(b.ct_eq(&a)));
+        assert!(!bool::from(a.ct_lt(&b)));
+        assert!(!bool::from(b.ct_gt(&a)));
+
+        assert!(bool::from(c.ct_lt(&d)));
+        assert!(bool::from(d.ct_gt(&c)));
+        assert!(!bool::from(c.ct_eq(&d)));
+
+        assert_eq!(b.cmp(&c), Ordering::Less);
+        assert_eq!(d.cmp(&b), Ordering::Greater);
+    }
+
+    #[test]
+    fn eq_across_precisions() {
+        let precisions = [64, 128, 192, 256, 1024];
+
+        for &a_bits in &precisions {
+            for &b_bits in &precisions {
+                let a = BoxedUint::from(5u8).widen(a_bits);
+                let b = BoxedUint::from(5u8).widen(b_bits);
+                assert_eq!(a, b);
+
+                // Setting a high bit in the wider operand makes them differ
+                let bits = a_bits.max(b_bits);
+                let high = BoxedUint::one_with_precision(bits).shl(bits - 1);
+                assert_ne!(a.widen(bits).bitor(&high), b);
+                assert_ne!(a, b.widen(bits).bitor(&high));
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn matches_ord() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for i in 0..100 {
+            let a = BoxedUint::random(&mut rng, 256);
+            let mut b = BoxedUint::random(&mut rng, 256);
+
+            // Make some pairs differ only in the least significant limb
+            if i % 4 == 0 {
+                b.limbs[1..].copy_from_slice(&a.limbs[1..]);
+            }
+
+            let expected = a.limbs.iter().rev().cmp(b.limbs.iter().rev());
+            assert_eq!(a.cmp(&b), expected);
+            assert_eq!(bool::from(a.ct_eq(&b)), expected == Ordering::Equal);
+            assert_eq!(bool::from(a.ct_lt(&b)), expected == Ordering::Less);
+            assert_eq!(bool::from(a.ct_gt(&b)), expected == Ordering::Greater);
+
+            // Zero-extending either operand must not change the result
+            let wide = a.widen(512);
+            assert_eq!(wide.cmp(&b), expected);
+            assert_eq!(b.cmp(&wide), expected.reverse());
+        }
+    }
 }
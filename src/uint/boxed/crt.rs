@@ -0,0 +1,106 @@
+//! [`BoxedUint`] Chinese Remainder Theorem recombination.
+
+use crate::{BoxedUint, NonZero};
+
+impl BoxedUint {
+    /// Recombines the residues `m_p = m mod p` and `m_q = m mod q` into `m mod p*q` using
+    /// Garner's formula, i.e. computes `m_q + q * ((m_p - m_q) * q_inv_mod_p mod p)`.
+    ///
+    /// This is the final step of RSA-CRT private key operations. `p` and `q` must be coprime,
+    /// `m_p` and `q_inv_mod_p` must be reduced modulo `p` and have its precision, and `m_q` must be
+    /// reduced modulo `q` and have its precision.
+    ///
+    /// The result has the precision of `p * q`, i.e. the sum of the precisions of `p` and `q`.
+    ///
+    /// Panics if `p` is even.
+    pub fn crt_combine(m_p: &Self, m_q: &Self, p: &Self, q: &Self, q_inv_mod_p: &Self) -> Self {
+        debug_assert_eq!(m_p.bits_precision(), p.bits_precision());
+        debug_assert_eq!(m_q.bits_precision(), q.bits_precision());
+        debug_assert_eq!(q_inv_mod_p.bits_precision(), p.bits_precision());
+        assert!(bool::from(p.is_odd()), "p must be odd");
+
+        // `m_q` may exceed `p`, so reduce it before subtracting: `sub_mod` adds `p` back on
+        // underflow
+        let m_q_mod_p = m_q.rem(&NonZero::new(p.clone()).expect("p is odd, so nonzero"));
+        let h = m_p.sub_mod(&m_q_mod_p, p).mul_mod(q_inv_mod_p, p);
+
+        // Will not overflow since `m_q <= q - 1`, `h <= p - 1`,
+        // so `m_q + q * h <= q * p - 1`.
+        q.mul(&h)
+            .wrapping_add(&m_q.widen(p.bits_precision() + q.bits_precision()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, NonZero};
+
+    fn pow_mod(base: u64, exponent: u64, modulus: u64) -> BoxedUint {
+        BoxedUint::from(base).pow_mod(
+            &BoxedUint::from(exponent),
+            &NonZero::new(BoxedUint::from(modulus)).unwrap(),
+        )
+    }
+
+    fn crt_combine(m_p: &BoxedUint, m_q: &BoxedUint, p: u64, q: u64) -> BoxedUint {
+        let (p, q) = (BoxedUint::from(p), BoxedUint::from(q));
+        let q_inv = q
+            .rem(&NonZero::new(p.clone()).unwrap())
+            .inv_mod(&p)
+            .unwrap();
+        BoxedUint::crt_combine(m_p, m_q, &p, &q, &q_inv)
+    }
+
+    #[test]
+    fn crt_combine_toy_rsa() {
+        // p = 61, q = 53, n = 3233, e = 17, d = 2753
+        let (p, q, n) = (61, 53, 3233);
+        let (dp, dq) = (2753 % 60, 2753 % 52);
+
+        for m in [0, 1, 2, 65, 1234, 3232] {
+            let c = pow_mod(m, 17, n);
+            let c = u64::from_le_bytes(c.to_le_bytes()[..8].try_into().unwrap());
+
+            let m_p = pow_mod(c, dp, p);
+            let m_q = pow_mod(c, dq, q);
+            let recovered = crt_combine(&m_p, &m_q, p, q);
+            assert_eq!(recovered, BoxedUint::from(m).widen(128));
+            assert_eq!(recovered, pow_mod(c, 2753, n).widen(128));
+        }
+    }
+
+    #[test]
+    fn crt_combine_matches_direct() {
+        let (c, d) = (0x1234_5678_9abc_def0, 0xdead_beef);
+
+        for (p, q) in [
+            (0xffff_ffef, 0xffff_fffb),
+            (0xffff_fffb, 0xffff_ffef),
+            (0xffff_fffb, 61),
+            (61, 0xffff_fffb),
+        ] {
+            let m = crt_combine(&pow_mod(c, d, p), &pow_mod(c, d, q), p, q);
+            let n = BoxedUint::from(p).mul(&BoxedUint::from(q));
+            assert_eq!(m.bits_precision(), 128);
+            assert_eq!(
+                m,
+                BoxedUint::from(c)
+                    .widen(128)
+                    .pow_mod(&BoxedUint::from(d), &NonZero::new(n).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "p must be odd")]
+    fn crt_combine_even_p() {
+        let one = BoxedUint::one();
+        BoxedUint::crt_combine(
+            &one,
+            &one,
+            &BoxedUint::from(4u64),
+            &BoxedUint::from(3u64),
+            &one,
+        );
+    }
+}
@@ -1,8 +1,8 @@
 //! Constant-time helper functions.
 
 use super::BoxedUint;
-use crate::Limb;
-use subtle::{Choice, ConditionallySelectable, CtOption};
+use crate::{Limb, Word};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl BoxedUint {
     /// Conditionally select `a` or `b` in constant time depending on [`Choice`].
@@ -53,6 +53,35 @@ impl BoxedUint {
         }
     }
 
+    /// Select `table[index]` in constant time, sweeping over every entry of the table so the
+    /// memory access pattern is independent of `index`.
+    ///
+    /// Returns zero if `index` is out of range. The table must be non-empty, and all of its entries
+    /// must have the same precision.
+    pub fn ct_select_from(table: &[Self], index: Word) -> Self {
+        debug_assert!(!table.is_empty());
+        let mut ret = Self::zero_with_precision(table[0].bits_precision());
+        ret.ct_select_into(table, index);
+        ret
+    }
+
+    /// Assign `table[index]` to `self` in constant time, like [`BoxedUint::ct_select_from`] but
+    /// reusing the existing allocation of `self`, e.g. in exponentiation loops.
+    ///
+    /// Assigns zero if `index` is out of range. All of the entries of the table must have the
+    /// precision of `self`.
+    pub fn ct_select_into(&mut self, table: &[Self], index: Word) {
+        debug_assert!(table
+            .iter()
+            .all(|entry| entry.bits_precision() == self.bits_precision()));
+
+        self.limbs.iter_mut().for_each(|limb| *limb = Limb::ZERO);
+
+        for (i, entry) in table.iter().enumerate() {
+            self.conditional_assign(entry, (i as Word).ct_eq(&index));
+        }
+    }
+
     /// Conditional `map`: workaround which provides a [`CtOption::map`]-like API.
     ///
     /// Ensures both functions are called regardless of whether the first returns some/none with an
@@ -109,6 +138,7 @@ impl BoxedUint {
 #[cfg(test)]
 mod tests {
     use super::BoxedUint;
+    use crate::Word;
     use subtle::{Choice, CtOption};
 
     #[test]
@@ -120,6 +150,56 @@ mod tests {
         assert_eq!(b, BoxedUint::conditional_select(&a, &b, Choice::from(1)));
     }
 
+    #[test]
+    fn conditional_swap() {
+        let a = BoxedUint::from_be_slice(&[0x11; 32], 256).unwrap();
+        let b = BoxedUint::max(256);
+
+        let (mut x, mut y) = (a.clone(), b.clone());
+        BoxedUint::conditional_swap(&mut x, &mut y, Choice::from(0));
+        assert_eq!((&x, &y), (&a, &b));
+
+        BoxedUint::conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((&x, &y), (&b, &a));
+
+        BoxedUint::conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((&x, &y), (&a, &b));
+    }
+
+    #[test]
+    fn ct_select_from() {
+        let table: [BoxedUint; 5] =
+            core::array::from_fn(|i| BoxedUint::from((i as u64 + 1) * 0x0101_0101).widen(128));
+
+        for (i, entry) in table.iter().enumerate() {
+            assert_eq!(&BoxedUint::ct_select_from(&table, i as Word), entry);
+        }
+
+        // Out of range indices select zero
+        for index in [5, 6, Word::MAX] {
+            assert_eq!(
+                BoxedUint::ct_select_from(&table, index),
+                BoxedUint::zero_with_precision(128)
+            );
+        }
+    }
+
+    #[test]
+    fn ct_select_into() {
+        let table: [BoxedUint; 5] =
+            core::array::from_fn(|i| BoxedUint::from((i as u64 + 1) * 0x0101_0101).widen(128));
+
+        // Previous contents of the buffer are overwritten
+        let mut power = BoxedUint::max(128);
+        for (i, entry) in table.iter().enumerate() {
+            power.ct_select_into(&table, i as Word);
+            assert_eq!(&power, entry);
+        }
+
+        power.ct_select_into(&table, 5);
+        assert_eq!(power, BoxedUint::zero_with_precision(128));
+    }
+
     #[test]
     fn conditional_map_some() {
         let n = BoxedUint::one();
@@ -1,6 +1,6 @@
 //! [`BoxedUint`] division operations.
 
-use crate::{BoxedUint, CheckedDiv, Limb, NonZero, Wrapping};
+use crate::{uint::div::rem2k_limb_mask, BoxedUint, CheckedDiv, Limb, NonZero, Wrapping};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use subtle::{Choice, ConstantTimeEq, ConstantTimeLess, CtOption};
 
@@ -12,8 +12,19 @@ impl BoxedUint {
     }
 
     /// Computes self % rhs, returns the remainder.
+    ///
+    /// `self` and `rhs` may have different precisions, in which case the remainder has the
+    /// precision of `rhs`. The precisions may be leaked in the time pattern, but not the values.
     pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
-        self.div_rem(rhs).1
+        if self.bits_precision() == rhs.bits_precision() {
+            return self.div_rem(rhs).1;
+        }
+
+        let bits_precision = self.bits_precision().max(rhs.bits_precision());
+        self.widen(bits_precision)
+            .div_rem_unchecked(&rhs.widen(bits_precision))
+            .1
+            .shorten(rhs.bits_precision())
     }
 
     /// Computes self / rhs, returns the quotient, remainder.
@@ -28,11 +39,23 @@ impl BoxedUint {
     ///
     /// Variable-time with respect to `rhs`.
     ///
-    /// # Panics
-    ///
-    /// Panics if `self` and `rhs` have different precisions.
-    // TODO(tarcieri): handle different precisions without panicking
+    /// `self` and `rhs` may have different precisions, in which case the remainder has the
+    /// precision of `rhs`.
     pub fn rem_vartime(&self, rhs: &NonZero<Self>) -> Self {
+        if self.bits_precision() == rhs.bits_precision() {
+            return self.rem_vartime_unchecked(rhs.as_ref());
+        }
+
+        let bits_precision = self.bits_precision().max(rhs.bits_precision());
+        self.widen(bits_precision)
+            .rem_vartime_unchecked(&rhs.widen(bits_precision))
+            .shorten(rhs.bits_precision())
+    }
+
+    /// Computes `self % rhs` without checking if `rhs` is zero.
+    ///
+    /// Variable-time with respect to `rhs`.
+    fn rem_vartime_unchecked(&self, rhs: &Self) -> Self {
         debug_assert_eq!(self.bits_precision(), rhs.bits_precision());
         let mb = rhs.bits();
         let mut bd = self.bits_precision() - mb;
@@ -50,6 +73,20 @@ impl BoxedUint {
         }
     }
 
+    /// Computes `self % 2^k`, i.e. clears all bits at positions `>= k`, preserving the precision
+    /// of `self`.
+    ///
+    /// Returns zero if `k == 0`, and `self` if `k >= self.bits_precision()`.
+    ///
+    /// This is constant-time with respect to both `self` and `k`.
+    pub fn rem2k(&self, k: u32) -> Self {
+        let mut ret = self.clone();
+        for (i, limb) in ret.limbs.iter_mut().enumerate() {
+            limb.0 &= rem2k_limb_mask(i, k);
+        }
+        ret
+    }
+
     /// Wrapped division is just normal division i.e. `self` / `rhs`
     /// There’s no way wrapping could ever happen.
     ///
@@ -67,6 +104,13 @@ impl BoxedUint {
         CtOption::new(q, !rhs.is_zero())
     }
 
+    /// Perform checked reduction, returning a [`CtOption`] which `is_some`
+    /// only if the rhs != 0
+    pub fn checked_rem(&self, rhs: &Self) -> CtOption<Self> {
+        let r = self.div_rem_unchecked(rhs).1;
+        CtOption::new(r, !rhs.is_zero())
+    }
+
     /// Computes `self` / `rhs`, returns the quotient (q), remainder (r) without checking if `rhs`
     /// is zero.
     ///
@@ -293,10 +337,93 @@ mod tests {
         assert_eq!(BoxedUint::from(648u128), n.rem(&p));
     }
 
+    #[test]
+    fn rem2k() {
+        let n = BoxedUint::from(0xFFEECCBBAA99887766u128).widen(256);
+        assert_eq!(n.rem2k(0), BoxedUint::zero_with_precision(256));
+        assert_eq!(n.rem2k(8), BoxedUint::from(0x66u8).widen(256));
+        assert_eq!(
+            n.rem2k(64),
+            BoxedUint::from(0xEECCBBAA99887766u64).widen(256)
+        );
+        assert_eq!(n.rem2k(256), n);
+        assert_eq!(n.rem2k(1000), n);
+
+        for k in [1, 63, 64, 65, 127, 128, 200, 255, 256] {
+            let one = BoxedUint::one_with_precision(256);
+            let mask = one.shl(k).wrapping_sub(&one);
+            assert_eq!(n.rem2k(k), n.bitand(&mask), "k = {k}");
+            assert_eq!(n.rem2k(k).bits_precision(), 256);
+        }
+    }
+
+    #[test]
+    fn checked_div_rem() {
+        let n = BoxedUint::from(0xFFEECCBBAA99887766u128);
+        let p = BoxedUint::from(997u128);
+        assert_eq!(
+            n.checked_div(&p).unwrap(),
+            BoxedUint::from(0xFFEECCBBAA99887766u128 / 997)
+        );
+        assert_eq!(n.checked_rem(&p).unwrap(), BoxedUint::from(648u128));
+
+        let zero = BoxedUint::zero_with_precision(128);
+        assert!(bool::from(n.checked_div(&zero).is_none()));
+        assert!(bool::from(n.checked_rem(&zero).is_none()));
+    }
+
     #[test]
     fn rem_vartime() {
         let n = BoxedUint::from(0xFFEECCBBAA99887766u128);
         let p = NonZero::new(BoxedUint::from(997u128)).unwrap();
         assert_eq!(BoxedUint::from(648u128), n.rem_vartime(&p));
     }
+
+    #[test]
+    fn rem_mixed_precision() {
+        let n = BoxedUint::from(0xFFEECCBBAA99887766u128);
+        let p = NonZero::new(BoxedUint::from(997u128).widen(256)).unwrap();
+        assert_eq!(BoxedUint::from(648u128).widen(256), n.rem(&p));
+        assert_eq!(BoxedUint::from(648u128).widen(256), n.rem_vartime(&p));
+
+        let n = n.widen(512);
+        let p = NonZero::new(BoxedUint::from(997u128)).unwrap();
+        assert_eq!(BoxedUint::from(648u128), n.rem(&p));
+        assert_eq!(BoxedUint::from(648u128), n.rem_vartime(&p));
+    }
+
+    #[test]
+    fn rem_less_than_modulus() {
+        let n = BoxedUint::from(0xFFEECCBBAA99887766u128);
+        let p = NonZero::new(BoxedUint::max(128)).unwrap();
+        assert_eq!(n, n.rem(&p));
+        assert_eq!(n, n.rem_vartime(&p));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rem_matches_uint() {
+        use crate::{Encoding, U512};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for (n_bits, p_bits) in [(512, 256), (256, 256), (256, 512), (512, 64), (128, 512)] {
+            for _ in 0..8 {
+                let n = BoxedUint::random(&mut rng, n_bits);
+                let p = BoxedUint::random(&mut rng, p_bits).shr_vartime(p_bits / 3);
+                let p = NonZero::new(p.bitor(&BoxedUint::one_with_precision(p_bits))).unwrap();
+
+                let expected = U512::from_le_slice(&n.widen(512).to_le_bytes())
+                    .rem(&NonZero::new(U512::from_le_slice(&p.widen(512).to_le_bytes())).unwrap());
+                let expected = BoxedUint::from_le_slice(&expected.to_le_bytes(), 512)
+                    .unwrap()
+                    .shorten(p_bits);
+
+                assert_eq!(n.rem(&p), expected);
+                assert_eq!(n.rem_vartime(&p), expected);
+            }
+        }
+    }
 }
@@ -1,34 +1,12 @@
 //! Const-friendly decoding operations for [`BoxedUint`].
 
 use super::BoxedUint;
-use crate::Limb;
-use alloc::boxed::Box;
-use core::fmt;
-
-/// Decoding errors for [`BoxedUint`].
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum DecodeError {
-    /// Input size is too small to fit in the given precision.
-    InputSize,
-
-    /// The deserialized number is larger than the given precision.
-    Precision,
-}
-
-impl fmt::Display for DecodeError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::InputSize => write!(f, "input size is too small to fit in the given precision"),
-            Self::Precision => write!(
-                f,
-                "the deserialized number is larger than the given precision"
-            ),
-        }
-    }
-}
-
-#[cfg(feature = "std")]
-impl std::error::Error for DecodeError {}
+use crate::{
+    uint::encoding::{be_bytes_trimmed, decode_hex_byte},
+    DecodeError, Limb, NonZero, ParseError,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt::Write;
 
 impl BoxedUint {
     /// Create a new [`BoxedUint`] from the provided big endian bytes.
@@ -97,6 +75,60 @@ impl BoxedUint {
         Ok(ret)
     }
 
+    /// Create a new [`BoxedUint`] from the provided big endian hex string.
+    ///
+    /// The precision of the result is `4 * hex.len()` bits rounded up to a multiple of
+    /// [`Limb::BITS`], or that of [`BoxedUint::zero`] if `hex` is empty.
+    ///
+    /// Since each pair of characters encodes a byte, strings of odd length are rejected, as are
+    /// strings containing non-hex characters, with [`ParseError::InvalidDigit`].
+    pub fn from_be_hex(hex: &str) -> Result<Self, ParseError> {
+        let bytes = decode_hex(hex)?;
+        Ok(Self::from_be_slice(&bytes, bytes.len() as u32 * 8)
+            .expect("precision is large enough for the input"))
+    }
+
+    /// Create a new [`BoxedUint`] from the provided little endian hex string.
+    ///
+    /// The precision of the result is `4 * hex.len()` bits rounded up to a multiple of
+    /// [`Limb::BITS`], or that of [`BoxedUint::zero`] if `hex` is empty.
+    ///
+    /// Since each pair of characters encodes a byte, strings of odd length are rejected, as are
+    /// strings containing non-hex characters, with [`ParseError::InvalidDigit`].
+    pub fn from_le_hex(hex: &str) -> Result<Self, ParseError> {
+        let bytes = decode_hex(hex)?;
+        Ok(Self::from_le_slice(&bytes, bytes.len() as u32 * 8)
+            .expect("precision is large enough for the input"))
+    }
+
+    /// Create a new [`BoxedUint`] from a minimal-length big endian encoding, as produced by
+    /// [`BoxedUint::to_be_bytes_trimmed`].
+    ///
+    /// The precision of the result is `8 * bytes.len()` bits rounded up to a multiple of
+    /// [`Limb::BITS`], or that of [`BoxedUint::zero`] if `bytes` is empty.
+    pub fn from_be_bytes_trimmed(bytes: &[u8]) -> Self {
+        Self::from_be_slice(bytes, bytes.len() as u32 * 8)
+            .expect("precision is large enough for the input")
+    }
+
+    /// Create a new [`BoxedUint`] from the provided big endian bytes, reduced modulo `modulus`.
+    ///
+    /// The input may be arbitrarily wider than the modulus, e.g. a 512-bit hash output reduced
+    /// modulo a 256-bit prime. The result has the precision of `modulus`.
+    ///
+    /// This function is constant-time with respect to the contents of `bytes` and `modulus`, but
+    /// not their lengths.
+    pub fn from_be_bytes_mod(bytes: &[u8], modulus: &NonZero<Self>) -> Self {
+        let bits_precision = modulus.bits_precision().max(bytes.len() as u32 * 8);
+        let mut n = Self::zero_with_precision(bits_precision);
+
+        for (chunk, limb) in bytes.rchunks(Limb::BYTES).zip(n.limbs.iter_mut()) {
+            *limb = Limb::from_be_slice(chunk);
+        }
+
+        n.rem(modulus)
+    }
+
     /// Serialize this [`BoxedUint`] as big-endian.
     #[inline]
     pub fn to_be_bytes(&self) -> Box<[u8]> {
@@ -115,6 +147,27 @@ impl BoxedUint {
         out.into()
     }
 
+    /// Serialize this [`BoxedUint`] as big-endian with leading zero bytes stripped, i.e. as
+    /// `ceil(self.bits() / 8)` bytes regardless of its precision. Zero is encoded as an empty
+    /// vector.
+    ///
+    /// The length of the output depends on the value of `self`, so this isn't constant-time.
+    pub fn to_be_bytes_trimmed(&self) -> Vec<u8> {
+        be_bytes_trimmed(&self.limbs)
+    }
+
+    /// Serialize this [`BoxedUint`] as a big-endian lowercase hex string, including leading zeros
+    /// up to its full precision.
+    pub fn to_be_hex(&self) -> String {
+        encode_hex(&self.to_be_bytes())
+    }
+
+    /// Serialize this [`BoxedUint`] as a little-endian lowercase hex string, including trailing
+    /// zeros up to its full precision.
+    pub fn to_le_hex(&self) -> String {
+        encode_hex(&self.to_le_bytes())
+    }
+
     /// Serialize this [`BoxedUint`] as little-endian.
     #[inline]
     pub fn to_le_bytes(&self) -> Box<[u8]> {
@@ -133,9 +186,42 @@ impl BoxedUint {
     }
 }
 
+/// Decode a hex string into bytes, in the order they appear.
+fn decode_hex(hex: &str) -> Result<Vec<u8>, ParseError> {
+    let hex = hex.as_bytes();
+    if hex.len() % 2 != 0 {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    let mut err = 0;
+    let bytes = hex
+        .chunks_exact(2)
+        .map(|pair| {
+            let (byte, byte_err) = decode_hex_byte([pair[0], pair[1]]);
+            err |= byte_err;
+            byte
+        })
+        .collect();
+
+    if err != 0 {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    Ok(bytes)
+}
+
+/// Encode bytes as a lowercase hex string, in the order they appear.
+fn encode_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        write!(hex, "{byte:02x}").expect("writing to a string is infallible");
+    }
+    hex
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{BoxedUint, DecodeError};
+    use super::{BoxedUint, DecodeError, ParseError};
     use crate::Limb;
     use hex_literal::hex;
 
@@ -338,4 +424,121 @@ mod tests {
         let n = BoxedUint::from_be_slice(&bytes, 128).unwrap();
         assert_eq!(bytes.as_slice(), &*n.to_be_bytes());
     }
+
+    #[test]
+    fn be_bytes_trimmed() {
+        assert!(BoxedUint::zero_with_precision(256)
+            .to_be_bytes_trimmed()
+            .is_empty());
+        assert_eq!(BoxedUint::from_be_bytes_trimmed(&[]), BoxedUint::zero());
+
+        for bits_precision in [64, 128, 256] {
+            for n in [
+                BoxedUint::one_with_precision(bits_precision),
+                BoxedUint::from(0x1122_3344_5566_77ffu64).widen(bits_precision),
+                BoxedUint::max(bits_precision).shr_vartime(9),
+                BoxedUint::max(bits_precision),
+            ] {
+                let bytes = n.to_be_bytes_trimmed();
+                assert_eq!(bytes.len(), (n.bits() as usize + 7) / 8);
+
+                let decoded = BoxedUint::from_be_bytes_trimmed(&bytes);
+                assert!(decoded.bits_precision() <= bits_precision);
+                assert_eq!(decoded.widen(bits_precision), n);
+            }
+        }
+
+        let n = BoxedUint::from_be_bytes_trimmed(&hex!("0100000000000000ff"));
+        assert_eq!(n.bits_precision(), 128);
+        assert_eq!(n.to_be_bytes_trimmed(), hex!("0100000000000000ff"));
+    }
+
+    #[test]
+    fn from_be_bytes_mod() {
+        use crate::{Encoding, NonZero, U1024};
+        use alloc::vec;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        let p = hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        let modulus = NonZero::new(BoxedUint::from_be_slice(&p, 256).unwrap()).unwrap();
+        let modulus_ref =
+            NonZero::new(U1024::from_be_slice(&[[0u8; 96].as_slice(), &p].concat())).unwrap();
+
+        // Inputs narrower than, equal to, and several times wider than the modulus
+        for len in [0, 16, 32, 33, 64, 100, 128] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let actual = BoxedUint::from_be_bytes_mod(&bytes, &modulus);
+            assert_eq!(actual.bits_precision(), 256);
+
+            let mut padded = [0u8; 128];
+            padded[128 - len..].copy_from_slice(&bytes);
+            let expected = U1024::from_be_slice(&padded).rem(&modulus_ref);
+            assert_eq!(&*actual.to_be_bytes(), &expected.to_be_bytes()[96..]);
+        }
+
+        // The modulus itself reduces to zero
+        assert!(bool::from(
+            BoxedUint::from_be_bytes_mod(&p, &modulus).is_zero()
+        ));
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let hex = "00112233445566778899aabbccddeeff0123456789abcdef";
+        let be = BoxedUint::from_be_hex(hex).unwrap();
+        assert_eq!(be.bits_precision(), 192);
+        assert_eq!(be.to_be_hex(), hex);
+        assert_eq!(
+            be,
+            BoxedUint::from_be_slice(
+                &hex!("00112233445566778899aabbccddeeff0123456789abcdef"),
+                192
+            )
+            .unwrap()
+        );
+
+        let le = BoxedUint::from_le_hex(hex).unwrap();
+        assert_eq!(le.to_le_hex(), hex);
+        assert_eq!(
+            le,
+            BoxedUint::from_le_slice(
+                &hex!("00112233445566778899aabbccddeeff0123456789abcdef"),
+                192
+            )
+            .unwrap()
+        );
+
+        // Uppercase digits are accepted, but serialized as lowercase
+        assert_eq!(BoxedUint::from_be_hex(&hex.to_uppercase()), Ok(be));
+    }
+
+    #[test]
+    fn from_hex_rounds_up_precision() {
+        let n = BoxedUint::from_be_hex("0102").unwrap();
+        assert_eq!(n.bits_precision(), Limb::BITS);
+        assert_eq!(n, BoxedUint::from(0x102u16));
+        assert_eq!(n.to_be_hex().len(), Limb::BYTES * 2);
+        assert_eq!(
+            BoxedUint::from_le_hex("0102").unwrap(),
+            BoxedUint::from(0x201u16)
+        );
+    }
+
+    #[test]
+    fn from_hex_empty() {
+        assert_eq!(BoxedUint::from_be_hex(""), Ok(BoxedUint::zero()));
+        assert_eq!(BoxedUint::from_le_hex(""), Ok(BoxedUint::zero()));
+    }
+
+    #[test]
+    fn from_hex_invalid() {
+        for hex in ["0", "abc", "0g", "0x00", " 00"] {
+            assert_eq!(BoxedUint::from_be_hex(hex), Err(ParseError::InvalidDigit));
+            assert_eq!(BoxedUint::from_le_hex(hex), Err(ParseError::InvalidDigit));
+        }
+    }
 }
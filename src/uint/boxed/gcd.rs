@@ -0,0 +1,215 @@
+//! Support for computing the greatest common divisor of two [`BoxedUint`]s.
+
+use super::BoxedUint;
+use crate::NonZero;
+use subtle::{ConditionallySelectable, ConstantTimeLess};
+
+impl BoxedUint {
+    /// Computes the greatest common divisor of `self` and `rhs` using the binary GCD algorithm.
+    ///
+    /// The inputs may have different precisions, and the result has the larger of the two. If
+    /// either input is zero the result is the other one, so `gcd(0, 0) == 0`.
+    ///
+    /// This function is constant-time with respect to the values of `self` and `rhs`, but not
+    /// their precisions.
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        let bits_precision = self.bits_precision().max(rhs.bits_precision());
+        let mut a = self.widen(bits_precision);
+        let mut b = rhs.widen(bits_precision);
+        let mut k = 0u32;
+
+        // Each step removes at least one bit from `a` or `b` until one of them is zero
+        for _ in 0..(2 * bits_precision) {
+            let done = a.is_zero() | b.is_zero();
+            let (a_odd, b_odd) = (a.is_odd(), b.is_odd());
+
+            // If both are odd, ensure `a >= b` so that `(a - b) / 2` doesn't underflow
+            let both_odd = a_odd & b_odd;
+            let swap = both_odd & a.ct_lt(&b);
+            Self::conditional_swap(&mut a, &mut b, swap);
+
+            // Common factors of two are removed from both and restored at the end
+            k += u32::conditional_select(&0, &1, !done & !a_odd & !b_odd);
+
+            let halved_diff = a.wrapping_sub(&b).shr1();
+            let a_halved = a.shr1();
+            let b_halved = b.shr1();
+            a.conditional_assign(&halved_diff, both_odd);
+            a.conditional_assign(&a_halved, !done & !a_odd);
+            b.conditional_assign(&b_halved, !done & !b_odd);
+        }
+
+        // One of `a` and `b` is now zero
+        a.bitor(&b).shl(k)
+    }
+
+    /// Computes the least common multiple of `self` and `rhs`.
+    ///
+    /// The inputs may have different precisions, and the result has twice the larger of the two,
+    /// so it can't overflow. If either input is zero the result is zero.
+    ///
+    /// This function is constant-time with respect to the values of `self` and `rhs`, but not
+    /// their precisions.
+    pub fn lcm(&self, rhs: &Self) -> Self {
+        let bits_precision = self.bits_precision().max(rhs.bits_precision());
+        let gcd = self.gcd(rhs);
+
+        // The gcd is only zero if both inputs are, in which case dividing by one gives zero too
+        let one = Self::one_with_precision(bits_precision);
+        let divisor = Self::conditional_select(&gcd, &one, gcd.is_zero());
+        let divisor = NonZero::new(divisor).expect("divisor is nonzero");
+
+        self.widen(bits_precision)
+            .wrapping_div(&divisor)
+            .mul(&rhs.widen(bits_precision))
+    }
+
+    /// Computes the greatest common divisor of `self` and `rhs` using the Euclidean algorithm.
+    ///
+    /// The inputs may have different precisions, and the result has the larger of the two. If
+    /// either input is zero the result is the other one, so `gcd_vartime(0, 0) == 0`.
+    ///
+    /// This function is variable-time with respect to both `self` and `rhs`, so it should only be
+    /// used with public inputs, e.g. when validating that an RSA public exponent is coprime to
+    /// `phi(n)`.
+    pub fn gcd_vartime(&self, rhs: &Self) -> Self {
+        let bits_precision = self.bits_precision().max(rhs.bits_precision());
+        let mut a = self.widen(bits_precision);
+        let mut b = rhs.widen(bits_precision);
+
+        while let Some(divisor) = Option::<NonZero<Self>>::from(NonZero::new(b.clone())) {
+            (a, b) = (b, a.rem_vartime(&divisor));
+        }
+
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedUint;
+
+    /// Euclidean reference implementation for values which fit in a `u128`.
+    fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    fn check(a: u128, b: u128) {
+        let expected = BoxedUint::from(gcd_u128(a, b));
+
+        for (a_bits, b_bits) in [(128, 128), (128, 256), (256, 128)] {
+            let x = BoxedUint::from(a).widen(a_bits);
+            let y = BoxedUint::from(b).widen(b_bits);
+
+            let gcd = x.gcd(&y);
+            assert_eq!(gcd.bits_precision(), a_bits.max(b_bits));
+            assert_eq!(gcd, expected, "gcd({a}, {b})");
+            assert_eq!(y.gcd(&x), expected, "gcd({b}, {a})");
+            assert_eq!(x.gcd_vartime(&y), expected, "gcd_vartime({a}, {b})");
+            assert_eq!(y.gcd_vartime(&x), expected, "gcd_vartime({b}, {a})");
+        }
+    }
+
+    #[test]
+    fn lcm() {
+        for (a, b, expected) in [
+            (0u128, 0u128, 0u128),
+            (0, 12345, 0),
+            (12345, 0, 0),
+            (4, 6, 12),
+            (21, 6, 42),
+            (1 << 127, 1 << 64, 1 << 127),
+            (3 << 100, 12 << 90, 3 << 100),
+        ] {
+            let lcm = BoxedUint::from(a).lcm(&BoxedUint::from(b));
+            assert_eq!(lcm.bits_precision(), 256);
+            assert_eq!(lcm, BoxedUint::from(expected).widen(256), "lcm({a}, {b})");
+        }
+
+        // Consecutive integers are coprime, so this would overflow 128 bits
+        let max = BoxedUint::max(128);
+        let n = max.wrapping_sub(&BoxedUint::one());
+        assert_eq!(max.lcm(&n), max.mul(&n));
+
+        // Mixed precisions
+        let lcm = BoxedUint::from(4u8).lcm(&BoxedUint::from(6u8).widen(192));
+        assert_eq!(lcm, BoxedUint::from(12u8).widen(384));
+    }
+
+    #[test]
+    fn gcd_edge_cases() {
+        check(0, 0);
+        check(0, 1);
+        check(0, 12345);
+        check(1, 1);
+        check(1, u128::MAX);
+        check(u128::MAX, u128::MAX);
+        check(1 << 127, 1 << 64);
+        check(3 << 100, 12 << 90);
+    }
+
+    #[test]
+    fn gcd_vartime() {
+        let gcd = BoxedUint::from(48u8).gcd_vartime(&BoxedUint::from(180u8));
+        assert_eq!(gcd, BoxedUint::from(12u8));
+
+        let gcd = BoxedUint::from(17u8).gcd_vartime(&BoxedUint::zero());
+        assert_eq!(gcd, BoxedUint::from(17u8));
+    }
+
+    #[test]
+    fn gcd_rsa_exponent() {
+        // p = 61, q = 53: phi = 3120
+        assert_eq!(
+            BoxedUint::from(17u8).gcd_vartime(&BoxedUint::from(3120u16)),
+            BoxedUint::one()
+        );
+        assert_eq!(
+            BoxedUint::from(65u8).gcd(&BoxedUint::from(3120u16)),
+            BoxedUint::from(65u8)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn gcd_random() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let mut random = || (u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64());
+
+        for _ in 0..32 {
+            // Share a random factor to get nontrivial gcds
+            let g = random() >> 96;
+            let (a, b) = (random() >> 32, random() >> 32);
+            check(a, b);
+            check(a * g.max(1), b * g.max(1));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn gcd_matches_vartime() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let g = BoxedUint::random(&mut rng, 64);
+
+        for (a_bits, b_bits) in [(256, 256), (512, 192), (64, 1024)] {
+            let a = BoxedUint::random(&mut rng, a_bits)
+                .widen(a_bits + 64)
+                .wrapping_mul(&g);
+            let b = BoxedUint::random(&mut rng, b_bits)
+                .widen(b_bits + 64)
+                .wrapping_mul(&g);
+            let gcd = a.gcd(&b);
+            assert_eq!(gcd, a.gcd_vartime(&b));
+            assert_eq!(gcd, b.gcd(&a));
+        }
+    }
+}
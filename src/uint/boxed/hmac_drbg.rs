@@ -0,0 +1,211 @@
+//! Deterministic [`BoxedUint`] generation using HMAC_DRBG as described in RFC 6979.
+
+use super::BoxedUint;
+use crate::{Limb, NonZero};
+use alloc::vec::Vec;
+use digest::{core_api::BlockSizeUser, Digest, Output};
+use hmac::{Mac, SimpleHmac};
+use subtle::{Choice, ConstantTimeEq};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+impl BoxedUint {
+    /// Deterministically derive a [`BoxedUint`] in range `[1, q)` from the secret `key` and the
+    /// message digest `msg` using the HMAC_DRBG based procedure from [RFC 6979 Section 3.2],
+    /// e.g. to compute the per-message nonce of a deterministic DSA or ECDSA signature.
+    ///
+    /// `key` and `msg` are big endian encodings, which are interpreted as the integers `x` and
+    /// `h1` of RFC 6979 respectively: `key` is reduced modulo `q` and `msg` is truncated to the
+    /// bit length of `q`. Candidates which are zero or not less than `q` are rejected, drawing
+    /// more output from the DRBG until one is in range.
+    ///
+    /// The result has the precision of `q`.
+    ///
+    /// Like [`RandomMod`](crate::RandomMod), the number of iterations of the rejection loop
+    /// depends on the output, so this is variable-time. With the `zeroize` feature enabled, the
+    /// encoded key and the DRBG state are zeroized before returning.
+    ///
+    /// Panics if `q` is less than two.
+    ///
+    /// [RFC 6979 Section 3.2]: https://www.rfc-editor.org/rfc/rfc6979#section-3.2
+    pub fn deterministic_scalar<D>(q: &Self, key: &[u8], msg: &[u8]) -> Self
+    where
+        D: Digest + BlockSizeUser,
+    {
+        assert!(q.bits_vartime() > 1, "q must be at least two");
+        let rlen = (q.bits_vartime() as usize + 7) / 8;
+
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut x = int2octets(
+            &Self::from_be_bytes_mod(key, &NonZero::new(q.clone()).expect("q is nonzero")),
+            rlen,
+        );
+
+        // `bits2int(msg) < 2^qlen < 2 * q`, so a single conditional subtraction reduces it
+        let h1 = bits2int(msg, q);
+        let (diff, borrow) = h1.sbb(q, Limb::ZERO);
+        let h1 = int2octets(
+            &Self::conditional_select(&diff, &h1, Choice::from((borrow.0 & 1) as u8)),
+            rlen,
+        );
+
+        let mut k = Output::<D>::default();
+        let mut v = Output::<D>::default();
+        v.iter_mut().for_each(|byte| *byte = 0x01);
+
+        for separator in [0x00, 0x01] {
+            k = hmac_concat::<D>(&k, &[&v, &[separator], &x, &h1]);
+            v = hmac_concat::<D>(&k, &[&v]);
+        }
+
+        // The encoded key is only needed to seed the DRBG
+        #[cfg(feature = "zeroize")]
+        x.as_mut_slice().zeroize();
+
+        let mut t = Vec::with_capacity(rlen + v.len());
+        loop {
+            while t.len() < rlen {
+                v = hmac_concat::<D>(&k, &[&v]);
+                t.extend_from_slice(&v);
+            }
+
+            let candidate = bits2int(&t, q);
+            let (_, borrow) = candidate.sbb(q, Limb::ZERO);
+            let in_range = candidate.is_nonzero() & !borrow.ct_eq(&Limb::ZERO);
+            #[cfg(feature = "zeroize")]
+            t.as_mut_slice().zeroize();
+            t.clear();
+
+            if bool::from(in_range) {
+                #[cfg(feature = "zeroize")]
+                {
+                    k.as_mut_slice().zeroize();
+                    v.as_mut_slice().zeroize();
+                }
+                return candidate;
+            }
+
+            k = hmac_concat::<D>(&k, &[&v, &[0x00]]);
+            v = hmac_concat::<D>(&k, &[&v]);
+        }
+    }
+}
+
+/// Computes the HMAC of the concatenation of `data` using the given `key`.
+fn hmac_concat<D>(key: &[u8], data: &[&[u8]]) -> Output<D>
+where
+    D: Digest + BlockSizeUser,
+{
+    let mut mac = SimpleHmac::<D>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    mac.finalize().into_bytes()
+}
+
+/// The `bits2int` transform from RFC 6979 Section 2.3.2, i.e. the leftmost `q.bits()` bits of
+/// `bytes`, with the precision of `q`.
+fn bits2int(bytes: &[u8], q: &BoxedUint) -> BoxedUint {
+    let qlen = q.bits_vartime();
+    let blen = bytes.len() as u32 * 8;
+    let n = BoxedUint::from_be_slice(bytes, blen).expect("precision is large enough for the input");
+    let n = if blen > qlen {
+        n.shr_vartime(blen - qlen)
+    } else {
+        n
+    };
+
+    // `n < 2^qlen`, so its value is unchanged by shortening
+    if n.bits_precision() > q.bits_precision() {
+        n.shorten(q.bits_precision())
+    } else {
+        n.widen(q.bits_precision())
+    }
+}
+
+/// The `int2octets` transform from RFC 6979 Section 2.3.3, i.e. the big endian encoding of `n`
+/// as `rlen` bytes.
+fn int2octets(n: &BoxedUint, rlen: usize) -> Vec<u8> {
+    let bytes = n.to_be_bytes();
+    bytes[bytes.len() - rlen..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedUint;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256, Sha512};
+
+    /// NIST P-256 group order.
+    const P256_Q: [u8; 32] =
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+
+    /// RFC 6979 Appendix A.2.5 private key.
+    const P256_X: [u8; 32] =
+        hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+    fn p256_q() -> BoxedUint {
+        BoxedUint::from_be_slice(&P256_Q, 256).unwrap()
+    }
+
+    #[test]
+    fn rfc6979_p256_sha256() {
+        for (msg, k) in [
+            (
+                "sample",
+                hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60"),
+            ),
+            (
+                "test",
+                hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0"),
+            ),
+        ] {
+            let h1 = Sha256::digest(msg);
+            let actual = BoxedUint::deterministic_scalar::<Sha256>(&p256_q(), &P256_X, &h1);
+            assert_eq!(actual.bits_precision(), 256);
+            assert_eq!(actual, BoxedUint::from_be_slice(&k, 256).unwrap(), "{msg}");
+        }
+    }
+
+    #[test]
+    fn rfc6979_p256_sha512() {
+        // The digest is longer than the group order, so it's truncated by `bits2int`
+        let h1 = Sha512::digest("sample");
+        let k = hex!("5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5");
+        let actual = BoxedUint::deterministic_scalar::<Sha512>(&p256_q(), &P256_X, &h1);
+        assert_eq!(actual, BoxedUint::from_be_slice(&k, 256).unwrap());
+    }
+
+    #[test]
+    fn rfc6979_rejects_out_of_range_candidate() {
+        // RFC 6979 Appendix A.1, in which the first candidate is not less than `q`
+        let q = BoxedUint::from_be_slice(&hex!("04000000000000000000020108A2E0CC0D99F8A5EF"), 163)
+            .unwrap();
+        let x = hex!("009A4D6792295A7F730FC3F2B49CBC0F62E862272F");
+        let k = hex!("023AF4074C90A02B3FE61D286D5C87F425E6BDD81B");
+
+        let actual = BoxedUint::deterministic_scalar::<Sha256>(&q, &x, &Sha256::digest("sample"));
+        assert_eq!(actual.bits_precision(), q.bits_precision());
+        assert_eq!(actual, BoxedUint::from_be_slice(&k, 163).unwrap());
+    }
+
+    #[test]
+    fn small_q() {
+        // Most candidates are rejected for a tiny `q`, but the result is always in `[1, q)`
+        for q in [2u8, 3, 5, 0x81] {
+            let q = BoxedUint::from(q);
+            for msg in ["sample", "test", ""] {
+                let k = BoxedUint::deterministic_scalar::<Sha256>(&q, &[1], &Sha256::digest(msg));
+                assert!(bool::from(k.is_nonzero()));
+                assert!(k < q);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "q must be at least two")]
+    fn q_one() {
+        BoxedUint::deterministic_scalar::<Sha256>(&BoxedUint::one(), &[1], &[]);
+    }
+}
@@ -68,8 +68,14 @@ impl BoxedUint {
     }
 
     /// Computes the multiplicative inverse of `self` mod `modulus`, where `modulus` is odd.
-    /// Returns `None` if an inverse does not exist.
-    pub(crate) fn inv_odd_mod(&self, modulus: &Self) -> (Self, Choice) {
+    ///
+    /// `self` and `modulus` must have the same precision, which is also the precision of the
+    /// result. The second element of the tuple is the truthy value if an inverse exists (i.e.
+    /// `gcd(self, modulus) == 1`), otherwise it is a falsy value.
+    ///
+    /// This is faster than [`BoxedUint::inv_mod`] as it skips the CRT recombination needed to
+    /// support even moduli.
+    pub fn inv_odd_mod(&self, modulus: &Self) -> (Self, Choice) {
         self.inv_odd_mod_bounded(modulus, self.bits_precision(), modulus.bits_precision())
     }
 
@@ -173,4 +179,58 @@ mod tests {
         let a = v.inv_mod2k(256);
         assert_eq!(e, a);
     }
+
+    #[test]
+    fn inv_odd_mod_edge_cases() {
+        let modulus = BoxedUint::from(0xffff_fffbu32).widen(128);
+
+        let (_, is_some) = BoxedUint::zero_with_precision(128).inv_odd_mod(&modulus);
+        assert!(!bool::from(is_some));
+        assert!(bool::from(
+            BoxedUint::zero_with_precision(128)
+                .inv_mod(&modulus)
+                .is_none()
+        ));
+
+        let one = BoxedUint::one_with_precision(128);
+        let (inv, is_some) = one.inv_odd_mod(&modulus);
+        assert!(bool::from(is_some));
+        assert_eq!(inv, one);
+        assert_eq!(one.inv_mod(&modulus).unwrap(), one);
+
+        // Not coprime
+        let (_, is_some) = BoxedUint::from(3u8)
+            .widen(128)
+            .inv_odd_mod(&BoxedUint::from(15u8).widen(128));
+        assert!(!bool::from(is_some));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn inv_odd_mod_random() {
+        use crate::NonZero;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let modulus = BoxedUint::random(&mut rng, 256) | BoxedUint::one_with_precision(256);
+            let nz_modulus = NonZero::new(modulus.clone()).unwrap();
+            let x = BoxedUint::random(&mut rng, 256).rem(&nz_modulus);
+
+            let (inv, is_some) = x.inv_odd_mod(&modulus);
+            assert_eq!(inv.bits_precision(), modulus.bits_precision());
+
+            if bool::from(is_some) {
+                assert_eq!(
+                    x.mul(&inv).rem(&nz_modulus),
+                    BoxedUint::one_with_precision(256)
+                );
+                assert_eq!(x.inv_mod(&modulus).unwrap(), inv);
+            } else {
+                assert!(bool::from(x.inv_mod(&modulus).is_none()));
+            }
+        }
+    }
 }
@@ -1,6 +1,9 @@
 //! [`BoxedUint`] multiplication operations.
 
-use crate::{uint::mul::mul_limbs, BoxedUint, CheckedMul, Limb, WideningMul, Wrapping, Zero};
+use crate::{
+    uint::mul::{mul_limbs, mul_limbs_with_threshold, square_limbs},
+    BoxedUint, CheckedMul, Limb, WideningMul, Wrapping, Zero,
+};
 use core::ops::{Mul, MulAssign};
 use subtle::{Choice, CtOption};
 
@@ -14,6 +17,19 @@ impl BoxedUint {
         limbs.into()
     }
 
+    /// Multiply `self` by `rhs` like [`BoxedUint::mul`], but only using Karatsuba multiplication
+    /// for operands of at least `karatsuba_min_limbs` limbs, e.g. `usize::MAX` always uses
+    /// schoolbook multiplication.
+    ///
+    /// This is used to measure where Karatsuba multiplication starts to pay off, and isn't part of
+    /// the stable API.
+    #[doc(hidden)]
+    pub fn mul_with_karatsuba_threshold(&self, rhs: &Self, karatsuba_min_limbs: usize) -> Self {
+        let mut limbs = vec![Limb::ZERO; self.nlimbs() + rhs.nlimbs()];
+        mul_limbs_with_threshold(&self.limbs, &rhs.limbs, &mut limbs, karatsuba_min_limbs);
+        limbs.into()
+    }
+
     /// Perform wrapping multiplication, wrapping to the width of `self`.
     pub fn wrapping_mul(&self, rhs: &Self) -> Self {
         self.mul(rhs).shorten(self.bits_precision())
@@ -21,8 +37,9 @@ impl BoxedUint {
 
     /// Multiply `self` by itself.
     pub fn square(&self) -> Self {
-        // TODO(tarcieri): more optimized implementation (shared with `Uint`?)
-        self.mul(self)
+        let mut limbs = vec![Limb::ZERO; self.nlimbs() * 2];
+        square_limbs(&self.limbs, &mut limbs);
+        limbs.into()
     }
 }
 
@@ -45,9 +62,7 @@ impl CheckedMul<&BoxedUint> for BoxedUint {
             .iter()
             .fold(Choice::from(1), |choice, limb| choice & limb.is_zero());
 
-        let mut limbs = product.limbs.into_vec();
-        limbs.truncate(self.nlimbs());
-        CtOption::new(limbs.into(), is_some)
+        CtOption::new(product.limbs[..self.nlimbs()].into(), is_some)
     }
 }
 
@@ -131,6 +146,35 @@ mod tests {
         assert_eq!(BoxedUint::one().mul(&BoxedUint::one()), BoxedUint::one());
     }
 
+    #[test]
+    fn square_matches_mul() {
+        for x in [
+            BoxedUint::zero(),
+            BoxedUint::one(),
+            BoxedUint::from(0xffff_ffff_ffff_ffffu64),
+            BoxedUint::max(256),
+            BoxedUint::from(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128),
+        ] {
+            assert_eq!(x.square(), x.mul(&x));
+        }
+    }
+
+    #[test]
+    fn mul_with_karatsuba_threshold() {
+        let x = BoxedUint::max(4096);
+        let y = BoxedUint::max(4096)
+            .shr_vartime(17)
+            .wrapping_sub(&BoxedUint::from(12345u32));
+        let expected = x.mul(&y);
+
+        for karatsuba_min_limbs in [0, 2, 3, 16, usize::MAX] {
+            assert_eq!(
+                x.mul_with_karatsuba_threshold(&y, karatsuba_min_limbs),
+                expected
+            );
+        }
+    }
+
     #[test]
     fn mul_primes() {
         let primes: &[u32] = &[3, 5, 17, 257, 65537];
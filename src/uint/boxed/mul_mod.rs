@@ -4,6 +4,7 @@ use crate::{
     modular::{BoxedResidue, BoxedResidueParams},
     BoxedUint, Limb, MulMod, WideWord, Word,
 };
+use subtle::ConstantTimeEq;
 
 impl BoxedUint {
     /// Computes `self * rhs mod p` for odd `p`.
@@ -28,6 +29,46 @@ impl BoxedUint {
         }
     }
 
+    /// Computes `self * scalar mod p` for a single-limb `scalar`.
+    ///
+    /// `self` doesn't need to be reduced modulo `p`, but must have its precision. The product only
+    /// has one more limb than `self`, so if `p` uses the full precision this only needs
+    /// `Limb::BITS` conditional subtractions to reduce it, rather than a full-width reduction.
+    ///
+    /// This is variable-time only with respect to `p`.
+    ///
+    /// Panics if `p` is zero.
+    pub fn mul_limb_mod(&self, scalar: Limb, p: &Self) -> Self {
+        debug_assert_eq!(self.bits_precision(), p.bits_precision());
+        let mb = p.bits_vartime();
+        assert!(mb > 0, "modulus must be nonzero");
+
+        let nlimbs = self.nlimbs();
+        let mut product = Self::zero_with_precision(self.bits_precision() + Limb::BITS);
+        let mut carry = Limb::ZERO;
+        for i in 0..nlimbs {
+            let (n, c) = Limb::ZERO.mac(self.limbs[i], scalar, carry);
+            product.limbs[i] = n;
+            carry = c;
+        }
+        product.limbs[nlimbs] = carry;
+
+        // Subtract each multiple `p * 2^i` which fits, from the largest down
+        let mut bd = product.bits_precision() - mb;
+        let mut c = p.widen(product.bits_precision()).shl_vartime(bd);
+        loop {
+            let (diff, borrow) = product.sbb(&c, Limb::ZERO);
+            product.conditional_assign(&diff, borrow.ct_eq(&Limb::ZERO));
+            if bd == 0 {
+                break;
+            }
+            bd -= 1;
+            c.shr1_assign();
+        }
+
+        product.shorten(self.bits_precision())
+    }
+
     /// Computes `self * rhs mod p` for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -91,9 +132,43 @@ fn mac_by_limb(a: &BoxedUint, b: &BoxedUint, c: Limb, carry: Limb) -> (BoxedUint
 
 #[cfg(all(test, feature = "rand"))]
 mod tests {
-    use crate::{Limb, NonZero, Random, RandomMod, Uint};
+    use crate::{BoxedUint, Limb, NonZero, Random, RandomMod, Uint};
     use rand_core::SeedableRng;
 
+    #[test]
+    fn mul_limb_mod() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        for bits_precision in [64, 256, 4096] {
+            let full = BoxedUint::random(&mut rng, bits_precision).bitor(&BoxedUint::one());
+            for p in [
+                full.clone(),
+                full.shr_vartime(bits_precision / 2),
+                BoxedUint::from(3u8),
+            ] {
+                let p = p.widen(bits_precision);
+                let modulus = NonZero::new(p.widen(bits_precision + Limb::BITS)).unwrap();
+
+                for scalar in [Limb::ZERO, Limb::ONE, Limb::from(3u8), Limb::MAX] {
+                    // `self` doesn't need to be reduced
+                    let a = BoxedUint::random(&mut rng, bits_precision);
+                    let expected = a
+                        .mul(&BoxedUint::from(scalar.0))
+                        .shorten(bits_precision + Limb::BITS)
+                        .rem(&modulus)
+                        .shorten(bits_precision);
+                    assert_eq!(a.mul_limb_mod(scalar, &p), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be nonzero")]
+    fn mul_limb_mod_zero_modulus() {
+        BoxedUint::one().mul_limb_mod(Limb::ONE, &BoxedUint::zero_with_precision(64));
+    }
+
     macro_rules! test_mul_mod_special {
         ($size:expr, $test_name:ident) => {
             #[test]
@@ -0,0 +1,147 @@
+//! [`BoxedUint`] modular exponentiation operations.
+
+use crate::{
+    modular::{BoxedResidue, BoxedResidueParams},
+    BoxedUint, Limb, NonZero,
+};
+use subtle::{Choice, ConstantTimeEq};
+
+impl BoxedUint {
+    /// Computes `self ^ exponent mod modulus` for any nonzero `modulus`.
+    ///
+    /// The modulus is decomposed as `modulus = s * 2^k` where `s` is odd. The power is computed
+    /// modulo `s` using Montgomery exponentiation via [`BoxedResidue`] and modulo `2^k` using
+    /// square-and-multiply, and the two are then recombined using the CRT.
+    ///
+    /// The result has the precision of `modulus`, while `self` and `exponent` may have any
+    /// precision.
+    ///
+    /// Constant-time with respect to `self` and `exponent`, but not `modulus`, which is assumed
+    /// to be public.
+    pub fn pow_mod(&self, exponent: &Self, modulus: &NonZero<Self>) -> Self {
+        let bits_precision = modulus.bits_precision();
+        let one = Self::one_with_precision(bits_precision);
+        let base = self.rem(modulus);
+
+        // Decompose `modulus = s * 2^k` where `s` is odd
+        let k = modulus.trailing_zeros();
+        let s = modulus.shr(k);
+
+        // Everything is congruent to zero modulo one, but Montgomery form doesn't account for it,
+        // so a surrogate odd modulus is used in that case
+        let s_is_one = s.ct_eq(&one);
+        let s_nz = Self::conditional_select(&s, &Self::from(3u8).widen(bits_precision), s_is_one);
+        let params = BoxedResidueParams::new(s_nz.clone()).expect("surrogate modulus is odd");
+        let s_nz = NonZero::new(s_nz).expect("surrogate modulus is nonzero");
+        let a = BoxedResidue::new(base.rem(&s_nz), params)
+            .pow(exponent)
+            .retrieve();
+        let a = Self::conditional_select(&a, &Self::zero_with_precision(bits_precision), s_is_one);
+
+        // This part is mod 2^k
+        let mask = one.shl(k).wrapping_sub(&one);
+        let b = pow_mod2k(&base, exponent, &mask);
+
+        // Restore from RNS:
+        // self^exponent = a mod s = b mod 2^k
+        // => self^exponent = a + s * ((b - a) * s^(-1) mod 2^k)
+        let s_inv = s.inv_mod2k(k); // `s` is odd, so this always exists
+        let t = b.wrapping_sub(&a).wrapping_mul(&s_inv).bitand(&mask);
+
+        // Will not overflow since `a <= s - 1`, `t <= 2^k - 1`,
+        // so `a + s * t <= s * 2^k - 1 == modulus - 1`.
+        a.wrapping_add(&s.wrapping_mul(&t))
+    }
+}
+
+/// Square-and-multiply exponentiation modulo `2^k`, where `mask = 2^k - 1`.
+fn pow_mod2k(base: &BoxedUint, exponent: &BoxedUint, mask: &BoxedUint) -> BoxedUint {
+    let mut z = BoxedUint::one_with_precision(mask.bits_precision()).bitand(mask);
+
+    for i in (0..exponent.bits_precision()).rev() {
+        z = z.wrapping_mul(&z).bitand(mask);
+        let product = z.wrapping_mul(base).bitand(mask);
+
+        let limb = exponent.limbs[(i / Limb::BITS) as usize].0;
+        let bit = Choice::from(((limb >> (i % Limb::BITS)) & 1) as u8);
+        z = BoxedUint::conditional_select(&z, &product, bit);
+    }
+
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, NonZero};
+
+    /// Reference implementation for moduli which fit in a `u64`.
+    fn pow_mod_u128(base: u128, mut exponent: u128, modulus: u128) -> u128 {
+        let mut base = base % modulus;
+        let mut ret = 1 % modulus;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                ret = ret * base % modulus;
+            }
+            base = base * base % modulus;
+            exponent >>= 1;
+        }
+
+        ret
+    }
+
+    fn check(base: u64, exponent: u64, modulus: u64) {
+        let actual = BoxedUint::from(base).pow_mod(
+            &BoxedUint::from(exponent),
+            &NonZero::new(BoxedUint::from(modulus)).unwrap(),
+        );
+        let expected = pow_mod_u128(base.into(), exponent.into(), modulus.into());
+        assert_eq!(
+            actual,
+            BoxedUint::from(expected as u64),
+            "{base}^{exponent} mod {modulus}"
+        );
+    }
+
+    #[test]
+    fn pow_mod_odd() {
+        for modulus in [1, 3, 997, 0xffff_fffb, 0xffff_ffff_ffff_ffc5] {
+            for (base, exponent) in [(0, 0), (0, 5), (2, 0), (2, 10), (0xdead_beef, 0x1234_5678)] {
+                check(base, exponent, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn pow_mod_power_of_two() {
+        for k in [1, 2, 7, 32, 63] {
+            for (base, exponent) in [(0, 0), (3, 5), (2, 10), (0xdead_beef, 0x1234_5678)] {
+                check(base, exponent, 1 << k);
+            }
+        }
+    }
+
+    #[test]
+    fn pow_mod_mixed() {
+        for modulus in [
+            6,
+            12,
+            1000,
+            997 << 20,
+            0xffff_fffb << 16,
+            0xffff_ffff_ffff_fffe,
+        ] {
+            for (base, exponent) in [(0, 0), (3, 5), (2, 10), (0xdead_beef, 0x1234_5678)] {
+                check(base, exponent, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn pow_mod_wide_modulus() {
+        let modulus = NonZero::new(BoxedUint::from(997u64 << 20).widen(256)).unwrap();
+        let actual = BoxedUint::from(0xdead_beefu32).pow_mod(&BoxedUint::from(65537u32), &modulus);
+        let expected = pow_mod_u128(0xdead_beef, 65537, 997 << 20);
+        assert_eq!(actual, BoxedUint::from(expected).widen(256));
+    }
+}
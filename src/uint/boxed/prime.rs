@@ -0,0 +1,245 @@
+//! Random prime generation for [`BoxedUint`].
+//!
+//! Candidates are first sieved by trial division with small primes, and the survivors are tested
+//! using the Miller-Rabin probabilistic primality test.
+//!
+//! These functions are variable-time. Rejected candidates are discarded, so the time taken only
+//! leaks information about values which are never used.
+
+use super::BoxedUint;
+use crate::{
+    modular::{BoxedResidue, BoxedResidueParams},
+    Limb, NonZero, RandomMod, WideWord,
+};
+use rand_core::CryptoRngCore;
+
+/// Odd primes below 256, used for trial division before the Miller-Rabin test.
+const SMALL_PRIMES: [u8; 53] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// Number of Miller-Rabin rounds with random bases, giving an error probability below `2^-128`
+/// for any candidate.
+const MILLER_RABIN_ROUNDS: usize = 64;
+
+impl BoxedUint {
+    /// Generate a random prime with exactly `bit_length` significant bits.
+    ///
+    /// Panics if `bit_length` is less than 2.
+    pub fn generate_prime(rng: &mut impl CryptoRngCore, bit_length: u32) -> Self {
+        assert!(bit_length >= 2, "bit_length must be at least 2");
+
+        loop {
+            let candidate = random_odd(rng, bit_length);
+
+            if sieve(&candidate, |p| small_rem(&candidate, p)) && is_prime_vartime(rng, &candidate)
+            {
+                return candidate;
+            }
+        }
+    }
+
+    /// Generate a random safe prime `p` with exactly `bit_length` significant bits, i.e. a prime
+    /// for which `(p - 1) / 2` is also prime.
+    ///
+    /// Panics if `bit_length` is less than 3.
+    pub fn generate_safe_prime(rng: &mut impl CryptoRngCore, bit_length: u32) -> Self {
+        assert!(bit_length >= 3, "bit_length must be at least 3");
+
+        loop {
+            // `p = 2q + 1` has exactly one more significant bit than `q`
+            let q = random_odd(rng, bit_length - 1);
+            let p = q.widen(q.bits_precision().max(bit_length)).shl_vartime(1) | BoxedUint::one();
+
+            let sieved = sieve(&q, |prime| small_rem(&q, prime))
+                && sieve(&p, |prime| {
+                    (2 * small_rem(&q, prime) + 1) % WideWord::from(prime)
+                });
+
+            if sieved && is_prime_vartime(rng, &q) && is_prime_vartime(rng, &p) {
+                return p;
+            }
+        }
+    }
+}
+
+/// Generate a random odd value with exactly `bit_length` significant bits.
+fn random_odd(rng: &mut impl CryptoRngCore, bit_length: u32) -> BoxedUint {
+    BoxedUint::random_bits(rng, bit_length) | BoxedUint::one()
+}
+
+/// Compute `n mod p` for a small `p`.
+fn small_rem(n: &BoxedUint, p: u8) -> WideWord {
+    let p = WideWord::from(p);
+
+    n.limbs.iter().rev().fold(0, |rem, limb| {
+        ((rem << Limb::BITS) | WideWord::from(limb.0)) % p
+    })
+}
+
+/// Returns `false` if `n` is divisible by any of [`SMALL_PRIMES`] other than itself, where
+/// `rem(p)` computes `n mod p`.
+fn sieve(n: &BoxedUint, rem: impl Fn(u8) -> WideWord) -> bool {
+    SMALL_PRIMES
+        .iter()
+        .all(|&p| rem(p) != 0 || *n == BoxedUint::from(p).widen(n.bits_precision()))
+}
+
+/// Miller-Rabin probabilistic primality test with random bases, for odd `n`.
+pub(crate) fn is_prime_vartime(rng: &mut impl CryptoRngCore, n: &BoxedUint) -> bool {
+    debug_assert!(bool::from(n.is_odd()));
+
+    let bits_precision = n.bits_precision();
+    let one = BoxedUint::one_with_precision(bits_precision);
+    let three = BoxedUint::from(3u8).widen(bits_precision);
+
+    // Handle `n <= 3` directly, which also ensures `n - 3` below is nonzero
+    if *n <= three {
+        return *n != one;
+    }
+
+    let params = match BoxedResidueParams::new_vartime(n.clone()) {
+        Some(params) => params,
+        None => return false,
+    };
+
+    // `n - 1 = d * 2^s` with `d` odd
+    let n_minus_one = n.wrapping_sub(&one);
+    let mut s = 0;
+    while !bool::from(n_minus_one.shr_vartime(s).is_odd()) {
+        s += 1;
+    }
+    let d = n_minus_one.shr_vartime(s);
+
+    let one_m = BoxedResidue::one(params.clone());
+    let minus_one_m = BoxedResidue::new(n_minus_one, params.clone());
+    let base_range = NonZero::new(n.wrapping_sub(&three)).unwrap();
+
+    'rounds: for _ in 0..MILLER_RABIN_ROUNDS {
+        // Random base in `[2, n - 2]`
+        let base = BoxedUint::random_mod(rng, &base_range).wrapping_add(&BoxedUint::from(2u8));
+        let mut x = BoxedResidue::new(base, params.clone()).pow(&d);
+
+        if x.retrieve() == one_m.retrieve() || x.retrieve() == minus_one_m.retrieve() {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = x.square();
+            if x.retrieve() == minus_one_m.retrieve() {
+                continue 'rounds;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_prime_vartime, sieve, small_rem, SMALL_PRIMES};
+    use crate::BoxedUint;
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+    use rand_chacha::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    /// Fermat test using `num-bigint` as an independent check.
+    fn fermat_check(n: &BoxedUint) -> bool {
+        let n = BigUint::from_bytes_be(&n.to_be_bytes());
+        let n_minus_one = &n - 1u8;
+
+        [2u8, 3, 5, 7, 11, 13]
+            .iter()
+            .map(|&a| BigUint::from(a))
+            .filter(|a| a < &n)
+            .all(|a| a.modpow(&n_minus_one, &n) == BigUint::from(1u8))
+    }
+
+    #[test]
+    fn sieve_rejects_multiples_of_small_primes() {
+        for &p in &SMALL_PRIMES {
+            let small = BoxedUint::from(p).widen(128);
+            assert!(sieve(&small, |q| small_rem(&small, q)));
+
+            for k in [3u128, 0xffff_ffff_ffff_fffb, 0x1_0000_0000_0000_0001] {
+                let n = BoxedUint::from(k * u128::from(p));
+                assert_eq!(small_rem(&n, p), 0);
+                assert!(!sieve(&n, |q| small_rem(&n, q)));
+            }
+        }
+    }
+
+    #[test]
+    fn miller_rabin() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        let primes = [
+            BoxedUint::from(3u8),
+            BoxedUint::from(257u16),
+            BoxedUint::from(0xffff_fffbu32),
+            BoxedUint::from_be_slice(
+                &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+                256,
+            )
+            .unwrap(),
+        ];
+        for p in &primes {
+            assert!(is_prime_vartime(&mut rng, p), "{} should be prime", p);
+        }
+
+        // Carmichael numbers and a product of two large primes
+        let composites = [
+            BoxedUint::from(1u8),
+            BoxedUint::from(561u16),
+            BoxedUint::from(41041u16),
+            BoxedUint::from(0xffff_fffbu64 * 0xffff_ffefu64),
+        ];
+        for n in &composites {
+            assert!(!is_prime_vartime(&mut rng, n), "{} should be composite", n);
+        }
+    }
+
+    #[test]
+    fn generate_prime() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for bit_length in [2, 3, 16, 64, 65, 256] {
+            let p = BoxedUint::generate_prime(&mut rng, bit_length);
+            assert_eq!(p.bits(), bit_length);
+            assert!(fermat_check(&p));
+        }
+
+        // Deterministic output for a fixed seed, which depends on the limb size as candidates are
+        // sampled a limb at a time
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let p = BoxedUint::generate_prime(&mut rng, 256);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            p,
+            BoxedUint::from_be_hex(
+                "8eb26eefe5a0fe7a794c86e203bd5348cebc835e1f0fa4ee87b24400323218db"
+            )
+            .unwrap()
+        );
+        assert!(fermat_check(&p));
+    }
+
+    #[test]
+    fn generate_safe_prime() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for bit_length in [3, 16, 64, 65, 128] {
+            let p = BoxedUint::generate_safe_prime(&mut rng, bit_length);
+            assert_eq!(p.bits(), bit_length);
+            assert!(fermat_check(&p));
+
+            let q = p.shr_vartime(1);
+            assert!(fermat_check(&q));
+        }
+    }
+}
@@ -2,12 +2,16 @@
 
 use super::BoxedUint;
 use crate::{uint::rand::random_mod_core, Limb, NonZero, Random, RandomMod};
-use rand_core::CryptoRngCore;
+use rand_core::{CryptoRngCore, RngCore};
+use subtle::Choice;
 
 impl BoxedUint {
-    /// Generate a cryptographically secure random [`BoxedUint`].
-    /// in range `[0, 2^bits_precision)`.
-    pub fn random(rng: &mut impl CryptoRngCore, bits_precision: u32) -> Self {
+    /// Generate a random [`BoxedUint`] in range `[0, 2^bits_precision)`.
+    ///
+    /// Any [`RngCore`] is accepted, so this is cryptographically secure only if `rng` is a CSRNG.
+    /// Use [`BoxedUint::random_bits`] or [`RandomMod`] for secret values, which require a
+    /// [`CryptoRngCore`].
+    pub fn random(rng: &mut impl RngCore, bits_precision: u32) -> Self {
         let mut ret = BoxedUint::zero_with_precision(bits_precision);
 
         for limb in &mut *ret.limbs {
@@ -21,6 +25,41 @@ impl BoxedUint {
 
         ret
     }
+
+    /// Generate a cryptographically secure random [`BoxedUint`] with exactly `bit_length`
+    /// significant bits, i.e. in range `[2^(bit_length-1), 2^bit_length)`.
+    ///
+    /// The most significant bit is always set, so e.g. `bit_length == 1` always returns 1. The
+    /// precision of the result is `bit_length` rounded up to a multiple of [`Limb::BITS`].
+    ///
+    /// Panics if `bit_length` is zero.
+    pub fn random_bits(rng: &mut impl CryptoRngCore, bit_length: u32) -> Self {
+        assert!(bit_length > 0, "bit_length must be nonzero");
+
+        let mut ret = Self::random(rng, bit_length);
+        ret.set_bit(bit_length - 1, Choice::from(1));
+        ret
+    }
+
+    /// Generate a cryptographically secure random [`BoxedUint`] in range `[lo, hi)`, by sampling
+    /// an offset with [`RandomMod::random_mod`] and adding it to `lo`.
+    ///
+    /// The result has the precision of `hi`, while `lo` may have any precision.
+    ///
+    /// Panics if the range is empty, i.e. if `lo >= hi`.
+    pub fn random_range(rng: &mut impl CryptoRngCore, lo: &Self, hi: &Self) -> Self {
+        assert!(lo < hi, "range must be nonempty");
+
+        // `lo < hi`, so it fits in the precision of `hi`
+        let lo = if lo.bits_precision() > hi.bits_precision() {
+            lo.shorten(hi.bits_precision())
+        } else {
+            lo.widen(hi.bits_precision())
+        };
+
+        let range = NonZero::new(hi.wrapping_sub(&lo)).expect("range is nonzero");
+        Self::random_mod(rng, &range).wrapping_add(&lo)
+    }
 }
 
 impl RandomMod for BoxedUint {
@@ -42,7 +81,7 @@ impl RandomMod for BoxedUint {
 
 #[cfg(test)]
 mod tests {
-    use crate::{BoxedUint, NonZero, RandomMod};
+    use crate::{BoxedUint, Limb, NonZero, Random, RandomMod, U256};
     use rand_core::SeedableRng;
 
     #[test]
@@ -57,6 +96,40 @@ mod tests {
         assert!(r < BoxedUint::one_with_precision(256) << (256 - 32 + 1));
     }
 
+    #[test]
+    fn random_matches_uint() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let r = BoxedUint::random(&mut rng, 256);
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        assert_eq!(r, BoxedUint::from(U256::random(&mut rng)));
+    }
+
+    #[test]
+    fn random_bits() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        assert_eq!(BoxedUint::random_bits(&mut rng, 1), BoxedUint::one());
+
+        for bit_length in [2, 63, 64, 65, 255, 256, 1023, 1024] {
+            for _ in 0..8 {
+                let r = BoxedUint::random_bits(&mut rng, bit_length);
+                assert_eq!(r.bits(), bit_length);
+                assert_eq!(
+                    r.bits_precision(),
+                    (bit_length + Limb::BITS - 1) / Limb::BITS * Limb::BITS
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "bit_length must be nonzero")]
+    fn random_bits_zero() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        BoxedUint::random_bits(&mut rng, 0);
+    }
+
     #[test]
     fn random_mod() {
         let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
@@ -76,4 +149,50 @@ mod tests {
         // Check that the value is in range
         assert!(res < BoxedUint::from(0x10000000000000001u128));
     }
+
+    #[test]
+    fn random_range() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let lo = BoxedUint::from(100u8);
+        let hi = BoxedUint::from(110u8).widen(128);
+
+        let mut seen = [false; 10];
+        for _ in 0..200 {
+            let r = BoxedUint::random_range(&mut rng, &lo, &hi);
+            assert_eq!(r.bits_precision(), 128);
+            assert!(lo <= r && r < hi);
+            seen[u64::try_from(&r).unwrap() as usize - 100] = true;
+        }
+
+        // Both ends of the range are reachable
+        assert_eq!(seen, [true; 10]);
+
+        // Wider `lo`, and a singleton range
+        let lo = BoxedUint::from(0x1234_5678u32).widen(256);
+        let hi = lo.wrapping_add(&BoxedUint::one()).shorten(64);
+        assert_eq!(BoxedUint::random_range(&mut rng, &lo, &hi), lo);
+
+        // Ranges spanning multiple limbs
+        let lo = BoxedUint::max(64).widen(256);
+        let hi = BoxedUint::max(256);
+        for _ in 0..16 {
+            let r = BoxedUint::random_range(&mut rng, &lo, &hi);
+            assert!(lo <= r && r < hi);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "range must be nonempty")]
+    fn random_range_empty() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let n = BoxedUint::from(42u8);
+        BoxedUint::random_range(&mut rng, &n, &n);
+    }
+
+    #[test]
+    #[should_panic(expected = "range must be nonempty")]
+    fn random_range_reversed() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        BoxedUint::random_range(&mut rng, &BoxedUint::from(43u8), &BoxedUint::from(42u8));
+    }
 }
@@ -2,7 +2,7 @@
 
 use crate::{BoxedUint, CtChoice, Limb, Word};
 use core::ops::{Shl, ShlAssign};
-use subtle::{Choice, ConstantTimeLess};
+use subtle::{Choice, ConstantTimeEq, ConstantTimeLess};
 
 impl BoxedUint {
     /// Computes `self << shift`.
@@ -25,6 +25,20 @@ impl BoxedUint {
         )
     }
 
+    /// Computes `self << shift` truncated to the precision of `self`, along with the truthy value
+    /// if any set bits were shifted out, i.e. if information was lost.
+    ///
+    /// Unlike [`u64::overflowing_shl`], the overflow reflects the value of `self` rather than
+    /// only the shift amount: shifting zero never overflows, while shifting a nonzero value by
+    /// `shift >= self.bits_precision()` always does.
+    pub fn overflowing_shl(&self, shift: u32) -> (Self, Choice) {
+        let result = self.shl(shift);
+
+        // Bits were only lost if shifting back doesn't restore the original value
+        let overflow = !result.shr(shift).ct_eq(self);
+        (result, overflow)
+    }
+
     /// Computes `self << shift`.
     ///
     /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
@@ -128,4 +142,34 @@ mod tests {
             one.shl_vartime(67)
         );
     }
+
+    #[test]
+    fn overflowing_shl() {
+        let n = BoxedUint::from(0xc000_0000_0000_0001u64).widen(128);
+
+        // Shifting within the precision
+        let (result, overflow) = n.overflowing_shl(64);
+        assert_eq!(
+            result,
+            BoxedUint::from(0xc000_0000_0000_0001_0000_0000_0000_0000u128)
+        );
+        assert!(!bool::from(overflow));
+
+        // Shifting the high set bit out of the precision
+        let (result, overflow) = n.overflowing_shl(65);
+        assert_eq!(
+            result,
+            BoxedUint::from(0x8000_0000_0000_0002_0000_0000_0000_0000u128)
+        );
+        assert!(bool::from(overflow));
+
+        for shift in [127, 128, 200] {
+            assert!(bool::from(n.overflowing_shl(shift).1), "shift = {shift}");
+        }
+
+        assert!(!bool::from(BoxedUint::one().overflowing_shl(63).1));
+        assert!(bool::from(BoxedUint::one().overflowing_shl(64).1));
+        assert!(!bool::from(BoxedUint::zero().overflowing_shl(64).1));
+        assert!(!bool::from(BoxedUint::zero().overflowing_shl(200).1));
+    }
 }
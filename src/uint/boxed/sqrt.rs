@@ -0,0 +1,168 @@
+//! [`BoxedUint`] square root operations.
+
+use crate::{BoxedUint, Limb};
+use subtle::{Choice, ConstantTimeEq};
+
+impl BoxedUint {
+    /// Computes √(`self`) in constant time, rounded down.
+    ///
+    /// The result has half the precision of `self`, rounded up to a whole number of limbs.
+    ///
+    /// Callers can check if `self` is a square by squaring the result, or by using
+    /// [`BoxedUint::sqrt_rem`].
+    pub fn sqrt(&self) -> Self {
+        self.sqrt_rem().0
+    }
+
+    /// Computes √(`self`) in constant time, rounded down, along with the remainder
+    /// `self - root^2`.
+    ///
+    /// The root has half the precision of `self`, rounded up to a whole number of limbs, and the
+    /// remainder has the precision of `self`. The remainder is zero if and only if `self` is a
+    /// perfect square.
+    pub fn sqrt_rem(&self) -> (Self, Self) {
+        // Uses the bit-by-bit method, which determines one bit of the root per iteration.
+        // `rem` holds the remainder `self - root^2`, and `root` holds the root found so far shifted
+        // left by `pos + 1` bits, where `pos` is the position of the bit being determined.
+        let bits_precision = self.bits_precision();
+        let mut rem = self.clone();
+        let mut root = Self::zero_with_precision(bits_precision);
+        let mut bit = Self::zero_with_precision(bits_precision);
+
+        // The precision is a whole number of limbs, so it's always even
+        let mut pos = bits_precision;
+        while pos > 0 {
+            pos -= 2;
+            bit.set_bit(pos, Choice::from(1));
+
+            let candidate = root.wrapping_add(&bit);
+            let (diff, borrow) = rem.sbb(&candidate, Limb::ZERO);
+            let is_ge = borrow.ct_eq(&Limb::ZERO);
+
+            rem = Self::conditional_select(&rem, &diff, is_ge);
+            root.shr1_assign();
+            root.conditional_adc_assign(&bit, is_ge);
+
+            bit.set_bit(pos, Choice::from(0));
+        }
+
+        (root.shorten((bits_precision + 1) / 2), rem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedUint;
+    use hex_literal::hex;
+
+    #[test]
+    fn edge() {
+        assert_eq!(BoxedUint::zero().sqrt(), BoxedUint::zero());
+        assert_eq!(BoxedUint::one().sqrt(), BoxedUint::one());
+        assert_eq!(BoxedUint::from(3u8).sqrt(), BoxedUint::one());
+        assert_eq!(BoxedUint::from(4u8).sqrt(), BoxedUint::from(2u8));
+        assert_eq!(BoxedUint::max(64).sqrt(), BoxedUint::from(0xffff_ffffu32));
+        assert_eq!(BoxedUint::max(256).sqrt(), BoxedUint::max(128));
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(
+            BoxedUint::zero_with_precision(64).sqrt().bits_precision(),
+            64
+        );
+        assert_eq!(
+            BoxedUint::zero_with_precision(128).sqrt().bits_precision(),
+            64
+        );
+        assert_eq!(
+            BoxedUint::zero_with_precision(192).sqrt().bits_precision(),
+            128
+        );
+        assert_eq!(
+            BoxedUint::zero_with_precision(2048).sqrt().bits_precision(),
+            1024
+        );
+    }
+
+    #[test]
+    fn exact_square_of_prime() {
+        let p = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            256,
+        )
+        .unwrap();
+        assert_eq!(p.square().sqrt(), p);
+        assert_eq!(
+            p.square().sqrt_rem(),
+            (p.clone(), BoxedUint::zero_with_precision(512))
+        );
+        assert_eq!(
+            p.square().wrapping_sub(&BoxedUint::one()).sqrt(),
+            p.wrapping_sub(&BoxedUint::one())
+        );
+    }
+
+    #[test]
+    fn sqrt_rem() {
+        assert_eq!(
+            BoxedUint::zero().sqrt_rem(),
+            (BoxedUint::zero(), BoxedUint::zero())
+        );
+        assert_eq!(
+            BoxedUint::from(15u8).sqrt_rem(),
+            (BoxedUint::from(3u8), BoxedUint::from(6u8))
+        );
+        assert_eq!(
+            BoxedUint::from(16u8).sqrt_rem(),
+            (BoxedUint::from(4u8), BoxedUint::zero())
+        );
+        assert_eq!(
+            BoxedUint::max(128).sqrt_rem(),
+            (BoxedUint::max(64), BoxedUint::max(64).widen(128).shl1())
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_sqrt_rem() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for bits_precision in [64, 128, 192, 256, 1024] {
+            for _ in 0..10 {
+                let n = BoxedUint::random(&mut rng, bits_precision);
+                let (r, rem) = n.sqrt_rem();
+                assert_eq!(rem.bits_precision(), bits_precision);
+
+                // `r^2 + rem == n`, and `rem <= 2 * r` since `(r + 1)^2 > n`
+                let square = r.square();
+                let wide = square.bits_precision();
+                assert_eq!(square.wrapping_add(&rem.widen(wide)), n.widen(wide));
+                assert!(rem <= r.widen(bits_precision).shl1());
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for bits_precision in [64, 128, 192, 256, 1024] {
+            for _ in 0..10 {
+                let n = BoxedUint::random(&mut rng, bits_precision);
+                let r = n.sqrt();
+                let r_plus_one = r.widen(bits_precision).wrapping_add(&BoxedUint::one());
+
+                assert!(r.square() <= n);
+                assert!(r_plus_one.square() > n);
+            }
+        }
+    }
+}
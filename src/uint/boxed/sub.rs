@@ -15,7 +15,7 @@ impl BoxedUint {
     /// Panics if `rhs` has a larger precision than `self`.
     #[inline(always)]
     pub fn sbb_assign(&mut self, rhs: &Self, mut borrow: Limb) -> Limb {
-        debug_assert!(self.bits_precision() <= rhs.bits_precision());
+        debug_assert!(rhs.bits_precision() <= self.bits_precision());
 
         for i in 0..self.nlimbs() {
             let (limb, b) = self.limbs[i].sbb(*rhs.limbs.get(i).unwrap_or(&Limb::ZERO), borrow);
@@ -31,10 +31,20 @@ impl BoxedUint {
         self.sbb(rhs, Limb::ZERO).0
     }
 
+    /// Perform checked subtraction, returning a [`CtOption`] which `is_some` only if `self >= rhs`,
+    /// i.e. if the subtraction doesn't underflow.
+    ///
+    /// If the precisions differ the shorter operand is zero-extended, so the result has the
+    /// precision of the wider one, which is that of `self` unless `rhs` is wider.
+    pub fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        let (result, borrow) = self.sbb(rhs, Limb::ZERO);
+        CtOption::new(result, borrow.is_zero())
+    }
+
     /// Perform in-place wrapping subtraction, returning the truthy value as the second element of
     /// the tuple if an underflow has occurred.
     pub(crate) fn conditional_sbb_assign(&mut self, rhs: &Self, choice: Choice) -> Choice {
-        debug_assert!(self.bits_precision() <= rhs.bits_precision());
+        debug_assert!(rhs.bits_precision() <= self.bits_precision());
         let mask = Limb::conditional_select(&Limb::ZERO, &Limb::MAX, choice);
         let mut borrow = Limb::ZERO;
 
@@ -53,8 +63,7 @@ impl CheckedSub<&BoxedUint> for BoxedUint {
     type Output = Self;
 
     fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
-        let (result, carry) = self.sbb(rhs, Limb::ZERO);
-        CtOption::new(result, carry.is_zero())
+        self.checked_sub(rhs)
     }
 }
 
@@ -91,5 +100,59 @@ mod tests {
     fn checked_sub_overflow() {
         let result = BoxedUint::zero().checked_sub(&BoxedUint::one());
         assert!(!bool::from(result.is_some()));
+
+        let result = CheckedSub::checked_sub(&BoxedUint::zero(), &BoxedUint::one());
+        assert!(!bool::from(result.is_some()));
+    }
+
+    #[test]
+    fn checked_sub_mixed_precision() {
+        let a = BoxedUint::from(0x1_0000_0000_0000_0000u128);
+        let b = BoxedUint::one();
+
+        let result = a.checked_sub(&b).unwrap();
+        assert_eq!(result, BoxedUint::from(u64::MAX).widen(128));
+        assert_eq!(result.bits_precision(), 128);
+
+        let result = Option::<BoxedUint>::from(b.widen(256).checked_sub(&a));
+        assert!(result.is_none());
+
+        // The high limbs of a wider `rhs` aren't ignored
+        let result = Option::<BoxedUint>::from(BoxedUint::from(u64::MAX).checked_sub(&a));
+        assert!(result.is_none());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn checked_sub_random() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..64 {
+            let (a, b) = (
+                u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64()),
+                u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64()),
+            );
+            let (a_bits, b_bits) = (128 + rng.next_u32() % 256, 128 + rng.next_u32() % 256);
+            let (x, y) = (
+                BoxedUint::from(a).widen(a_bits),
+                BoxedUint::from(b).widen(b_bits),
+            );
+
+            let result = Option::<BoxedUint>::from(x.checked_sub(&y));
+            match a.checked_sub(b) {
+                Some(expected) => {
+                    let result = result.unwrap();
+                    assert_eq!(result, BoxedUint::from(expected));
+                    assert_eq!(
+                        result.bits_precision(),
+                        x.bits_precision().max(y.bits_precision())
+                    );
+                }
+                None => assert!(result.is_none()),
+            }
+        }
     }
 }
@@ -1,6 +1,7 @@
 //! [`BoxedUint`] modular subtraction operations.
 
 use crate::{BoxedUint, Limb, SubMod};
+use subtle::{Choice, ConditionallySelectable};
 
 impl BoxedUint {
     /// Computes `self - rhs mod p`.
@@ -19,6 +20,27 @@ impl BoxedUint {
         out.wrapping_add(&p.bitand_limb(mask))
     }
 
+    /// Computes `self - rhs mod p` if `choice` is truthy, otherwise returns `self`.
+    ///
+    /// Assumes `self` and `rhs` are `< p` and have the same precision as `p`.
+    pub fn conditional_sub_mod(&self, rhs: &Self, p: &Self, choice: Choice) -> Self {
+        let mask = Limb::conditional_select(&Limb::ZERO, &Limb::MAX, choice);
+        self.sub_mod(&rhs.bitand_limb(mask), p)
+    }
+
+    /// Computes `self mod modulus` for `self` in `[0, 2 * modulus)`, i.e. subtracts `modulus` iff
+    /// `self >= modulus`, in constant time.
+    ///
+    /// Assumes `self` has the same precision as `modulus`.
+    pub fn reduce_once(&self, modulus: &Self) -> Self {
+        debug_assert_eq!(self.bits_precision(), modulus.bits_precision());
+
+        let (out, mask) = self.sbb(modulus, Limb::ZERO);
+
+        // Add the modulus back if the subtraction underflowed, i.e. if `self < modulus`
+        out.wrapping_add(&modulus.bitand_limb(mask))
+    }
+
     /// Computes `self - rhs mod p` for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -45,7 +67,22 @@ impl SubMod for BoxedUint {
 #[cfg(test)]
 mod tests {
     use super::BoxedUint;
+    use crate::NonZero;
     use hex_literal::hex;
+    use subtle::Choice;
+
+    #[test]
+    fn reduce_once() {
+        let modulus = BoxedUint::from(97u8).widen(128);
+        for x in 0..(2 * 97u8) {
+            let x = BoxedUint::from(x).widen(128);
+            let expected = x.rem(&NonZero::new(modulus.clone()).unwrap());
+            assert_eq!(x.reduce_once(&modulus), expected);
+            if x < modulus {
+                assert_eq!(x.reduce_once(&modulus), x);
+            }
+        }
+    }
 
     #[test]
     fn sub_mod_nist_p256() {
@@ -74,4 +111,30 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn conditional_sub_mod() {
+        let a = BoxedUint::from_be_slice(
+            &hex!("1a2472fde50286541d97ca6a3592dd75beb9c9646e40c511b82496cfc3926956"),
+            256,
+        )
+        .unwrap();
+        let b = BoxedUint::from_be_slice(
+            &hex!("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251"),
+            256,
+        )
+        .unwrap();
+        let n = BoxedUint::from_be_slice(
+            &hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+            256,
+        )
+        .unwrap();
+
+        let actual = a.conditional_sub_mod(&b, &n, Choice::from(1));
+        assert_eq!(actual, a.sub_mod(&b, &n));
+        assert!(actual < n);
+
+        let actual = a.conditional_sub_mod(&b, &n, Choice::from(0));
+        assert_eq!(actual, a);
+    }
 }
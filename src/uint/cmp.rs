@@ -30,9 +30,33 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (new_a, new_b)
     }
 
-    /// Returns the truthy value if `self`!=0 or the falsy value otherwise.
+    /// Swap `a` and `b` in constant time if `choice` is truthy, otherwise leave both unchanged.
+    ///
+    /// Implemented as a masked XOR swap, so the memory access pattern is independent of `choice`.
     #[inline]
-    pub(crate) const fn ct_is_nonzero(&self) -> CtChoice {
+    pub fn ct_swap_in_place(a: &mut Self, b: &mut Self, choice: CtChoice) {
+        let mut i = 0;
+        while i < LIMBS {
+            let t = choice.if_true_word(a.limbs[i].0 ^ b.limbs[i].0);
+            a.limbs[i].0 ^= t;
+            b.limbs[i].0 ^= t;
+            i += 1;
+        }
+    }
+
+    /// Returns the truthy value if `self == 0` or the falsy value otherwise.
+    ///
+    /// All limbs are always inspected, regardless of their values.
+    #[inline]
+    pub const fn ct_is_zero(&self) -> CtChoice {
+        self.ct_is_nonzero().not()
+    }
+
+    /// Returns the truthy value if `self != 0` or the falsy value otherwise.
+    ///
+    /// All limbs are always inspected, regardless of their values.
+    #[inline]
+    pub const fn ct_is_nonzero(&self) -> CtChoice {
         let mut b = 0;
         let mut i = 0;
         while i < LIMBS {
@@ -42,11 +66,30 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Limb(b).ct_is_nonzero()
     }
 
+    /// Returns the truthy value if `self == 1` or the falsy value otherwise.
+    ///
+    /// All limbs are always inspected, regardless of their values.
+    #[inline]
+    pub const fn is_one(&self) -> CtChoice {
+        let mut b = self.limbs[0].0 ^ 1;
+        let mut i = 1;
+        while i < LIMBS {
+            b |= self.limbs[i].0;
+            i += 1;
+        }
+        Limb(b).ct_is_nonzero().not()
+    }
+
     /// Returns the truthy value if `self` is odd or the falsy value otherwise.
-    pub(crate) const fn ct_is_odd(&self) -> CtChoice {
+    pub const fn ct_is_odd(&self) -> CtChoice {
         CtChoice::from_word_lsb(self.limbs[0].0 & 1)
     }
 
+    /// Returns the truthy value if `self` is even or the falsy value otherwise.
+    pub const fn ct_is_even(&self) -> CtChoice {
+        self.ct_is_odd().not()
+    }
+
     /// Returns the truthy value if `self == rhs` or the falsy value otherwise.
     #[inline]
     pub(crate) const fn ct_eq(lhs: &Self, rhs: &Self) -> CtChoice {
@@ -79,13 +122,12 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         CtChoice::from_word_mask(borrow.0)
     }
 
-    /// Returns the ordering between `self` and `rhs` as an i8.
-    /// Values correspond to the Ordering enum:
-    ///   -1 is Less
-    ///   0 is Equal
-    ///   1 is Greater
+    /// Returns the [`Ordering`] between `lhs` and `rhs` in constant time.
+    ///
+    /// This always inspects every limb of both operands and doesn't branch on their values. It's
+    /// also used for the [`Ord`] impl; use [`Uint::cmp_vartime`] for public values.
     #[inline]
-    pub(crate) const fn ct_cmp(lhs: &Self, rhs: &Self) -> i8 {
+    pub const fn ct_cmp(lhs: &Self, rhs: &Self) -> Ordering {
         let mut i = 0;
         let mut borrow = Limb::ZERO;
         let mut diff = Limb::ZERO;
@@ -97,7 +139,76 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             i += 1;
         }
         let sgn = ((borrow.0 & 2) as i8) - 1;
-        (diff.ct_is_nonzero().to_u8() as i8) * sgn
+        match (diff.ct_is_nonzero().to_u8() as i8) * sgn {
+            -1 => Ordering::Less,
+            0 => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+
+    /// Returns the truthy value if `self == rhs` for the single-limb scalar `rhs`, or the falsy
+    /// value otherwise.
+    #[inline]
+    pub const fn ct_eq_limb(&self, rhs: Limb) -> CtChoice {
+        self.high_limbs_ct_is_zero()
+            .and(CtChoice::from_word_eq(self.limbs[0].0, rhs.0))
+    }
+
+    /// Returns the truthy value if `self < rhs` for the single-limb scalar `rhs`, or the falsy
+    /// value otherwise.
+    #[inline]
+    pub const fn ct_lt_limb(&self, rhs: Limb) -> CtChoice {
+        self.high_limbs_ct_is_zero()
+            .and(CtChoice::from_word_lt(self.limbs[0].0, rhs.0))
+    }
+
+    /// Returns the [`Ordering`] between `self` and the single-limb scalar `rhs` in constant time.
+    pub const fn cmp_limb(&self, rhs: Limb) -> Ordering {
+        let is_lt = self.ct_lt_limb(rhs).to_u8() as i8;
+        let is_eq = self.ct_eq_limb(rhs).to_u8() as i8;
+        match 1 - 2 * is_lt - is_eq {
+            -1 => Ordering::Less,
+            0 => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+
+    /// Returns the truthy value if all limbs but the least significant one are zero.
+    #[inline]
+    const fn high_limbs_ct_is_zero(&self) -> CtChoice {
+        let mut b = 0;
+        let mut i = 1;
+        while i < LIMBS {
+            b |= self.limbs[i].0;
+            i += 1;
+        }
+        Limb(b).ct_is_nonzero().not()
+    }
+
+    /// Returns the minimum of `self` and `rhs` in constant time.
+    ///
+    /// Neither the timing nor the result reveal which operand was smaller. For public values
+    /// [`Ord::min`] can be used instead.
+    pub const fn ct_min(&self, rhs: &Self) -> Self {
+        Self::ct_select(self, rhs, Self::ct_lt(rhs, self))
+    }
+
+    /// Returns the maximum of `self` and `rhs` in constant time.
+    ///
+    /// Neither the timing nor the result reveal which operand was larger. For public values
+    /// [`Ord::max`] can be used instead.
+    pub const fn ct_max(&self, rhs: &Self) -> Self {
+        Self::ct_select(self, rhs, Self::ct_gt(rhs, self))
+    }
+
+    /// Returns the truthy value if `self ≡ rhs (mod modulus)`, or the falsy value otherwise.
+    ///
+    /// Neither operand needs to be reduced: this checks whether `|self - rhs|` is a multiple of
+    /// `modulus`. A zero modulus compares the operands for equality.
+    ///
+    /// This is variable-time only with respect to `modulus`.
+    pub const fn ct_eq_mod(&self, rhs: &Self, modulus: &Self) -> CtChoice {
+        self.abs_diff(rhs).const_rem(modulus).0.ct_is_zero()
     }
 
     /// Returns the Ordering between `self` and `rhs` in variable time.
@@ -118,6 +229,21 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             i -= 1;
         }
     }
+
+    /// Returns `true` if `self == rhs`, in variable time.
+    ///
+    /// Returns early at the first differing limb, so it should only be used on public values.
+    /// Note that `==` is constant-time (see the [`PartialEq`] impl).
+    pub const fn eq_vartime(&self, rhs: &Self) -> bool {
+        let mut i = 0;
+        while i < LIMBS {
+            if !self.limbs[i].eq_vartime(&rhs.limbs[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
 }
 
 impl<const LIMBS: usize> ConstantTimeEq for Uint<LIMBS> {
@@ -143,14 +269,12 @@ impl<const LIMBS: usize> ConstantTimeLess for Uint<LIMBS> {
 
 impl<const LIMBS: usize> Eq for Uint<LIMBS> {}
 
+/// Orders [`Uint`]s by their numeric value in constant time using [`Uint::ct_cmp`].
+///
+/// Use [`Uint::cmp_vartime`] for a faster, variable-time comparison of public values.
 impl<const LIMBS: usize> Ord for Uint<LIMBS> {
     fn cmp(&self, other: &Self) -> Ordering {
-        let c = Self::ct_cmp(self, other);
-        match c {
-            -1 => Ordering::Less,
-            0 => Ordering::Equal,
-            _ => Ordering::Greater,
-        }
+        Self::ct_cmp(self, other)
     }
 }
 
@@ -160,6 +284,10 @@ impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
     }
 }
 
+/// Compares [`Uint`]s in constant time using [`ConstantTimeEq`], always inspecting every limb, so
+/// `==` is safe to use on secret values.
+///
+/// Use [`Uint::eq_vartime`] for a faster, variable-time comparison of public values.
 impl<const LIMBS: usize> PartialEq for Uint<LIMBS> {
     fn eq(&self, other: &Self) -> bool {
         self.ct_eq(other).into()
@@ -168,7 +296,7 @@ impl<const LIMBS: usize> PartialEq for Uint<LIMBS> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Integer, Zero, U128};
+    use crate::{CtChoice, Integer, Zero, U128, U256};
     use core::cmp::Ordering;
     use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
 
@@ -179,6 +307,37 @@ mod tests {
         assert!(!bool::from(U128::MAX.is_zero()));
     }
 
+    #[test]
+    fn ct_is_zero() {
+        assert!(U128::ZERO.ct_is_zero().is_true_vartime());
+        assert!(!U128::ONE.ct_is_zero().is_true_vartime());
+        assert!(!(U128::ONE << (U128::BITS - 1))
+            .ct_is_zero()
+            .is_true_vartime());
+        assert!(!U128::MAX.ct_is_zero().is_true_vartime());
+    }
+
+    #[test]
+    fn ct_is_nonzero() {
+        assert!(!U128::ZERO.ct_is_nonzero().is_true_vartime());
+        assert!(U128::ONE.ct_is_nonzero().is_true_vartime());
+        assert!((U128::ONE << (U128::BITS - 1))
+            .ct_is_nonzero()
+            .is_true_vartime());
+        assert!(U128::MAX.ct_is_nonzero().is_true_vartime());
+    }
+
+    #[test]
+    fn is_one() {
+        assert!(U128::ONE.is_one().is_true_vartime());
+        assert!(!U128::ZERO.is_one().is_true_vartime());
+        assert!(!((U128::ONE << (U128::BITS - 1)) | U128::ONE)
+            .is_one()
+            .is_true_vartime());
+        assert!(!U128::from(3u8).is_one().is_true_vartime());
+        assert!(!U128::MAX.is_one().is_true_vartime());
+    }
+
     #[test]
     fn is_odd() {
         assert!(!bool::from(U128::ZERO.is_odd()));
@@ -186,6 +345,63 @@ mod tests {
         assert!(bool::from(U128::MAX.is_odd()));
     }
 
+    #[test]
+    fn is_even() {
+        assert!(bool::from(U128::ZERO.is_even()));
+        assert!(!bool::from(U128::ONE.is_even()));
+        assert!(bool::from(U128::from(2u8).is_even()));
+        assert!(!bool::from(U128::MAX.is_even()));
+    }
+
+    #[test]
+    fn ct_is_odd() {
+        let is_odd: CtChoice = U256::ONE.ct_is_odd();
+        assert!(is_odd.is_true_vartime());
+
+        let is_even: CtChoice = U256::from(2u8).ct_is_even();
+        assert!(is_even.is_true_vartime());
+    }
+
+    #[test]
+    fn ct_swap_in_place() {
+        let a = U256::from(1u8);
+        let b = U256::MAX;
+
+        let (mut x, mut y) = (a, b);
+        U256::ct_swap_in_place(&mut x, &mut y, CtChoice::FALSE);
+        assert_eq!((x, y), (a, b));
+
+        U256::ct_swap_in_place(&mut x, &mut y, CtChoice::TRUE);
+        assert_eq!((x, y), (b, a));
+
+        U256::ct_swap_in_place(&mut x, &mut y, CtChoice::TRUE);
+        assert_eq!((x, y), (a, b));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn ct_swap_in_place_random() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U256::random(&mut rng);
+            let b = U256::random(&mut rng);
+
+            let (mut x, mut y) = (a, b);
+            U256::ct_swap_in_place(&mut x, &mut y, CtChoice::FALSE);
+            assert_eq!((x, y), (a, b));
+
+            U256::ct_swap_in_place(&mut x, &mut y, CtChoice::TRUE);
+            assert_eq!((x, y), (b, a));
+            U256::ct_swap_in_place(&mut x, &mut y, CtChoice::TRUE);
+            assert_eq!((x, y), (a, b));
+        }
+    }
+
     #[test]
     fn ct_eq() {
         let a = U128::ZERO;
@@ -197,6 +413,30 @@ mod tests {
         assert!(bool::from(b.ct_eq(&b)));
     }
 
+    #[test]
+    fn eq() {
+        let n =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        let n_2 = n.shr_vartime(1);
+
+        assert_eq!(n_2.shl_vartime(1), n.wrapping_sub(&U256::ONE));
+        assert_eq!(n_2.wrapping_add(&n_2), n.wrapping_sub(&U256::ONE));
+        assert_ne!(n_2.shl_vartime(1), n);
+        assert_ne!(n_2, n);
+    }
+
+    #[test]
+    fn eq_vartime() {
+        let values = [U128::ZERO, U128::ONE, U128::ONE.shl_vartime(127), U128::MAX];
+
+        for a in &values {
+            for b in &values {
+                assert_eq!(a.eq_vartime(b), a == b);
+                assert_eq!(a.eq_vartime(b), bool::from(a.ct_eq(b)));
+            }
+        }
+    }
+
     #[test]
     fn ct_gt() {
         let a = U128::ZERO;
@@ -254,6 +494,194 @@ mod tests {
         assert_eq!(c.cmp(&b), Ordering::Greater);
     }
 
+    #[test]
+    fn ct_cmp() {
+        let a = U128::ZERO;
+        let b = U128::ONE;
+        let c = U128::MAX;
+
+        assert_eq!(U128::ct_cmp(&a, &b), Ordering::Less);
+        assert_eq!(U128::ct_cmp(&a, &c), Ordering::Less);
+        assert_eq!(U128::ct_cmp(&b, &c), Ordering::Less);
+
+        assert_eq!(U128::ct_cmp(&a, &a), Ordering::Equal);
+        assert_eq!(U128::ct_cmp(&b, &b), Ordering::Equal);
+        assert_eq!(U128::ct_cmp(&c, &c), Ordering::Equal);
+
+        assert_eq!(U128::ct_cmp(&b, &a), Ordering::Greater);
+        assert_eq!(U128::ct_cmp(&c, &a), Ordering::Greater);
+        assert_eq!(U128::ct_cmp(&c, &b), Ordering::Greater);
+    }
+
+    #[test]
+    fn cmp_limb() {
+        use crate::{Limb, Uint, Word, U64};
+
+        fn check<const LIMBS: usize>(n: Uint<LIMBS>, rhs: Word) {
+            let expected = Uint::<LIMBS>::from_word(rhs);
+            assert_eq!(n.cmp_limb(Limb(rhs)), n.cmp(&expected), "{n} <=> {rhs}");
+            assert_eq!(
+                n.ct_eq_limb(Limb(rhs)).is_true_vartime(),
+                n == expected,
+                "{n} == {rhs}"
+            );
+            assert_eq!(
+                n.ct_lt_limb(Limb(rhs)).is_true_vartime(),
+                n < expected,
+                "{n} < {rhs}"
+            );
+        }
+
+        for rhs in [0, 1, 255, 256, Word::MAX - 1, Word::MAX] {
+            for n in [0, 1, 255, 256, 257, Word::MAX - 1, Word::MAX] {
+                check(U64::from_word(n), rhs);
+                check(U128::from_word(n), rhs);
+            }
+
+            // Values which only differ in the high limbs
+            check(U128::MAX, rhs);
+            check(U256::ONE.shl_vartime(Limb::BITS), rhs);
+            check(U256::from_word(rhs).with_bit(U256::BITS - 1), rhs);
+        }
+
+        assert!(U256::from_u8(255).ct_lt_limb(Limb(256)).is_true_vartime());
+        assert!(!U256::from_u16(256).ct_lt_limb(Limb(256)).is_true_vartime());
+        assert!(U256::ONE.ct_eq_limb(Limb::ONE).is_true_vartime());
+    }
+
+    #[test]
+    fn cmp_numeric_order() {
+        let n =
+            U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        let n_2 =
+            U256::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0");
+
+        assert!(U256::from(2u8) > U256::from(1u8));
+        assert!((U256::ONE << 200) < U256::MAX >> 1);
+        assert!((U256::ONE << 255) > U256::MAX >> 1);
+        assert!(n > n_2);
+        assert_eq!(U256::ct_cmp(&n, &n_2), Ordering::Greater);
+
+        // The least significant limb of `n_2` is larger than that of `n`
+        assert!(n_2.as_limbs()[0] > n.as_limbs()[0]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn cmp_matches_u128() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let a = (u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64());
+            // Share the upper half sometimes so that lower limbs decide the ordering
+            let b = if rng.next_u32() & 1 == 0 {
+                (a & !u128::from(u64::MAX)) | u128::from(rng.next_u64())
+            } else {
+                (u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64())
+            };
+
+            let (x, y) = (U128::from_u128(a), U128::from_u128(b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            assert_eq!(U128::ct_cmp(&x, &y), a.cmp(&b));
+            assert_eq!(x.cmp_vartime(&y), a.cmp(&b));
+        }
+    }
+
+    #[test]
+    fn ct_min_max() {
+        for (a, b) in [
+            (U128::ZERO, U128::ONE),
+            (U128::ONE, U128::MAX),
+            (U128::ZERO, U128::MAX),
+        ] {
+            assert_eq!(a.ct_min(&b), a);
+            assert_eq!(b.ct_min(&a), a);
+            assert_eq!(a.ct_max(&b), b);
+            assert_eq!(b.ct_max(&a), b);
+            assert_eq!(a.min(b), a);
+            assert_eq!(a.max(b), b);
+
+            assert_eq!(a.ct_min(&a), a);
+            assert_eq!(a.ct_max(&a), a);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn ct_min_max_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let (x, y) = (U128::random(&mut rng), U128::random(&mut rng));
+            let (a, b) = (u128::from(x), u128::from(y));
+
+            assert_eq!(u128::from(x.ct_min(&y)), a.min(b));
+            assert_eq!(u128::from(x.ct_max(&y)), a.max(b));
+            assert_eq!(x.ct_min(&x), x);
+            assert_eq!(x.ct_max(&x), x);
+        }
+    }
+
+    #[test]
+    fn ct_eq_mod() {
+        let modulus = U128::from_u64(0xffff_ffff_ffff_ffc5);
+        let x = U128::from_u64(0x1234_5678_9abc_def0);
+        let y = x.wrapping_add(&modulus.shl_vartime(40));
+
+        assert!(x.ct_eq_mod(&y, &modulus).is_true_vartime());
+        assert!(y.ct_eq_mod(&x, &modulus).is_true_vartime());
+        assert!(x.ct_eq_mod(&x, &modulus).is_true_vartime());
+        assert!(U128::ZERO.ct_eq_mod(&modulus, &modulus).is_true_vartime());
+        assert!(!x
+            .ct_eq_mod(&y.wrapping_add(&U128::ONE), &modulus)
+            .is_true_vartime());
+        assert!(!U128::ONE.ct_eq_mod(&U128::MAX, &modulus).is_true_vartime());
+
+        // Reduced operands are only congruent if equal
+        let z = modulus.wrapping_sub(&U128::ONE);
+        assert!(!x.ct_eq_mod(&z, &modulus).is_true_vartime());
+        assert!(z.ct_eq_mod(&z, &modulus).is_true_vartime());
+
+        // Everything is congruent modulo one, and only equal values modulo zero
+        assert!(x.ct_eq_mod(&U128::MAX, &U128::ONE).is_true_vartime());
+        assert!(x.ct_eq_mod(&x, &U128::ZERO).is_true_vartime());
+        assert!(!x.ct_eq_mod(&y, &U128::ZERO).is_true_vartime());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn ct_eq_mod_random() {
+        use crate::{NonZero, Random, RandomMod};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            // Leave room so that `x + modulus` doesn't overflow
+            let modulus = U256::random(&mut rng).shr_vartime(1).bitor(&U256::ONE);
+            let modulus_nz = NonZero::new(modulus).unwrap();
+            let x = U256::random_mod(&mut rng, &modulus_nz);
+            let y = U256::random_mod(&mut rng, &modulus_nz);
+
+            assert!(x
+                .ct_eq_mod(&x.wrapping_add(&modulus), &modulus)
+                .is_true_vartime());
+            assert!(x
+                .wrapping_add(&modulus)
+                .ct_eq_mod(&x, &modulus)
+                .is_true_vartime());
+            assert_eq!(x.ct_eq_mod(&y, &modulus).is_true_vartime(), x == y);
+        }
+    }
+
     #[test]
     fn cmp_vartime() {
         let a = U128::ZERO;
@@ -1,7 +1,7 @@
 //! [`Uint`] division operations.
 
 use super::div_limb::{div_rem_limb_with_reciprocal, Reciprocal};
-use crate::{CheckedDiv, CtChoice, Limb, NonZero, Uint, Word, Wrapping};
+use crate::{CheckedDiv, CtChoice, Limb, NonZero, Uint, Word, Wrapping, Zero};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use subtle::CtOption;
 
@@ -34,6 +34,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Computes `self` / `rhs`, returns the quotient (q) and remainder (r).
+    ///
+    /// This is much cheaper than [`Uint::div_rem`] for single-limb divisors, e.g. when converting
+    /// to decimal. A zero divisor is ruled out by the type of `rhs`.
     #[inline(always)]
     pub fn div_rem_limb(&self, rhs: NonZero<Limb>) -> (Self, Limb) {
         // Guaranteed to succeed since `rhs` is nonzero.
@@ -177,29 +180,19 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (lower, is_some)
     }
 
-    /// Computes `self` % 2^k. Faster than reduce since its a power of 2.
-    /// Limited to 2^16-1 since Uint doesn't support higher.
+    /// Computes `self % 2^k`, i.e. clears all bits at positions `>= k`. Faster than reduce since
+    /// it's a power of 2.
+    ///
+    /// Returns zero if `k == 0`, and `self` if `k >= Self::BITS`.
+    ///
+    /// This is constant-time with respect to both `self` and `k`.
     pub const fn rem2k(&self, k: u32) -> Self {
-        let highest = (LIMBS - 1) as u32;
-        let index = k / Limb::BITS;
-        let le = CtChoice::from_u32_le(index, highest);
-        let limb_num = le.select_u32(highest, index) as usize;
-
-        let base = k % Limb::BITS;
-        let mask = (1 << base) - 1;
         let mut out = *self;
-
-        let outmask = Limb(out.limbs[limb_num].0 & mask);
-
-        out.limbs[limb_num] = Limb::ct_select(out.limbs[limb_num], outmask, le);
-
-        // TODO: this is not constant-time.
-        let mut i = limb_num + 1;
+        let mut i = 0;
         while i < LIMBS {
-            out.limbs[i] = Limb::ZERO;
+            out.limbs[i].0 &= rem2k_limb_mask(i, k);
             i += 1;
         }
-
         out
     }
 
@@ -271,6 +264,47 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     pub fn checked_rem(&self, rhs: &Self) -> CtOption<Self> {
         NonZero::new(*rhs).map(|rhs| self.rem(&rhs))
     }
+
+    /// Computes `self / rhs`, rounding the quotient up rather than down.
+    ///
+    /// This never overflows, as a nonzero remainder implies `rhs >= 2`.
+    ///
+    /// Panics if `rhs == 0`.
+    pub const fn div_ceil(&self, rhs: &Self) -> Self {
+        let (q, r, c) = self.const_div_rem(rhs);
+        assert!(c.is_true_vartime(), "divide by zero");
+        q.wrapping_add(&Self::from_word(r.ct_is_nonzero().if_true_word(1)))
+    }
+
+    /// Computes the smallest multiple of `rhs` which is greater than or equal to `self`, i.e.
+    /// `self` rounded up to a multiple of `rhs`.
+    ///
+    /// If that multiple exceeds [`Uint::MAX`], it wraps around modulo `2^BITS`; use
+    /// [`Uint::checked_next_multiple_of`] to detect this.
+    ///
+    /// Panics if `rhs == 0`.
+    pub const fn next_multiple_of(&self, rhs: &Self) -> Self {
+        let (r, c) = self.const_rem(rhs);
+        assert!(c.is_true_vartime(), "modulo zero");
+        self.wrapping_add(&Self::next_multiple_padding(&r, rhs))
+    }
+
+    /// Computes the smallest multiple of `rhs` which is greater than or equal to `self`,
+    /// returning a [`CtOption`] which `is_some` only if `rhs != 0` and the multiple doesn't exceed
+    /// [`Uint::MAX`].
+    pub fn checked_next_multiple_of(&self, rhs: &Self) -> CtOption<Self> {
+        NonZero::new(*rhs).and_then(|rhs| {
+            let padding = Self::next_multiple_padding(&self.rem(&rhs), &rhs);
+            let (result, carry) = self.adc(&padding, Limb::ZERO);
+            CtOption::new(result, carry.is_zero())
+        })
+    }
+
+    /// Returns the amount to add to a value with remainder `r` modulo `rhs` to round it up to the
+    /// next multiple of `rhs`, i.e. `rhs - r` if `r` is nonzero and zero otherwise.
+    const fn next_multiple_padding(r: &Self, rhs: &Self) -> Self {
+        Self::ct_select(&Self::ZERO, &rhs.wrapping_sub(r), r.ct_is_nonzero())
+    }
 }
 
 //
@@ -652,9 +686,20 @@ impl<const LIMBS: usize> RemAssign<&NonZero<Uint<LIMBS>>> for Wrapping<Uint<LIMB
     }
 }
 
+/// Mask for the limb at index `i` when clearing all bits at positions `>= k`.
+#[inline(always)]
+pub(crate) const fn rem2k_limb_mask(i: usize, k: u32) -> Word {
+    let index = k / Limb::BITS;
+    let partial_mask: Word = (1 << (k % Limb::BITS)) - 1;
+
+    let is_full = CtChoice::from_u32_lt(i as u32, index);
+    let is_partial = CtChoice::from_u32_eq(i as u32, index);
+    is_full.select_word(is_partial.if_true_word(partial_mask), Word::MAX)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Limb, NonZero, Uint, Word, U256};
+    use crate::{Limb, NonZero, Uint, Word, U128, U256};
 
     #[cfg(feature = "rand")]
     use {
@@ -691,6 +736,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn div_rem_limb_matches_u128() {
+        for n in [
+            0,
+            1,
+            9,
+            10,
+            0xdead_beef,
+            u128::from(u64::MAX) + 1,
+            u128::MAX,
+        ] {
+            for d in [1, 2, 3, 10, 0xffff_fffb, Word::MAX] {
+                let (q, r) = U128::from_u128(n).div_rem_limb(NonZero::new(Limb(d)).unwrap());
+                assert_eq!(q, U128::from_u128(n / d as u128), "{n} / {d}");
+                assert_eq!(r, Limb((n % d as u128) as Word), "{n} % {d}");
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn div_rem_limb_decimal() {
+        use alloc::string::{String, ToString};
+
+        let ten = NonZero::new(Limb(10)).unwrap();
+        let mut n =
+            U256::from_be_hex("ffffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+        let expected = n.to_string();
+
+        let mut digits = String::new();
+        while n != U256::ZERO {
+            let (q, r) = n.div_rem_limb(ten);
+            digits.insert(0, char::from(b'0' + r.0 as u8));
+            n = q;
+        }
+        assert_eq!(digits, expected);
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn div() {
@@ -801,6 +884,81 @@ mod tests {
         assert_eq!(r, a);
     }
 
+    /// Reference implementation of `next_multiple_of` for `u128`, returning `None` on overflow.
+    fn next_multiple_of_u128(a: u128, b: u128) -> Option<u128> {
+        match a % b {
+            0 => Some(a),
+            r => a.checked_add(b - r),
+        }
+    }
+
+    #[test]
+    fn div_ceil_next_multiple_of() {
+        let values = [
+            0,
+            1,
+            2,
+            3,
+            10,
+            1000,
+            u64::MAX as u128,
+            u128::MAX / 3,
+            u128::MAX - 1,
+            u128::MAX,
+        ];
+
+        for a in values {
+            for b in values.into_iter().filter(|&b| b != 0) {
+                let (x, y) = (U128::from_u128(a), U128::from_u128(b));
+                let expected_ceil = a / b + u128::from(a % b != 0);
+                assert_eq!(x.div_ceil(&y), U128::from_u128(expected_ceil), "{a} / {b}");
+
+                let expected = next_multiple_of_u128(a, b);
+                let checked = Option::<U128>::from(x.checked_next_multiple_of(&y));
+                assert_eq!(checked, expected.map(U128::from_u128), "{a}, {b}");
+
+                let wrapped = a.wrapping_add((b - a % b) % b);
+                assert_eq!(x.next_multiple_of(&y), U128::from_u128(wrapped), "{a}, {b}");
+            }
+
+            let checked = U128::from_u128(a).checked_next_multiple_of(&U128::ZERO);
+            assert!(bool::from(checked.is_none()));
+        }
+
+        assert_eq!(
+            U128::from_u16(1000).div_ceil(&U128::from_u8(7)),
+            U128::from_u8(143)
+        );
+        assert_eq!(
+            U128::from_u16(1000).next_multiple_of(&U128::from_u8(7)),
+            U128::from_u16(1001)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn next_multiple_of_zero() {
+        U128::ONE.next_multiple_of(&U128::ZERO);
+    }
+
+    #[test]
+    fn rem2k_boundaries() {
+        let x =
+            U256::from_be_hex("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685");
+
+        for k in [0, 1, 31, 32, 33, 63, 64, 65, 128, 255, 256, 257, 1000] {
+            let mask = if k >= U256::BITS {
+                U256::MAX
+            } else {
+                U256::ONE.shl(k).wrapping_sub(&U256::ONE)
+            };
+            assert_eq!(x.rem2k(k), x & mask, "k = {k}");
+            assert_eq!(U256::MAX.rem2k(k), mask, "k = {k}");
+        }
+        assert_eq!(x.rem2k(0), U256::ZERO);
+        assert_eq!(x.rem2k(U256::BITS), x);
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn rem2krand() {
@@ -7,7 +7,62 @@ mod der;
 mod rlp;
 
 use super::Uint;
-use crate::{Encoding, Limb, Word};
+use crate::{Encoding, Limb, NonZero, Word};
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Decoding errors for [`Uint`] and `BoxedUint`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// Input size is too small to fit in the given precision.
+    InputSize,
+
+    /// The deserialized number is larger than the given precision.
+    Precision,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InputSize => write!(f, "input size is too small to fit in the given precision"),
+            Self::Precision => write!(
+                f,
+                "the deserialized number is larger than the given precision"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Errors which can occur when parsing a [`Uint`] or `BoxedUint` from a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The string contains no digits.
+    Empty,
+
+    /// The string contains a character which isn't a valid digit.
+    InvalidDigit,
+
+    /// The parsed number is larger than the given precision.
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse integer from empty string"),
+            Self::InvalidDigit => write!(f, "invalid digit found in string"),
+            Self::Overflow => write!(f, "number too large to fit in the given precision"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Create a new [`Uint`] from the provided big endian bytes.
@@ -34,6 +89,38 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::new(res)
     }
 
+    /// Create a new [`Uint`] from the provided big endian bytes, which may be of any length,
+    /// reduced modulo `modulus`.
+    ///
+    /// To obtain an output with negligible bias (e.g. when deriving a scalar from a hash output),
+    /// `bytes` should be at least 64 bits longer than `modulus`.
+    ///
+    /// This is variable-time only with respect to `modulus` and the length of `bytes`.
+    pub const fn from_be_bytes_mod_order(bytes: &[u8], modulus: &NonZero<Self>) -> Self {
+        let modulus = &modulus.0;
+
+        // The input is consumed in `Self::BYTES`-sized blocks starting from the most significant
+        // one, which is the only block that may be shorter than `Self::BYTES`. Each step computes
+        // `acc = (acc * 2^BITS + block) mod modulus` using a wide reduction.
+        let lead = bytes.len() % Self::BYTES;
+        let mut acc = Self::ZERO;
+        let mut pos = 0;
+
+        if lead != 0 {
+            let block = be_block(bytes, 0, lead);
+            acc = Self::const_rem_wide((block, Self::ZERO), modulus).0;
+            pos = lead;
+        }
+
+        while pos < bytes.len() {
+            let block = be_block(bytes, pos, Self::BYTES);
+            acc = Self::const_rem_wide((block, acc), modulus).0;
+            pos += Self::BYTES;
+        }
+
+        acc
+    }
+
     /// Create a new [`Uint`] from the provided big endian hex string.
     pub const fn from_be_hex(hex: &str) -> Self {
         let bytes = hex.as_bytes();
@@ -66,6 +153,49 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::new(res)
     }
 
+    /// Parse a [`Uint`] from a big endian hex string, e.g. user input.
+    ///
+    /// Unlike [`Uint::from_be_hex`], surrounding whitespace and an optional `0x` or `0X` prefix
+    /// are ignored, and the string may have any number of digits: it is treated as if it were
+    /// zero-padded to the full width.
+    ///
+    /// Returns [`ParseError::InvalidDigit`] if the string contains non-hex characters, and
+    /// [`ParseError::Overflow`] if the value doesn't fit in [`Uint::BITS`].
+    pub fn from_str_hex(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s)
+            .as_bytes();
+
+        if digits.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let nibbles_per_limb = Limb::BYTES * 2;
+        let mut res = [Limb::ZERO; LIMBS];
+
+        for (i, &digit) in digits.iter().rev().enumerate() {
+            let nibble = decode_nibble(digit);
+            if nibble >> 8 != 0 {
+                return Err(ParseError::InvalidDigit);
+            }
+
+            // Leading zeros beyond the width are fine
+            if i >= nibbles_per_limb * LIMBS {
+                if nibble != 0 {
+                    return Err(ParseError::Overflow);
+                }
+                continue;
+            }
+
+            res[i / nibbles_per_limb].0 |= Word::from(nibble) << ((i % nibbles_per_limb) * 4);
+        }
+
+        Ok(Uint::new(res))
+    }
+
     /// Create a new [`Uint`] from the provided little endian bytes.
     pub const fn from_le_slice(bytes: &[u8]) -> Self {
         assert!(
@@ -90,6 +220,56 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::new(res)
     }
 
+    /// Create a new [`Uint`] from the provided big endian bytes, which may be shorter than
+    /// [`Uint::BYTES`], in which case they are zero-extended.
+    ///
+    /// Returns [`DecodeError::InputSize`] if `bytes` is longer than [`Uint::BYTES`].
+    pub const fn try_from_be_slice(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() > Self::BYTES {
+            return Err(DecodeError::InputSize);
+        }
+
+        Ok(be_block(bytes, 0, bytes.len()))
+    }
+
+    /// Create a new [`Uint`] from the provided little endian bytes, which may be shorter than
+    /// [`Uint::BYTES`], in which case they are zero-extended.
+    ///
+    /// Returns [`DecodeError::InputSize`] if `bytes` is longer than [`Uint::BYTES`].
+    pub const fn try_from_le_slice(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() > Self::BYTES {
+            return Err(DecodeError::InputSize);
+        }
+
+        let mut res = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+
+        while i < bytes.len() {
+            res[i / Limb::BYTES].0 |= (bytes[i] as Word) << ((i % Limb::BYTES) * 8);
+            i += 1;
+        }
+
+        Ok(Uint::new(res))
+    }
+
+    /// Create a new [`Uint`] from a minimal-length big endian encoding, as produced by
+    /// [`Uint::to_be_bytes_trimmed`].
+    ///
+    /// This accepts any input of up to [`Uint::BYTES`] bytes, including ones with leading zeros,
+    /// in the same way as [`Uint::try_from_be_slice`].
+    pub const fn from_be_bytes_trimmed(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::try_from_be_slice(bytes)
+    }
+
+    /// Serialize this [`Uint`] as big-endian with leading zero bytes stripped, i.e. as
+    /// `ceil(self.bits() / 8)` bytes. Zero is encoded as an empty vector.
+    ///
+    /// The length of the output depends on the value of `self`, so this isn't constant-time.
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes_trimmed(&self) -> Vec<u8> {
+        be_bytes_trimmed(&self.limbs)
+    }
+
     /// Create a new [`Uint`] from the provided little endian hex string.
     pub const fn from_le_hex(hex: &str) -> Self {
         let bytes = hex.as_bytes();
@@ -156,6 +336,21 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 }
 
+/// Decode `len <= Uint::<LIMBS>::BYTES` big endian bytes of `bytes` starting at `pos` into a
+/// zero-extended [`Uint`].
+const fn be_block<const LIMBS: usize>(bytes: &[u8], pos: usize, len: usize) -> Uint<LIMBS> {
+    let mut res = [Limb::ZERO; LIMBS];
+    let mut i = 0;
+
+    while i < len {
+        let byte = bytes[pos + len - i - 1] as Word;
+        res[i / Limb::BYTES].0 |= byte << ((i % Limb::BYTES) * 8);
+        i += 1;
+    }
+
+    Uint::new(res)
+}
+
 /// Decode a single nibble of upper or lower hex
 #[inline(always)]
 const fn decode_nibble(src: u8) -> u16 {
@@ -179,7 +374,7 @@ const fn decode_nibble(src: u8) -> u16 {
 /// Second element of the tuple is non-zero if the `bytes` values are not in the valid range
 /// (0-9, a-z, A-Z).
 #[inline(always)]
-const fn decode_hex_byte(bytes: [u8; 2]) -> (u8, u16) {
+pub(crate) const fn decode_hex_byte(bytes: [u8; 2]) -> (u8, u16) {
     let hi = decode_nibble(bytes[0]);
     let lo = decode_nibble(bytes[1]);
     let byte = (hi << 4) | lo;
@@ -188,19 +383,33 @@ const fn decode_hex_byte(bytes: [u8; 2]) -> (u8, u16) {
     (result, err)
 }
 
+/// Serialize the integer represented by the little endian `limbs` as big-endian with leading zero
+/// bytes stripped.
+#[cfg(feature = "alloc")]
+pub(crate) fn be_bytes_trimmed(limbs: &[Limb]) -> Vec<u8> {
+    limbs
+        .iter()
+        .rev()
+        .flat_map(|limb| limb.0.to_be_bytes())
+        .skip_while(|&byte| byte == 0)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Limb;
     use hex_literal::hex;
 
+    use crate::{DecodeError, Encoding, NonZero, ParseError, U128, U256, U512};
+
     #[cfg(feature = "alloc")]
-    use {crate::U128, alloc::format};
+    use alloc::format;
 
     #[cfg(target_pointer_width = "32")]
     use crate::U64 as UintEx;
 
     #[cfg(target_pointer_width = "64")]
-    use crate::U128 as UintEx;
+    use U128 as UintEx;
 
     #[test]
     #[cfg(target_pointer_width = "32")]
@@ -289,4 +498,197 @@ mod tests {
         let n = U128::from_be_hex(hex);
         assert_eq!(hex, format!("{:x}", n));
     }
+
+    #[test]
+    fn from_be_bytes_mod_order() {
+        let n = NonZero::new(U256::from_be_hex(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        ))
+        .unwrap();
+
+        // Inputs shorter than the modulus are unchanged
+        let bytes = hex!("0011223344556677");
+        assert_eq!(
+            U256::from_be_bytes_mod_order(&bytes, &n),
+            U256::from_u64(0x0011223344556677)
+        );
+        assert_eq!(U256::from_be_bytes_mod_order(&[], &n), U256::ZERO);
+
+        // Inputs of exactly `BYTES` are reduced once
+        let bytes = [0xff; U256::BYTES];
+        assert_eq!(U256::from_be_bytes_mod_order(&bytes, &n), U256::MAX.rem(&n));
+
+        // Long inputs, including ones which aren't a multiple of `BYTES`
+        let n_wide = NonZero::new(n.resize::<{ U512::LIMBS }>()).unwrap();
+        for len in [33, 40, 48, 63, 64] {
+            let mut bytes = [0u8; U512::BYTES];
+            for (i, b) in bytes.iter_mut().enumerate() {
+                *b = (i as u8).wrapping_mul(0x9d) ^ 0xa5;
+            }
+            let bytes = &bytes[U512::BYTES - len..];
+
+            let mut padded = [0u8; U512::BYTES];
+            padded[U512::BYTES - len..].copy_from_slice(bytes);
+            let expected = U512::from_be_slice(&padded).rem(&n_wide).resize();
+
+            let actual = U256::from_be_bytes_mod_order(bytes, &n);
+            assert_eq!(actual, expected);
+            assert!(actual < *n);
+        }
+    }
+
+    #[test]
+    fn try_from_be_slice() {
+        let n =
+            U256::from_be_hex("00000000000000000000000000000000000000000000000000112233445566ff");
+        assert_eq!(U256::try_from_be_slice(&hex!("112233445566ff")), Ok(n));
+        assert_eq!(U256::try_from_be_slice(&n.to_be_bytes()), Ok(n));
+        assert_eq!(U256::try_from_be_slice(&[]), Ok(U256::ZERO));
+        assert_eq!(
+            U256::try_from_be_slice(&[0u8; 33]),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn be_bytes_trimmed() {
+        assert!(U256::ZERO.to_be_bytes_trimmed().is_empty());
+        assert_eq!(U256::ONE.to_be_bytes_trimmed(), [1]);
+        assert_eq!(U256::from_u64(0x100).to_be_bytes_trimmed(), [1, 0]);
+        assert_eq!(U256::MAX.to_be_bytes_trimmed(), [0xff; 32]);
+
+        for n in [
+            U256::ZERO,
+            U256::ONE,
+            U256::from_u64(0x80),
+            U256::from_u64(0x1122_3344_5566_77ff),
+            U256::from_be_hex("00000000000000000000000000000000ffffffffffffffffffffffffffffffff"),
+            U256::MAX.shr_vartime(1),
+            U256::MAX,
+        ] {
+            let bytes = n.to_be_bytes_trimmed();
+            assert_eq!(bytes.len(), (n.bits() as usize + 7) / 8);
+            assert_eq!(U256::from_be_bytes_trimmed(&bytes), Ok(n));
+        }
+
+        assert_eq!(U256::from_be_bytes_trimmed(&[0, 0, 1]), Ok(U256::ONE));
+        assert_eq!(
+            U256::from_be_bytes_trimmed(&[1; 33]),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    fn try_from_le_slice() {
+        let n =
+            U256::from_be_hex("00000000000000000000000000000000000000000000000000112233445566ff");
+        assert_eq!(U256::try_from_le_slice(&hex!("ff66554433221100")), Ok(n));
+        assert_eq!(U256::try_from_le_slice(&n.to_le_bytes()), Ok(n));
+        assert_eq!(U256::try_from_le_slice(&[]), Ok(U256::ZERO));
+        assert_eq!(
+            U256::try_from_le_slice(&[0u8; 33]),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    fn le_byte_layout() {
+        let n = U256::from_u64(0x0102_0304_0506_0708);
+        let bytes = n.to_le_bytes();
+        assert_eq!(&bytes[..8], &[8, 7, 6, 5, 4, 3, 2, 1]);
+        assert!(bytes[8..].iter().all(|&b| b == 0));
+
+        let mut be = n.to_be_bytes();
+        be.reverse();
+        assert_eq!(be, bytes);
+    }
+
+    /// Checks the [`Encoding`] methods agree with the inherent slice constructors, using only the
+    /// trait bound as width-generic code would.
+    fn check_encoding<T>(
+        bytes: T::Repr,
+        from_be_slice: fn(&[u8]) -> T,
+        from_le_slice: fn(&[u8]) -> T,
+    ) where
+        T: Encoding + core::fmt::Debug + PartialEq,
+    {
+        let be = T::from_be_bytes(bytes);
+        assert_eq!(be, from_be_slice(bytes.as_ref()));
+        assert_eq!(be.to_be_bytes().as_ref(), bytes.as_ref());
+
+        let le = T::from_le_bytes(bytes);
+        assert_eq!(le, from_le_slice(bytes.as_ref()));
+        assert_eq!(le.to_le_bytes().as_ref(), bytes.as_ref());
+
+        let mut reversed = be.to_le_bytes();
+        reversed.as_mut().reverse();
+        assert_eq!(reversed.as_ref(), bytes.as_ref());
+    }
+
+    #[test]
+    fn encoding_trait_matches_inherent() {
+        check_encoding::<U128>(
+            hex!("00112233445566778899aabbccddeeff"),
+            U128::from_be_slice,
+            U128::from_le_slice,
+        );
+        check_encoding::<U256>(
+            hex!("00112233445566778899aabbccddeeffffeeddccbbaa99887766554433221100"),
+            U256::from_be_slice,
+            U256::from_le_slice,
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn le_round_trip() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let n = U256::random(&mut rng);
+            assert_eq!(U256::from_le_bytes(n.to_le_bytes()), n);
+            assert_eq!(U256::try_from_le_slice(&n.to_le_bytes()), Ok(n));
+            assert_eq!(U256::try_from_be_slice(&n.to_be_bytes()), Ok(n));
+        }
+    }
+
+    #[test]
+    fn from_str_hex() {
+        assert_eq!(U256::from_str_hex(" 0xFF  "), Ok(U256::from_u8(0xff)));
+        assert_eq!(U256::from_str_hex("ff"), Ok(U256::from_u8(0xff)));
+        assert_eq!(U256::from_str_hex("0XfFf"), Ok(U256::from_u16(0xfff)));
+        assert_eq!(U256::from_str_hex("\t0\n"), Ok(U256::ZERO));
+
+        let hex = "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff";
+        assert_eq!(U256::from_str_hex(hex), Ok(U256::from_be_hex(hex)));
+        assert_eq!(
+            U256::from_str_hex(&hex[1..]),
+            Ok(U256::from_be_hex(hex)),
+            "odd length"
+        );
+    }
+
+    #[test]
+    fn from_str_hex_errors() {
+        assert_eq!(U256::from_str_hex(""), Err(ParseError::Empty));
+        assert_eq!(U256::from_str_hex(" 0x "), Err(ParseError::Empty));
+        assert_eq!(U256::from_str_hex("0xfg"), Err(ParseError::InvalidDigit));
+        assert_eq!(U256::from_str_hex("0x 1"), Err(ParseError::InvalidDigit));
+        assert_eq!(U256::from_str_hex("+1"), Err(ParseError::InvalidDigit));
+
+        // 65 digits: too long unless the excess digit is a leading zero
+        assert_eq!(
+            U256::from_str_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+            Ok(U256::MAX)
+        );
+        assert_eq!(
+            U256::from_str_hex("1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+            Err(ParseError::Overflow)
+        );
+    }
 }
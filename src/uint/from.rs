@@ -1,6 +1,21 @@
 //! `From`-like conversions for [`Uint`].
 
-use crate::{ConcatMixed, Limb, Uint, WideWord, Word, U128, U64};
+use crate::{ConcatMixed, CtChoice, Limb, Uint, WideWord, Word, U128, U64};
+use core::fmt;
+
+/// Error returned when converting an integer into a primitive type which is too small to hold its
+/// value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TryFromUintError;
+
+impl fmt::Display for TryFromUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value is too large to fit in the target type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromUintError {}
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Create a [`Uint`] from a `u8` (const-friendly)
@@ -98,6 +113,19 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         limbs[1].0 = (n >> Limb::BITS) as Word;
         Self { limbs }
     }
+
+    /// Create a [`Uint`] which is [`Uint::ONE`] if `b` is `true` and [`Uint::ZERO`] otherwise.
+    pub const fn from_bool(b: bool) -> Self {
+        Self::from_word(b as Word)
+    }
+
+    /// Create a [`Uint`] which is [`Uint::ONE`] if `choice` is truthy and [`Uint::ZERO`]
+    /// otherwise, in constant time.
+    ///
+    /// This is useful e.g. for accumulating carries without selecting between two values.
+    pub const fn from_ct_choice(choice: CtChoice) -> Self {
+        Self::from_word(choice.if_true_word(1))
+    }
 }
 
 impl<const LIMBS: usize> From<u8> for Uint<LIMBS> {
@@ -166,6 +194,39 @@ impl From<U128> for u128 {
     }
 }
 
+impl<const LIMBS: usize> TryFrom<&Uint<LIMBS>> for u64 {
+    type Error = TryFromUintError;
+
+    fn try_from(n: &Uint<LIMBS>) -> Result<u64, TryFromUintError> {
+        limbs_to_u128(&n.limbs).and_then(|n| u64::try_from(n).map_err(|_| TryFromUintError))
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&Uint<LIMBS>> for u128 {
+    type Error = TryFromUintError;
+
+    fn try_from(n: &Uint<LIMBS>) -> Result<u128, TryFromUintError> {
+        limbs_to_u128(&n.limbs)
+    }
+}
+
+/// Convert little endian `limbs` into a `u128`, returning an error if any bits above the lowest
+/// 128 are set.
+///
+/// Variable-time with respect to the value of `limbs`.
+pub(crate) fn limbs_to_u128(limbs: &[Limb]) -> Result<u128, TryFromUintError> {
+    let (lo, hi) = limbs.split_at(limbs.len().min(16 / Limb::BYTES));
+
+    if hi.iter().any(|limb| limb.0 != 0) {
+        return Err(TryFromUintError);
+    }
+
+    Ok(lo
+        .iter()
+        .rev()
+        .fold(0, |acc, limb| (acc << Limb::BITS) | limb.0 as u128))
+}
+
 impl<const LIMBS: usize> From<[Word; LIMBS]> for Uint<LIMBS> {
     fn from(arr: [Word; LIMBS]) -> Self {
         Self::from_words(arr)
@@ -228,7 +289,8 @@ impl<const LIMBS: usize, const LIMBS2: usize> From<&Uint<LIMBS>> for Uint<LIMBS2
 
 #[cfg(test)]
 mod tests {
-    use crate::{Limb, Word, U128};
+    use super::TryFromUintError;
+    use crate::{CtChoice, Limb, Word, U128, U256, U64};
 
     #[cfg(target_pointer_width = "32")]
     use crate::U64 as UintEx;
@@ -261,6 +323,22 @@ mod tests {
         assert_eq!(u128::from(n), 42u128);
     }
 
+    #[test]
+    fn from_bool() {
+        assert_eq!(UintEx::from_bool(true), UintEx::ONE);
+        assert_eq!(UintEx::from_bool(false), UintEx::ZERO);
+    }
+
+    #[test]
+    fn from_ct_choice() {
+        assert_eq!(UintEx::from_ct_choice(CtChoice::TRUE), UintEx::ONE);
+        assert_eq!(UintEx::from_ct_choice(CtChoice::FALSE), UintEx::ZERO);
+        assert_eq!(
+            U256::from_ct_choice(U256::MAX.ct_is_odd()).wrapping_add(&U256::MAX),
+            U256::ZERO
+        );
+    }
+
     #[test]
     fn array_round_trip() {
         let arr1 = [1, 2];
@@ -268,4 +346,36 @@ mod tests {
         let arr2: [Word; 2] = n.into();
         assert_eq!(arr1, arr2);
     }
+
+    #[test]
+    fn try_into_u64() {
+        assert_eq!(u64::try_from(&U64::from_u64(42)), Ok(42));
+        assert_eq!(u64::try_from(&U256::from_u64(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(
+            u64::try_from(&U256::from_u128(u64::MAX as u128 + 1)),
+            Err(TryFromUintError)
+        );
+        assert_eq!(u64::try_from(&U256::MAX), Err(TryFromUintError));
+    }
+
+    #[test]
+    fn try_into_u128() {
+        assert_eq!(
+            u128::try_from(&U64::from_u64(u64::MAX)),
+            Ok(u64::MAX as u128)
+        );
+        assert_eq!(u128::try_from(&U256::from_u128(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(
+            u128::try_from(&U256::ONE.shl_vartime(128)),
+            Err(TryFromUintError)
+        );
+        assert_eq!(u128::try_from(&U256::MAX), Err(TryFromUintError));
+    }
+
+    #[test]
+    fn u128_round_trip() {
+        for n in [0, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX] {
+            assert_eq!(u128::try_from(&U256::from_u128(n)), Ok(n));
+        }
+    }
 }
@@ -0,0 +1,254 @@
+//! Support for computing the greatest common divisor of two [`Uint`]s.
+
+use crate::{CtChoice, NonZero, Uint};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes the greatest common divisor of `self` and `rhs` using the binary GCD algorithm.
+    ///
+    /// If either input is zero the result is the other one, so `gcd(0, 0) == 0`.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`.
+    pub const fn gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (*self, *rhs);
+        let mut k = 0;
+
+        // Each step removes at least one bit from `a` or `b` until one of them is zero
+        let mut i = 0;
+        while i < 2 * Self::BITS {
+            let not_done = a.ct_is_nonzero().and(b.ct_is_nonzero());
+            let (a_odd, b_odd) = (a.ct_is_odd(), b.ct_is_odd());
+
+            // If both are odd, ensure `a >= b` so that `(a - b) / 2` doesn't underflow
+            let both_odd = a_odd.and(b_odd);
+            (a, b) = Self::ct_swap(&a, &b, both_odd.and(Self::ct_lt(&a, &b)));
+
+            // Common factors of two are removed from both and restored at the end
+            k += not_done.and(a_odd.not()).and(b_odd.not()).if_true_u32(1);
+
+            a = Self::ct_select(&a, &a.wrapping_sub(&b).shr1(), both_odd);
+            a = Self::ct_select(&a, &a.shr1(), not_done.and(a_odd.not()));
+            b = Self::ct_select(&b, &b.shr1(), not_done.and(b_odd.not()));
+            i += 1;
+        }
+
+        // One of `a` and `b` is now zero
+        a.bitor(&b).shl(k)
+    }
+
+    /// Computes the least common multiple of `self` and `rhs`, along with the truthy value if it
+    /// fits in `Self`, or the falsy value if it overflows, in which case the result is
+    /// undefined.
+    ///
+    /// Computed as `(self / gcd) * rhs`, so that the multiplication only overflows if the result
+    /// does. If either input is zero the result is zero.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`.
+    pub const fn lcm(&self, rhs: &Self) -> (Self, CtChoice) {
+        let gcd = self.gcd(rhs);
+
+        // The gcd is only zero if both inputs are, in which case dividing by one gives zero too
+        let divisor = Self::ct_select(&gcd, &Self::ONE, gcd.ct_is_zero());
+        let (quotient, _, _) = self.const_div_rem(&divisor);
+
+        let (lo, hi) = quotient.mul_wide(rhs);
+        (lo, hi.ct_is_zero())
+    }
+
+    /// Computes the greatest common divisor of `self` and `rhs` using the extended Euclidean
+    /// algorithm, along with Bézout coefficients `x` and `y` such that `self * x + rhs * y = gcd`.
+    ///
+    /// Since there is no signed integer type, the coefficients are returned as magnitudes along
+    /// with flags which are `true` if the corresponding coefficient is negative, i.e. the result
+    /// is `(gcd, |x|, x < 0, |y|, y < 0)`. Zero coefficients are never flagged as negative.
+    ///
+    /// For nonzero inputs the coefficients are minimal, i.e. `|x| <= rhs / gcd` and
+    /// `|y| <= self / gcd`, so they always fit in `Self`. If `self` and `rhs` are coprime, `x` is
+    /// the inverse of `self` modulo `rhs` (after negating it modulo `rhs` if flagged as negative).
+    ///
+    /// If both inputs are zero the gcd is zero and the coefficients are `(1, 0)`.
+    ///
+    /// This function is variable-time with respect to both `self` and `rhs`.
+    pub fn xgcd_vartime(&self, rhs: &Self) -> (Self, Self, bool, Self, bool) {
+        let (mut r0, mut r1) = (*self, *rhs);
+        let (mut s0, mut s1) = (Self::ONE, Self::ZERO);
+        let (mut t0, mut t1) = (Self::ZERO, Self::ONE);
+
+        // The signs of the coefficients alternate with every step, so only their magnitudes are
+        // tracked: `x` is negative after an odd number of steps, `y` after an even number.
+        let mut odd_step = false;
+
+        while let Some(divisor) = Option::<NonZero<Self>>::from(NonZero::new(r1)) {
+            let (q, r) = r0.div_rem_vartime(&divisor);
+            (r0, r1) = (r1, r);
+
+            // Magnitudes never exceed `rhs` and `self` respectively, so these can't overflow
+            (s0, s1) = (s1, s0.wrapping_add(&q.wrapping_mul(&s1)));
+            (t0, t1) = (t1, t0.wrapping_add(&q.wrapping_mul(&t1)));
+            odd_step = !odd_step;
+        }
+
+        let x_is_negative = odd_step && s0 != Self::ZERO;
+        let y_is_negative = !odd_step && t0 != Self::ZERO;
+        (r0, s0, x_is_negative, t0, y_is_negative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{U128, U256, U512};
+
+    /// Checks `a * x + b * y == gcd` and that `gcd` matches a plain Euclidean reference.
+    fn check(a: U256, b: U256) {
+        let (gcd, x, x_neg, y, y_neg) = a.xgcd_vartime(&b);
+
+        let (mut r0, mut r1) = (a, b);
+        while r1 != U256::ZERO {
+            (r0, r1) = (r1, r0.wrapping_rem(&r1));
+        }
+        assert_eq!(gcd, r0, "gcd({a}, {b})");
+
+        let ax = U256::concat_wide(a.mul_wide(&x));
+        let by = U256::concat_wide(b.mul_wide(&y));
+        let lhs = match (x_neg, y_neg) {
+            (false, false) => ax.wrapping_add(&by),
+            (true, false) => by.wrapping_sub(&ax),
+            (false, true) => ax.wrapping_sub(&by),
+            (true, true) => panic!("both coefficients negative"),
+        };
+        assert_eq!(lhs, gcd.resize::<{ U512::LIMBS }>(), "bezout({a}, {b})");
+    }
+
+    /// Euclidean reference implementation.
+    fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    /// Checks `gcd` and `lcm` against `u128` references, along with `gcd * lcm == a * b`.
+    fn check_lcm(a: u128, b: u128) {
+        let (x, y) = (U128::from_u128(a), U128::from_u128(b));
+        let gcd = gcd_u128(a, b);
+        assert_eq!(x.gcd(&y), U128::from_u128(gcd), "gcd({a}, {b})");
+        assert_eq!(y.gcd(&x), U128::from_u128(gcd), "gcd({b}, {a})");
+
+        let expected = match gcd {
+            0 => Some(0),
+            _ => (a / gcd).checked_mul(b),
+        };
+        let (lcm, is_some) = x.lcm(&y);
+        assert_eq!(
+            is_some.is_true_vartime(),
+            expected.is_some(),
+            "lcm({a}, {b})"
+        );
+
+        if let Some(expected) = expected {
+            assert_eq!(lcm, U128::from_u128(expected), "lcm({a}, {b})");
+            assert_eq!(y.lcm(&x).0, lcm, "lcm({b}, {a})");
+            assert_eq!(
+                U128::concat_wide(x.gcd(&y).mul_wide(&lcm)),
+                U128::concat_wide(x.mul_wide(&y)),
+                "gcd({a}, {b}) * lcm({a}, {b})"
+            );
+        }
+    }
+
+    #[test]
+    fn gcd_lcm_edge_cases() {
+        check_lcm(0, 0);
+        check_lcm(0, 12345);
+        check_lcm(12345, 0);
+        check_lcm(1, 1);
+        check_lcm(4, 6);
+        check_lcm(21, 6);
+        check_lcm(1, u128::MAX);
+        check_lcm(u128::MAX, u128::MAX);
+        check_lcm(1 << 127, 1 << 64);
+        check_lcm(3 << 100, 12 << 90);
+
+        // Overflows
+        check_lcm(u128::MAX, u128::MAX - 1);
+        check_lcm(1 << 127, 3);
+        check_lcm(0xffff_ffff_ffff_ffc5, 0xffff_ffff_ffff_ff59 << 1);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn gcd_lcm_random() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..64 {
+            // Share a random factor to get nontrivial gcds
+            let g = u128::from(rng.next_u32());
+            let a = u128::from(rng.next_u64() >> (rng.next_u32() % 64));
+            let b = u128::from(rng.next_u64() >> (rng.next_u32() % 64));
+            check_lcm(a * g, b * g);
+            check_lcm(a, b);
+        }
+    }
+
+    #[test]
+    fn xgcd_edge_cases() {
+        check(U256::ZERO, U256::ZERO);
+        check(U256::ZERO, U256::from_u8(5));
+        check(U256::from_u8(5), U256::ZERO);
+        check(U256::ONE, U256::MAX);
+        check(U256::MAX, U256::MAX);
+        check(U256::MAX, U256::ONE);
+
+        assert_eq!(
+            U256::ZERO.xgcd_vartime(&U256::ZERO),
+            (U256::ZERO, U256::ONE, false, U256::ZERO, false)
+        );
+    }
+
+    #[test]
+    fn xgcd_divides() {
+        let b = U256::from_u64(0x1234_5678_9abc_def1);
+        let a = b.wrapping_mul(&U256::from_u64(0xdead_beef));
+        check(a, b);
+        check(b, a);
+
+        let (gcd, x, x_neg, y, y_neg) = a.xgcd_vartime(&b);
+        assert_eq!(gcd, b);
+        assert_eq!((x, x_neg, y, y_neg), (U256::ZERO, false, U256::ONE, false));
+    }
+
+    #[test]
+    fn xgcd_coprime_inverse() {
+        let a =
+            U256::from_be_hex("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685");
+        let m =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        check(a, m);
+
+        let (gcd, x, x_neg, _, _) = a.xgcd_vartime(&m);
+        assert_eq!(gcd, U256::ONE);
+        let inv = if x_neg { m.wrapping_sub(&x) } else { x };
+        assert_eq!(inv, a.inv_odd_mod(&m).0);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn xgcd_random() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..64 {
+            // Share a random factor to get nontrivial gcds
+            let g = U256::random(&mut rng).shr_vartime(U256::BITS - 32);
+            let a = U256::random(&mut rng).shr_vartime(32 + rng.next_u32() % 192);
+            let b = U256::random(&mut rng).shr_vartime(32 + rng.next_u32() % 192);
+            check(a.wrapping_mul(&g), b.wrapping_mul(&g));
+            check(U256::random(&mut rng), U256::random(&mut rng));
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::Uint;
-use crate::CtChoice;
+use crate::{CtChoice, Limb, Word};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes 1/`self` mod `2^k`.
@@ -25,7 +25,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             // b_{i+1} = (b_i - a * X_i) / 2
             b = Self::ct_select(&b, &b.wrapping_sub(self), x_i_choice).shr1();
             // Store the X_i bit in the result (x = x | (1 << X_i))
-            x = x.bitor(&Uint::from_word(x_i).shl_vartime(i));
+            x = x.set_bit(i, x_i_choice);
 
             i += 1;
         }
@@ -34,32 +34,32 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Computes 1/`self` mod `2^k`.
+    /// This method is constant-time w.r.t. both `self` and `k`.
     ///
-    /// Conditions: `self` < 2^k and `self` must be odd
+    /// Conditions: `self` must be odd, otherwise it has no inverse modulo `2^k` and the result is
+    /// unspecified. Bits of the result at positions `k` and above are zero.
     pub const fn inv_mod2k(&self, k: u32) -> Self {
-        // This is the same algorithm as in `inv_mod2k_vartime()`,
-        // but made constant-time w.r.t `k` as well.
+        // Newton's iteration `x' = x * (2 - self * x)`, which doubles the number of correct
+        // low bits of `x` at each step. For odd `self`, `self * self = 1 mod 8`, so starting from
+        // `x = self` gives an inverse which is correct modulo `2^3`.
+        let two = Self::from_u8(2);
+        let mut x = *self;
+        let mut correct_bits = 3;
+
+        while correct_bits < Self::BITS {
+            x = x.wrapping_mul(&two.wrapping_sub(&self.wrapping_mul(&x)));
+            correct_bits *= 2;
+        }
 
-        let mut x = Self::ZERO; // keeps `x` during iterations
-        let mut b = Self::ONE; // keeps `b_i` during iterations
+        // Reduce modulo `2^k` by masking each limb in constant time
         let mut i = 0;
-
-        while i < Self::BITS {
-            // Only iterations for i = 0..k need to change `x`,
-            // the rest are dummy ones performed for the sake of constant-timeness.
-            let within_range = CtChoice::from_u32_lt(i, k);
-
-            // X_i = b_i mod 2
-            let x_i = b.limbs[0].0 & 1;
-            let x_i_choice = CtChoice::from_word_lsb(x_i);
-            // b_{i+1} = (b_i - a * X_i) / 2
-            b = Self::ct_select(&b, &b.wrapping_sub(self), x_i_choice).shr1();
-
-            // Store the X_i bit in the result (x = x | (1 << X_i))
-            // Don't change the result in dummy iterations.
-            let x_i_choice = x_i_choice.and(within_range);
-            x = x.set_bit(i, x_i_choice);
-
+        while i < LIMBS {
+            let lo = i as u32 * Limb::BITS;
+            let is_full = CtChoice::from_u32_le(lo + Limb::BITS, k);
+            let is_empty = CtChoice::from_u32_le(k, lo);
+            let partial = (1 << (k.wrapping_sub(lo) % Limb::BITS)) - 1;
+            let mask = is_full.select_word(is_empty.select_word(partial, 0), Word::MAX);
+            x.limbs[i].0 &= mask;
             i += 1;
         }
 
@@ -111,7 +111,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             // Set `b += self` if `swap` is true.
             b = Uint::ct_select(&b, &b.wrapping_add(&new_a), swap);
             // Negate `self` if `swap` is true.
-            a = new_a.conditional_wrapping_neg(swap);
+            a = new_a.conditional_negate(swap);
 
             let (new_u, new_v) = Uint::ct_swap(&u, &v, swap);
             let (new_u, cy) = new_u.conditional_wrapping_sub(&new_v, self_odd);
@@ -146,6 +146,10 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes the multiplicative inverse of `self` mod `modulus`.
     /// Returns `(inverse, CtChoice::TRUE)` if an inverse exists,
     /// otherwise `(undefined, CtChoice::FALSE)`.
+    ///
+    /// The modulus may be even: it is split into an odd part and a power of two, the inverses
+    /// modulo each are computed with [`Uint::inv_odd_mod`] and [`Uint::inv_mod2k`], and then
+    /// recombined using the CRT. For known odd moduli [`Uint::inv_odd_mod`] is cheaper.
     pub const fn inv_mod(&self, modulus: &Self) -> (Self, CtChoice) {
         // Decompose `modulus = s * 2^k` where `s` is odd
         let k = modulus.trailing_zeros();
@@ -174,6 +178,17 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         let result = a.wrapping_add(&s.wrapping_mul(&t));
         (result, a_is_some.and(b_is_some))
     }
+
+    /// Computes the coefficients for two-prime CRT recombination with the odd moduli `p` and `q`,
+    /// i.e. `(q^-1 mod p, p^-1 mod q)`.
+    ///
+    /// The third element of the tuple is the truthy value if `p` and `q` are coprime (and so both
+    /// coefficients exist), otherwise it is a falsy value and the coefficients are undefined.
+    pub const fn crt_coeffs(p: &Self, q: &Self) -> (Self, Self, CtChoice) {
+        let (q_inv, q_inv_is_some) = q.inv_odd_mod(p);
+        let (p_inv, p_inv_is_some) = p.inv_odd_mod(q);
+        (q_inv, p_inv, q_inv_is_some.and(p_inv_is_some))
+    }
 }
 
 #[cfg(test)]
@@ -197,6 +212,37 @@ mod tests {
         assert_eq!(e, a);
     }
 
+    #[test]
+    fn inv_mod2k_partial() {
+        let v =
+            U256::from_be_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+
+        for k in [0, 1, 2, 3, 63, 64, 65, 127, 128, 200, 255, 256] {
+            let a = v.inv_mod2k(k);
+            assert!(a.bits_vartime() <= k, "k = {}", k);
+            assert_eq!(a, v.rem2k(k).inv_mod2k_vartime(k), "k = {}", k);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn inv_mod2k_random() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..16 {
+            let v = U256::random(&mut rng).bitor(&U256::ONE);
+
+            for k in [1, 8, 64, 100, 128, 255, 256] {
+                let a = v.inv_mod2k(k);
+                assert_eq!(v.wrapping_mul(&a).rem2k(k), U256::ONE.rem2k(k), "k = {}", k);
+            }
+        }
+    }
+
     #[test]
     fn test_invert_odd() {
         let a = U1024::from_be_hex(concat![
@@ -254,6 +300,36 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn inv_mod_matches_brute_force() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let m = u64::from(rng.next_u32() % 4094) + 2;
+
+            // Both the odd path and the `2^k` splitting path
+            for m in [m & !1, m | 1] {
+                let a = rng.next_u64() % m;
+                let expected = (0..m).find(|x| a * x % m == 1);
+
+                let (inv, is_some) = U64::from_u64(a).inv_mod(&U64::from_u64(m));
+                assert_eq!(
+                    is_some.is_true_vartime(),
+                    expected.is_some(),
+                    "{a}^-1 mod {m}"
+                );
+
+                if let Some(expected) = expected {
+                    assert_eq!(inv, U64::from_u64(expected), "{a}^-1 mod {m}");
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_invert_bounded() {
         let a = U1024::from_be_hex(concat![
@@ -292,6 +368,34 @@ mod tests {
         assert_eq!(U64::from(9u64), res);
     }
 
+    #[test]
+    fn test_crt_coeffs() {
+        let p =
+            U256::from_be_hex("E4A4D7B98C1F2E17B8B0E3C3E4B1C2D3A5F6E7D8C9BAAB9C8D7E6F5A4B3C2D1F");
+        let q =
+            U256::from_be_hex("0000000000000000000000000000000000000000000000000000000000010001");
+
+        let (q_inv, p_inv, is_some) = U256::crt_coeffs(&p, &q);
+        assert!(is_some.is_true_vartime());
+        assert_eq!(q.mul_mod(&q_inv, &p), U256::ONE);
+        assert_eq!(p.mul_mod(&p_inv, &q), U256::ONE);
+
+        // The coefficients are symmetric in `p` and `q`
+        let (p_inv2, q_inv2, is_some) = U256::crt_coeffs(&q, &p);
+        assert!(is_some.is_true_vartime());
+        assert_eq!(p_inv, p_inv2);
+        assert_eq!(q_inv, q_inv2);
+    }
+
+    #[test]
+    fn test_crt_coeffs_not_coprime() {
+        let p = U64::from(15u64);
+        let q = U64::from(21u64);
+
+        let (_, _, is_some) = U64::crt_coeffs(&p, &q);
+        assert!(!is_some.is_true_vartime());
+    }
+
     #[test]
     fn test_no_inverse_small() {
         let a = U64::from(14u64);
@@ -84,6 +84,12 @@ macro_rules! impl_uint_concat_split_even {
             pub const fn concat(&self, lo: &Uint<{ <$name>::LIMBS / 2 }>) -> $name {
                 $crate::uint::concat::concat_mixed(lo, self)
             }
+
+            /// Concatenate a `(lo, hi)` pair such as the ones returned by [`Uint::mul_wide`]
+            /// into a single value.
+            pub const fn concat_wide(lower_upper: (Self, Self)) -> $name {
+                $crate::uint::concat::concat_mixed(&lower_upper.0, &lower_upper.1)
+            }
         }
 
         impl $crate::traits::SplitMixed<Uint<{ <$name>::LIMBS / 2 }>, Uint<{ <$name>::LIMBS / 2 }>> for $name
@@ -104,6 +110,13 @@ macro_rules! impl_uint_concat_split_even {
             pub const fn split(&self) -> (Uint<{ <$name>::LIMBS / 2 }>, Uint<{ <$name>::LIMBS / 2 }>) {
                 $crate::uint::split::split_mixed(self)
             }
+
+            /// Split this number in half, returning its low and high components
+            /// respectively, i.e. the `(lo, hi)` convention used by [`Uint::mul_wide`].
+            pub const fn split_wide(&self) -> (Uint<{ <$name>::LIMBS / 2 }>, Uint<{ <$name>::LIMBS / 2 }>) {
+                let (hi, lo) = $crate::uint::split::split_mixed(self);
+                (lo, hi)
+            }
         }
     };
     ($($name:ident,)+) => {
@@ -1,12 +1,19 @@
 //! [`Uint`] multiplication operations.
 
-// TODO(tarcieri): use Karatsuba for better performance
+#[cfg(feature = "alloc")]
+mod karatsuba;
+
+#[cfg(feature = "alloc")]
+pub(crate) use karatsuba::KARATSUBA_MIN_LIMBS;
 
 use crate::{
-    Checked, CheckedMul, Concat, ConcatMixed, Limb, Uint, WideWord, WideningMul, Word, Wrapping,
-    Zero,
+    Checked, CheckedMul, Concat, ConcatMixed, CtChoice, Limb, Uint, WideWord, WideningMul, Word,
+    Wrapping, Zero,
+};
+use core::{
+    iter::Product,
+    ops::{Mul, MulAssign},
 };
-use core::ops::{Mul, MulAssign};
 use subtle::CtOption;
 
 /// Impl the core schoolbook multiplication algorithm.
@@ -50,6 +57,98 @@ macro_rules! impl_schoolbook_multiplication {
     }};
 }
 
+/// Impl the schoolbook squaring algorithm, which only computes half of the multiplication grid
+/// since `a_i * a_j == a_j * a_i`.
+///
+/// Like [`impl_schoolbook_multiplication`], this is a macro so it can be shared between the
+/// `const fn` implementation for `Uint` and the one for `BoxedUint`. `$lo` and `$hi` must both be
+/// zero-initialized and have the same length as `$limbs`.
+macro_rules! impl_schoolbook_squaring {
+    ($limbs:expr, $lo:expr, $hi:expr) => {{
+        // Translated from https://github.com/ucbrise/jedi-pairing/blob/c4bf151/include/core/bigint.hpp#L410
+        //
+        // Permission to relicense the resulting translation as Apache 2.0 + MIT was given
+        // by the original author Sam Kumar: https://github.com/RustCrypto/crypto-bigint/pull/133#discussion_r1056870411
+        let len = $limbs.len();
+
+        // Schoolbook multiplication, but only considering half of the multiplication grid
+        let mut i = 1;
+        while i < len {
+            let mut j = 0;
+            let mut carry = Limb::ZERO;
+
+            while j < i {
+                let k = i + j;
+
+                if k >= len {
+                    let (n, c) = $hi[k - len].mac($limbs[i], $limbs[j], carry);
+                    $hi[k - len] = n;
+                    carry = c;
+                } else {
+                    let (n, c) = $lo[k].mac($limbs[i], $limbs[j], carry);
+                    $lo[k] = n;
+                    carry = c;
+                }
+
+                j += 1;
+            }
+
+            if (2 * i) < len {
+                $lo[2 * i] = carry;
+            } else {
+                $hi[2 * i - len] = carry;
+            }
+
+            i += 1;
+        }
+
+        // Double the current result, this accounts for the other half of the multiplication grid.
+        // The top bit is always zero at this point, so the shift never overflows.
+        let mut carry = 0;
+        let mut i = 0;
+        while i < len {
+            let n = ($lo[i].0 << 1) | carry;
+            carry = $lo[i].0 >> Limb::HI_BIT;
+            $lo[i] = Limb(n);
+            i += 1;
+        }
+        let mut i = 0;
+        while i < len {
+            let n = ($hi[i].0 << 1) | carry;
+            carry = $hi[i].0 >> Limb::HI_BIT;
+            $hi[i] = Limb(n);
+            i += 1;
+        }
+
+        // Handle the diagonal of the multiplication grid, which finishes the multiplication grid.
+        let mut carry = Limb::ZERO;
+        let mut i = 0;
+        while i < len {
+            if (i * 2) < len {
+                let (n, c) = $lo[i * 2].mac($limbs[i], $limbs[i], carry);
+                $lo[i * 2] = n;
+                carry = c;
+            } else {
+                let (n, c) = $hi[i * 2 - len].mac($limbs[i], $limbs[i], carry);
+                $hi[i * 2 - len] = n;
+                carry = c;
+            }
+
+            if (i * 2 + 1) < len {
+                let n = $lo[i * 2 + 1].0 as WideWord + carry.0 as WideWord;
+                $lo[i * 2 + 1] = Limb(n as Word);
+                carry = Limb((n >> Word::BITS) as Word);
+            } else {
+                let n = $hi[i * 2 + 1 - len].0 as WideWord + carry.0 as WideWord;
+                $hi[i * 2 + 1 - len] = Limb(n as Word);
+                carry = Limb((n >> Word::BITS) as Word);
+            }
+
+            i += 1;
+        }
+    }};
+}
+
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Multiply `self` by `rhs`, returning a concatenated "wide" result.
     pub fn mul<const HLIMBS: usize>(
@@ -65,7 +164,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Compute "wide" multiplication, with a product twice the size of the input.
     ///
-    /// Returns a tuple containing the `(lo, hi)` components of the product.
+    /// Returns a tuple containing the `(lo, hi)` components of the product, where `hi` is zero if
+    /// the product fits in `Self::BITS`. See [`WideningMul`] for a product concatenated into a
+    /// single wider [`Uint`].
     pub const fn mul_wide<const HLIMBS: usize>(&self, rhs: &Uint<HLIMBS>) -> (Self, Uint<HLIMBS>) {
         let mut lo = Self::ZERO;
         let mut hi = Uint::<HLIMBS>::ZERO;
@@ -79,91 +180,109 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Self::ct_select(&res, &Self::MAX, overflow.ct_is_nonzero())
     }
 
+    /// Perform wrapping multiplication, returning the wrapped result along with the truthy value
+    /// if an overflow has occurred, i.e. if the high half of the product is nonzero, like
+    /// [`u64::overflowing_mul`].
+    pub const fn overflowing_mul<const HLIMBS: usize>(
+        &self,
+        rhs: &Uint<HLIMBS>,
+    ) -> (Self, CtChoice) {
+        let (res, overflow) = self.mul_wide(rhs);
+        (res, overflow.ct_is_nonzero())
+    }
+
     /// Perform wrapping multiplication, discarding overflow.
+    ///
+    /// Only the partial products which contribute to the low `Self::BITS` bits of the product are
+    /// computed, so this is cheaper than taking the low half of [`Uint::mul_wide`].
     pub const fn wrapping_mul<const H: usize>(&self, rhs: &Uint<H>) -> Self {
-        self.mul_wide(rhs).0
-    }
+        let mut lo = Self::ZERO;
 
-    /// Square self, returning a concatenated "wide" result.
-    pub fn square(&self) -> <Self as Concat>::Output
-    where
-        Self: Concat,
-    {
-        let (lo, hi) = self.square_wide();
-        hi.concat(&lo)
+        let mut i = 0;
+        while i < LIMBS {
+            let mut j = 0;
+            let mut carry = Limb::ZERO;
+
+            // Partial products at `i + j >= LIMBS` only affect the discarded high half
+            while j < H && i + j < LIMBS {
+                let (n, c) = lo.limbs[i + j].mac(self.limbs[i], rhs.limbs[j], carry);
+                lo.limbs[i + j] = n;
+                carry = c;
+                j += 1;
+            }
+
+            if i + j < LIMBS {
+                lo.limbs[i + j] = carry;
+            }
+            i += 1;
+        }
+
+        lo
     }
 
-    /// Square self, returning a "wide" result in two parts as (lo, hi).
-    pub const fn square_wide(&self) -> (Self, Self) {
-        // Translated from https://github.com/ucbrise/jedi-pairing/blob/c4bf151/include/core/bigint.hpp#L410
-        //
-        // Permission to relicense the resulting translation as Apache 2.0 + MIT was given
-        // by the original author Sam Kumar: https://github.com/RustCrypto/crypto-bigint/pull/133#discussion_r1056870411
+    /// Square self, discarding overflow.
+    ///
+    /// Like [`Uint::square_wide`] this exploits the symmetry of the multiplication grid, and like
+    /// [`Uint::wrapping_mul`] it only computes the partial products which contribute to the low
+    /// `Self::BITS` bits, so this is cheaper than both `self.wrapping_mul(self)` and taking the
+    /// low half of [`Uint::square_wide`].
+    pub const fn wrapping_square(&self) -> Self {
         let mut lo = Self::ZERO;
-        let mut hi = Self::ZERO;
 
-        // Schoolbook multiplication, but only considering half of the multiplication grid
+        // Off-diagonal partial products `a_i * a_j` with `j < i`, which appear twice in the grid
         let mut i = 1;
         while i < LIMBS {
             let mut j = 0;
             let mut carry = Limb::ZERO;
 
-            while j < i {
-                let k = i + j;
-
-                if k >= LIMBS {
-                    let (n, c) = hi.limbs[k - LIMBS].mac(self.limbs[i], self.limbs[j], carry);
-                    hi.limbs[k - LIMBS] = n;
-                    carry = c;
-                } else {
-                    let (n, c) = lo.limbs[k].mac(self.limbs[i], self.limbs[j], carry);
-                    lo.limbs[k] = n;
-                    carry = c;
-                }
-
+            while j < i && i + j < LIMBS {
+                let (n, c) = lo.limbs[i + j].mac(self.limbs[i], self.limbs[j], carry);
+                lo.limbs[i + j] = n;
+                carry = c;
                 j += 1;
             }
 
-            if (2 * i) < LIMBS {
-                lo.limbs[2 * i] = carry;
-            } else {
-                hi.limbs[2 * i - LIMBS] = carry;
+            if i + j < LIMBS {
+                lo.limbs[i + j] = carry;
             }
-
             i += 1;
         }
 
-        // Double the current result, this accounts for the other half of the multiplication grid.
-        // TODO: The top word is empty so we can also use a special purpose shl.
-        (lo, hi) = Self::shl_vartime_wide((lo, hi), 1);
+        let mut lo = lo.shl1();
 
-        // Handle the diagonal of the multiplication grid, which finishes the multiplication grid.
+        // Diagonal partial products `a_i * a_i`
         let mut carry = Limb::ZERO;
         let mut i = 0;
-        while i < LIMBS {
-            if (i * 2) < LIMBS {
-                let (n, c) = lo.limbs[i * 2].mac(self.limbs[i], self.limbs[i], carry);
-                lo.limbs[i * 2] = n;
+        while 2 * i < LIMBS {
+            let (n, c) = lo.limbs[2 * i].mac(self.limbs[i], self.limbs[i], carry);
+            lo.limbs[2 * i] = n;
+            carry = c;
+
+            if 2 * i + 1 < LIMBS {
+                let (n, c) = lo.limbs[2 * i + 1].adc(carry, Limb::ZERO);
+                lo.limbs[2 * i + 1] = n;
                 carry = c;
-            } else {
-                let (n, c) = hi.limbs[i * 2 - LIMBS].mac(self.limbs[i], self.limbs[i], carry);
-                hi.limbs[i * 2 - LIMBS] = n;
-                carry = c;
-            }
-
-            if (i * 2 + 1) < LIMBS {
-                let n = lo.limbs[i * 2 + 1].0 as WideWord + carry.0 as WideWord;
-                lo.limbs[i * 2 + 1] = Limb(n as Word);
-                carry = Limb((n >> Word::BITS) as Word);
-            } else {
-                let n = hi.limbs[i * 2 + 1 - LIMBS].0 as WideWord + carry.0 as WideWord;
-                hi.limbs[i * 2 + 1 - LIMBS] = Limb(n as Word);
-                carry = Limb((n >> Word::BITS) as Word);
             }
-
             i += 1;
         }
 
+        lo
+    }
+
+    /// Square self, returning a concatenated "wide" result.
+    pub fn square(&self) -> <Self as Concat>::Output
+    where
+        Self: Concat,
+    {
+        let (lo, hi) = self.square_wide();
+        hi.concat(&lo)
+    }
+
+    /// Square self, returning a "wide" result in two parts as (lo, hi).
+    pub const fn square_wide(&self) -> (Self, Self) {
+        let mut lo = Self::ZERO;
+        let mut hi = Self::ZERO;
+        impl_schoolbook_squaring!(&self.limbs, lo.limbs, hi.limbs);
         (lo, hi)
     }
 }
@@ -317,6 +436,8 @@ impl<const LIMBS: usize, const HLIMBS: usize> MulAssign<&Checked<Uint<HLIMBS>>>
     }
 }
 
+/// Multiplication operators on [`Uint`] compute the full-width product, like [`Uint::mul`]. Use
+/// [`Uint::wrapping_mul`] or [`Wrapping`] for a product which wraps around `Self::BITS`.
 impl<const LIMBS: usize, const HLIMBS: usize> Mul<Uint<HLIMBS>> for Uint<LIMBS>
 where
     Uint<HLIMBS>: ConcatMixed<Uint<LIMBS>>,
@@ -361,18 +482,105 @@ where
     }
 }
 
-/// Wrapper function used by `BoxedUint`
+/// Multiplies the values using [`Uint::wrapping_mul`], i.e. modulo `2^BITS`.
+///
+/// The product of an empty iterator is one. Use [`Checked`] to detect overflow.
+impl<const LIMBS: usize> Product for Uint<LIMBS> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc.wrapping_mul(&x))
+    }
+}
+
+/// Multiplies the values using [`Uint::wrapping_mul`], i.e. modulo `2^BITS`.
+///
+/// The product of an empty iterator is one. Use [`Checked`] to detect overflow.
+impl<'a, const LIMBS: usize> Product<&'a Uint<LIMBS>> for Uint<LIMBS> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc.wrapping_mul(x))
+    }
+}
+
+/// Wrapper function used by `BoxedUint`.
+///
+/// Uses Karatsuba multiplication for large operands of equal size, and falls back to schoolbook
+/// multiplication otherwise. `out` must be zero-initialized.
 #[cfg(feature = "alloc")]
 pub(crate) fn mul_limbs(lhs: &[Limb], rhs: &[Limb], out: &mut [Limb]) {
+    mul_limbs_with_threshold(lhs, rhs, out, KARATSUBA_MIN_LIMBS);
+}
+
+/// Like [`mul_limbs`], but only using Karatsuba multiplication for operands of at least
+/// `karatsuba_min_limbs` limbs rather than [`KARATSUBA_MIN_LIMBS`].
+#[cfg(feature = "alloc")]
+pub(crate) fn mul_limbs_with_threshold(
+    lhs: &[Limb],
+    rhs: &[Limb],
+    out: &mut [Limb],
+    karatsuba_min_limbs: usize,
+) {
+    debug_assert_eq!(lhs.len() + rhs.len(), out.len());
+
+    // Karatsuba multiplication splits the operands in half, so needs at least two limbs
+    let karatsuba_min_limbs = karatsuba_min_limbs.max(2);
+
+    if lhs.len() == rhs.len() && lhs.len() >= karatsuba_min_limbs {
+        karatsuba::karatsuba_mul_limbs(lhs, rhs, out, karatsuba_min_limbs);
+    } else {
+        schoolbook_mul_limbs(lhs, rhs, out);
+    }
+}
+
+/// Wrapper function used by `BoxedUint` for squaring.
+///
+/// Uses Karatsuba squaring for large operands, and falls back to schoolbook squaring otherwise.
+/// `out` must be zero-initialized and twice the length of `limbs`.
+#[cfg(feature = "alloc")]
+pub(crate) fn square_limbs(limbs: &[Limb], out: &mut [Limb]) {
+    debug_assert_eq!(limbs.len() * 2, out.len());
+
+    if limbs.len() >= KARATSUBA_MIN_LIMBS {
+        karatsuba::karatsuba_square_limbs(limbs, out);
+    } else {
+        schoolbook_square_limbs(limbs, out);
+    }
+}
+
+/// Schoolbook multiplication of limb slices. `out` must be zero-initialized.
+#[cfg(feature = "alloc")]
+pub(crate) fn schoolbook_mul_limbs(lhs: &[Limb], rhs: &[Limb], out: &mut [Limb]) {
     debug_assert_eq!(lhs.len() + rhs.len(), out.len());
     let (lo, hi) = out.split_at_mut(lhs.len());
     impl_schoolbook_multiplication!(lhs, rhs, lo, hi);
 }
 
+/// Schoolbook squaring of a limb slice. `out` must be zero-initialized and twice the length of
+/// `limbs`.
+#[cfg(feature = "alloc")]
+pub(crate) fn schoolbook_square_limbs(limbs: &[Limb], out: &mut [Limb]) {
+    debug_assert_eq!(limbs.len() * 2, out.len());
+    let (lo, hi) = out.split_at_mut(limbs.len());
+    impl_schoolbook_squaring!(limbs, lo, hi);
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{CheckedMul, Zero, U128, U192, U256, U64};
 
+    #[test]
+    fn product() {
+        let values = [U256::from_u8(2), U256::from_u8(3), U256::from_u8(7)];
+        assert_eq!(values.iter().product::<U256>(), U256::from_u8(42));
+        assert_eq!(values.into_iter().product::<U256>(), U256::from_u8(42));
+        assert_eq!(core::iter::empty::<U256>().product::<U256>(), U256::ONE);
+
+        // Overflow wraps around
+        let half = U256::ONE << (U256::BITS / 2);
+        assert_eq!(
+            [half, half, U256::from_u8(3)].iter().product::<U256>(),
+            U256::ZERO
+        );
+    }
+
     #[test]
     fn mul_wide_zero_and_one() {
         assert_eq!(U64::ZERO.mul_wide(&U64::ZERO), (U64::ZERO, U64::ZERO));
@@ -432,6 +640,22 @@ mod tests {
         assert!(bool::from(n.checked_mul(&n).is_none()));
     }
 
+    #[test]
+    fn overflowing_mul() {
+        let (res, overflow) = U64::MAX.overflowing_mul(&U64::from_u8(2));
+        assert_eq!(res, U64::MAX.wrapping_sub(&U64::ONE));
+        assert!(overflow.is_true_vartime());
+
+        let (res, overflow) = U64::from_u8(8).overflowing_mul(&U64::from_u8(8));
+        assert_eq!(res, U64::from_u8(64));
+        assert!(!overflow.is_true_vartime());
+
+        // Mixed widths
+        let (res, overflow) = U128::MAX.overflowing_mul(&U64::ONE);
+        assert_eq!(res, U128::MAX);
+        assert!(!overflow.is_true_vartime());
+    }
+
     #[test]
     fn saturating_mul_no_overflow() {
         let n = U64::from_u8(8);
@@ -460,4 +684,179 @@ mod tests {
         assert_eq!(lo, U256::ONE);
         assert_eq!(hi, U256::MAX.wrapping_sub(&U256::ONE));
     }
+
+    /// Full 256-bit product of two `u128`s as `(lo, hi)`.
+    fn u128_widening_mul(a: u128, b: u128) -> (u128, u128) {
+        let (a0, a1) = (a as u64 as u128, a >> 64);
+        let (b0, b1) = (b as u64 as u128, b >> 64);
+
+        let (mid, mid_carry) = (a0 * b1).overflowing_add(a1 * b0);
+        let (lo, lo_carry) = (a0 * b0).overflowing_add(mid << 64);
+        let hi = a1 * b1 + (mid >> 64) + ((mid_carry as u128) << 64) + lo_carry as u128;
+        (lo, hi)
+    }
+
+    #[test]
+    fn mul_wide_u128() {
+        let values = [
+            0,
+            1,
+            0xffff_ffff_ffff_ffff,
+            0x1_0000_0000_0000_0000,
+            0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+            0x8000_0000_0000_0000_0000_0000_0000_0001,
+            u128::MAX,
+        ];
+
+        for &a in &values {
+            for &b in &values {
+                let (lo, hi) = U128::from_u128(a).mul_wide(&U128::from_u128(b));
+                let (expected_lo, expected_hi) = u128_widening_mul(a, b);
+                assert_eq!(lo, U128::from_u128(expected_lo));
+                assert_eq!(hi, U128::from_u128(expected_hi));
+
+                if a.checked_mul(b).is_some() {
+                    assert!(bool::from(hi.is_zero()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrapping_mul_u128() {
+        let values = [
+            0,
+            1,
+            0xffff_ffff_ffff_ffff,
+            0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+            u128::MAX,
+        ];
+
+        for &a in &values {
+            for &b in &values {
+                assert_eq!(
+                    U128::from_u128(a).wrapping_mul(&U128::from_u128(b)),
+                    U128::from_u128(a.wrapping_mul(b))
+                );
+            }
+        }
+
+        assert_eq!(U128::MAX.wrapping_mul(&U128::MAX), U128::ONE);
+    }
+
+    #[test]
+    fn wrapping_square_u128() {
+        for a in [
+            0,
+            1,
+            0xffff_ffff_ffff_ffff,
+            0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+            u128::MAX,
+        ] {
+            assert_eq!(
+                U128::from_u128(a).wrapping_square(),
+                U128::from_u128(a.wrapping_mul(a))
+            );
+        }
+
+        assert_eq!(U128::MAX.wrapping_square(), U128::ONE);
+        assert_eq!(U64::MAX.wrapping_square(), U64::ONE);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn wrapping_square_matches_wrapping_mul() {
+        use crate::{Random, Uint};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        fn check<const LIMBS: usize>(rng: &mut ChaCha8Rng) {
+            for _ in 0..16 {
+                let a = Uint::<LIMBS>::random(rng);
+                assert_eq!(a.wrapping_square(), a.wrapping_mul(&a));
+                assert_eq!(a.wrapping_square(), a.square_wide().0);
+            }
+            assert_eq!(Uint::<LIMBS>::MAX.wrapping_square(), Uint::ONE);
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        check::<1>(&mut rng);
+        check::<2>(&mut rng);
+        check::<3>(&mut rng);
+        check::<4>(&mut rng);
+        check::<5>(&mut rng);
+        check::<16>(&mut rng);
+    }
+
+    #[test]
+    fn wrapping_mul_mixed() {
+        let a = U192::MAX.wrapping_sub(&U192::from_u64(0x1234_5678));
+        let b = U64::from_u64(0xfedc_ba98_7654_3210);
+        assert_eq!(a.wrapping_mul(&b), a.mul_wide(&b).0);
+
+        let b = U256::MAX.wrapping_sub(&U256::from_u64(0xdead_beef));
+        assert_eq!(a.wrapping_mul(&b), a.mul_wide(&b).0);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "rand"))]
+    #[test]
+    fn mul_wide_matches_boxed() {
+        use crate::{BoxedUint, Random, U1024};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..10 {
+            let a = U1024::random(&mut rng);
+            let b = U1024::random(&mut rng);
+            let (lo, hi) = a.mul_wide(&b);
+
+            let expected = BoxedUint::from(a).mul(&BoxedUint::from(b));
+            assert_eq!(BoxedUint::from(lo), expected.shorten(1024));
+            assert_eq!(
+                BoxedUint::from(hi),
+                expected.shr_vartime(1024).shorten(1024)
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn saturating_mul_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U128::random(&mut rng) >> (rng.next_u32() % 128);
+            let b = U128::random(&mut rng) >> (rng.next_u32() % 128);
+            assert_eq!(
+                u128::from(a.saturating_mul(&b)),
+                u128::from(a).saturating_mul(u128::from(b))
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn overflowing_mul_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U128::random(&mut rng) >> (rng.next_u32() % 128);
+            let b = U128::random(&mut rng) >> (rng.next_u32() % 128);
+            let (res, overflow) = a.overflowing_mul(&b);
+            assert_eq!(
+                (u128::from(res), overflow.is_true_vartime()),
+                u128::from(a).overflowing_mul(u128::from(b))
+            );
+        }
+    }
 }
@@ -0,0 +1,229 @@
+//! Karatsuba multiplication.
+//!
+//! Splits each operand into a low and a high half and computes the product using three half-size
+//! multiplications rather than four, which gives an asymptotic complexity of `O(n^log2(3))`
+//! rather than the `O(n^2)` of schoolbook multiplication.
+//!
+//! The recursion bottoms out in schoolbook multiplication once operands are smaller than a
+//! threshold, [`KARATSUBA_MIN_LIMBS`] by default, so the recursion depth is bounded by
+//! `log2(n / threshold)`.
+
+use super::{mul_limbs_with_threshold, square_limbs};
+use crate::{Limb, Word};
+use alloc::vec;
+
+/// Minimum number of limbs in each operand before Karatsuba multiplication is used.
+///
+/// Below this threshold the overhead of the additional additions and subtractions outweighs the
+/// savings in partial products, so schoolbook multiplication is used instead. The crossover
+/// depends on the target, and can be measured by comparing thresholds with
+/// `BoxedUint::mul_with_karatsuba_threshold` in the `boxed_uint` benchmark.
+pub(crate) const KARATSUBA_MIN_LIMBS: usize = 32;
+
+/// Compute `out = lhs * rhs` using Karatsuba multiplication, recursing into half-size products
+/// for as long as they have at least `min_limbs` limbs.
+///
+/// `lhs` and `rhs` must have the same length, and `out` must be zero-initialized and have twice
+/// their length.
+pub(crate) fn karatsuba_mul_limbs(lhs: &[Limb], rhs: &[Limb], out: &mut [Limb], min_limbs: usize) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    debug_assert_eq!(lhs.len() * 2, out.len());
+    debug_assert!(lhs.len() >= 2);
+
+    // Split the operands as `x = x1 * b^k + x0`, where `x1` has `h >= k` limbs
+    let n = lhs.len();
+    let k = n / 2;
+    let h = n - k;
+    let (a0, a1) = lhs.split_at(k);
+    let (b0, b1) = rhs.split_at(k);
+
+    // `z0 = a0 * b0` and `z2 = a1 * b1` occupy non-overlapping ranges of the output
+    {
+        let (z0, z2) = out.split_at_mut(k * 2);
+        mul_limbs_with_threshold(a0, b0, z0, min_limbs);
+        mul_limbs_with_threshold(a1, b1, z2, min_limbs);
+    }
+
+    // `|a1 - a0| * |b1 - b0|`, keeping track of the sign of each difference
+    let mut da = vec![Limb::ZERO; h];
+    let mut db = vec![Limb::ZERO; h];
+    let a_neg = abs_diff_limbs(a1, a0, &mut da);
+    let b_neg = abs_diff_limbs(b1, b0, &mut db);
+
+    let mut p = vec![Limb::ZERO; h * 2];
+    mul_limbs_with_threshold(&da, &db, &mut p, min_limbs);
+
+    // `z1 = a0 * b1 + a1 * b0 = z0 + z2 - (a1 - a0) * (b1 - b0)`, which needs at most one extra
+    // limb to hold the carry
+    let mut z1 = vec![Limb::ZERO; h * 2 + 1];
+    z1[..k * 2].copy_from_slice(&out[..k * 2]);
+    add_assign_limbs(&mut z1, &out[k * 2..], Limb::ZERO);
+
+    // The product of the differences is subtracted when both have the same sign, and added
+    // otherwise. Subtraction is performed by adding the two's complement.
+    let sub_mask = !(a_neg ^ b_neg);
+    p.iter_mut().for_each(|limb| limb.0 ^= sub_mask);
+    let carry = add_assign_limbs(&mut z1[..h * 2], &p, Limb(sub_mask & 1));
+    z1[h * 2] = z1[h * 2].wrapping_add(carry).wrapping_add(Limb(sub_mask));
+
+    // The result is `z2 * b^2k + z1 * b^k + z0`, and fits in `out` so the final carry is zero
+    let carry = add_assign_limbs(&mut out[k..], &z1, Limb::ZERO);
+    debug_assert_eq!(carry, Limb::ZERO);
+}
+
+/// Compute `out = limbs^2` using Karatsuba squaring.
+///
+/// This is [`karatsuba_mul_limbs`] specialized to equal operands, so all three half-size products
+/// are squares and the product of the differences is always subtracted. `out` must be
+/// zero-initialized and have twice the length of `limbs`.
+pub(crate) fn karatsuba_square_limbs(limbs: &[Limb], out: &mut [Limb]) {
+    debug_assert_eq!(limbs.len() * 2, out.len());
+    debug_assert!(limbs.len() >= 2);
+
+    let n = limbs.len();
+    let k = n / 2;
+    let h = n - k;
+    let (a0, a1) = limbs.split_at(k);
+
+    // `z0 = a0^2` and `z2 = a1^2` occupy non-overlapping ranges of the output
+    {
+        let (z0, z2) = out.split_at_mut(k * 2);
+        square_limbs(a0, z0);
+        square_limbs(a1, z2);
+    }
+
+    let mut da = vec![Limb::ZERO; h];
+    abs_diff_limbs(a1, a0, &mut da);
+
+    let mut p = vec![Limb::ZERO; h * 2];
+    square_limbs(&da, &mut p);
+
+    // `z1 = 2 * a0 * a1 = z0 + z2 - (a1 - a0)^2`, where the subtraction is performed by adding
+    // the two's complement
+    let mut z1 = vec![Limb::ZERO; h * 2 + 1];
+    z1[..k * 2].copy_from_slice(&out[..k * 2]);
+    add_assign_limbs(&mut z1, &out[k * 2..], Limb::ZERO);
+
+    p.iter_mut().for_each(|limb| *limb = !*limb);
+    let carry = add_assign_limbs(&mut z1[..h * 2], &p, Limb::ONE);
+    z1[h * 2] = z1[h * 2].wrapping_add(carry).wrapping_add(Limb::MAX);
+
+    // The result is `z2 * b^2k + z1 * b^k + z0`, and fits in `out` so the final carry is zero
+    let carry = add_assign_limbs(&mut out[k..], &z1, Limb::ZERO);
+    debug_assert_eq!(carry, Limb::ZERO);
+}
+
+/// Compute `out = |x - y|`, zero-padding `y` to the length of `x`.
+///
+/// Returns `Word::MAX` if `x < y`, and `0` otherwise.
+fn abs_diff_limbs(x: &[Limb], y: &[Limb], out: &mut [Limb]) -> Word {
+    debug_assert!(x.len() >= y.len());
+    debug_assert_eq!(x.len(), out.len());
+
+    let mut borrow = Limb::ZERO;
+    for i in 0..x.len() {
+        let (limb, b) = x[i].sbb(*y.get(i).unwrap_or(&Limb::ZERO), borrow);
+        out[i] = limb;
+        borrow = b;
+    }
+
+    // If the subtraction underflowed, negate the result
+    let mask = borrow.0;
+    let mut carry = Limb(mask & 1);
+    for limb in out.iter_mut() {
+        let (l, c) = Limb(limb.0 ^ mask).adc(Limb::ZERO, carry);
+        *limb = l;
+        carry = c;
+    }
+
+    mask
+}
+
+/// Compute `x += y + carry`, zero-padding `y` to the length of `x`.
+///
+/// Returns the carry out of the most significant limb of `x`.
+fn add_assign_limbs(x: &mut [Limb], y: &[Limb], mut carry: Limb) -> Limb {
+    debug_assert!(x.len() >= y.len());
+
+    for i in 0..x.len() {
+        let (limb, c) = x[i].adc(*y.get(i).unwrap_or(&Limb::ZERO), carry);
+        x[i] = limb;
+        carry = c;
+    }
+
+    carry
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use super::{karatsuba_mul_limbs, karatsuba_square_limbs, KARATSUBA_MIN_LIMBS};
+    use crate::{
+        uint::mul::{schoolbook_mul_limbs, schoolbook_square_limbs},
+        Limb, Random,
+    };
+    use alloc::{vec, vec::Vec};
+    use rand_chacha::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn matches_schoolbook() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        // Sizes straddling `KARATSUBA_MIN_LIMBS` exercise both the recursive and base cases
+        for n in [2, 3, 31, 32, 33, 63, 64, 65, 97, 128] {
+            for _ in 0..4 {
+                let a: Vec<Limb> = (0..n).map(|_| Limb::random(&mut rng)).collect();
+                let b: Vec<Limb> = (0..n).map(|_| Limb::random(&mut rng)).collect();
+
+                let mut expected = vec![Limb::ZERO; n * 2];
+                schoolbook_mul_limbs(&a, &b, &mut expected);
+
+                // Recursing all the way down, and stopping at the default threshold
+                for min_limbs in [2, KARATSUBA_MIN_LIMBS] {
+                    let mut actual = vec![Limb::ZERO; n * 2];
+                    karatsuba_mul_limbs(&a, &b, &mut actual, min_limbs);
+                    assert_eq!(expected, actual);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_operands() {
+        for n in [2, 33, 64, 65] {
+            let a = vec![Limb::MAX; n];
+
+            let mut expected = vec![Limb::ZERO; n * 2];
+            schoolbook_mul_limbs(&a, &a, &mut expected);
+
+            let mut actual = vec![Limb::ZERO; n * 2];
+            karatsuba_mul_limbs(&a, &a, &mut actual, KARATSUBA_MIN_LIMBS);
+
+            assert_eq!(expected, actual);
+
+            let mut actual = vec![Limb::ZERO; n * 2];
+            karatsuba_square_limbs(&a, &mut actual);
+
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn square_matches_schoolbook() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+
+        for n in [2, 3, 31, 32, 33, 63, 64, 65, 97, 128] {
+            for _ in 0..4 {
+                let a: Vec<Limb> = (0..n).map(|_| Limb::random(&mut rng)).collect();
+
+                let mut expected = vec![Limb::ZERO; n * 2];
+                schoolbook_square_limbs(&a, &mut expected);
+
+                let mut actual = vec![Limb::ZERO; n * 2];
+                karatsuba_square_limbs(&a, &mut actual);
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+}
@@ -1,15 +1,18 @@
 //! [`Uint`] modular multiplication operations.
 
 use crate::{
-    modular::{DynResidue, DynResidueParams},
+    modular::{montgomery_reduction, DynResidue, DynResidueParams},
     Limb, MulMod, Uint, WideWord, Word,
 };
 
 impl<const LIMBS: usize> Uint<LIMBS> {
-    /// Computes `self * rhs mod p` for odd `p`.
+    /// Computes `self * rhs mod p`.
     ///
-    /// Panics if `p` is even.
-    // TODO(tarcieri): support for even `p`?
+    /// Assumes `self` and `rhs` are `< p`.
+    ///
+    /// For odd `p` this is constant-time, otherwise it is variable-time with respect to `p` only.
+    ///
+    /// Panics if `p` is zero.
     pub fn mul_mod(&self, rhs: &Uint<LIMBS>, p: &Uint<LIMBS>) -> Uint<LIMBS> {
         // NOTE: the overhead of converting to Montgomery form to perform this operation and then
         // immediately converting out of Montgomery form after just a single operation is likely to
@@ -24,10 +27,41 @@ impl<const LIMBS: usize> Uint<LIMBS> {
                 let ret = lhs * rhs;
                 ret.retrieve()
             }
-            None => todo!("even moduli are currently unsupported"),
+            None => {
+                // Even moduli are handled by reducing the wide product directly
+                let (ret, is_some) = Uint::const_rem_wide(self.mul_wide(rhs), p);
+                assert!(is_some.is_true_vartime(), "modulus must be nonzero");
+                ret
+            }
         }
     }
 
+    /// Computes the Montgomery product `self * rhs * R^-1 mod modulus`, where `R = 2^Self::BITS`,
+    /// i.e. multiplies two values in Montgomery form.
+    ///
+    /// This is [`Uint::mul_wide`] followed by [`montgomery_reduction`], so it has the same
+    /// requirements: `modulus` must be odd, `mod_neg_inv` must be `-(modulus^-1) mod 2^Limb::BITS`
+    /// (see [`Limb::montgomery_inv`]), and `self * rhs` must be less than `modulus * R`, e.g.
+    /// because both are reduced modulo `modulus`.
+    pub const fn mont_mul(&self, rhs: &Self, modulus: &Self, mod_neg_inv: Limb) -> Self {
+        montgomery_reduction(&self.mul_wide(rhs), modulus, mod_neg_inv)
+    }
+
+    /// Computes `self * scalar mod p` for a single-limb `scalar`.
+    ///
+    /// `self` doesn't need to be reduced modulo `p`. The product only has one more limb than
+    /// `self`, so this is cheaper than [`Uint::mul_mod`] with a full-width operand.
+    ///
+    /// This is variable-time only with respect to `p`.
+    ///
+    /// Panics if `p` is zero.
+    pub const fn mul_limb_mod(&self, scalar: Limb, p: &Self) -> Self {
+        let (lo, hi) = mac_by_limb(&Self::ZERO, self, scalar, Limb::ZERO);
+        let (ret, is_some) = Self::const_rem_wide((lo, Self::from_word(hi.0)), p);
+        assert!(is_some.is_true_vartime(), "modulus must be nonzero");
+        ret
+    }
+
     /// Computes `self * rhs mod p` for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -152,6 +186,122 @@ mod tests {
         };
     }
 
+    #[test]
+    fn ring_laws_u64() {
+        use crate::U64;
+
+        let to_u128 = |x: U64| u128::from(u64::from(x));
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        // The largest prime below `2^64`
+        let p = 0xffff_ffff_ffff_ffc5u64;
+        let modulus = NonZero::new(U64::from_u64(p)).unwrap();
+
+        for _ in 0..100 {
+            let a = U64::random_mod(&mut rng, &modulus);
+            let b = U64::random_mod(&mut rng, &modulus);
+            let c = U64::random_mod(&mut rng, &modulus);
+            let (a_ref, b_ref) = (to_u128(a), to_u128(b));
+            let p_ref = u128::from(p);
+
+            assert_eq!(to_u128(a.add_mod(&b, &modulus)), (a_ref + b_ref) % p_ref);
+            assert_eq!(
+                to_u128(a.sub_mod(&b, &modulus)),
+                (a_ref + p_ref - b_ref) % p_ref
+            );
+            assert_eq!(to_u128(a.mul_mod(&b, &modulus)), (a_ref * b_ref) % p_ref);
+
+            // Distributivity
+            assert_eq!(
+                a.mul_mod(&b.add_mod(&c, &modulus), &modulus),
+                a.mul_mod(&b, &modulus)
+                    .add_mod(&a.mul_mod(&c, &modulus), &modulus)
+            );
+        }
+    }
+
+    #[test]
+    fn mul_mod_even() {
+        use crate::U64;
+
+        let to_u128 = |x: U64| u128::from(u64::from(x));
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        for p in [2u64, 0x1_0000_0000, 0xffff_ffff_ffff_fffe] {
+            let modulus = NonZero::new(U64::from_u64(p)).unwrap();
+
+            for _ in 0..16 {
+                let a = U64::random_mod(&mut rng, &modulus);
+                let b = U64::random_mod(&mut rng, &modulus);
+                let expected = (to_u128(a) * to_u128(b)) % u128::from(p);
+                assert_eq!(to_u128(a.mul_mod(&b, &modulus)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_limb_mod_matches_u128() {
+        use crate::U64;
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        for p in [2u64, 3, 0x1_0000_0000, 0xffff_ffff_ffff_ffc5, u64::MAX] {
+            let modulus = U64::from_u64(p);
+
+            for scalar in [Limb::ZERO, Limb::ONE, Limb::from(8u8), Limb::MAX] {
+                // `self` doesn't need to be reduced
+                let a = U64::random(&mut rng);
+                let expected = u128::from(u64::from(a)) * u128::from(scalar.0) % u128::from(p);
+                assert_eq!(
+                    u128::from(u64::from(a.mul_limb_mod(scalar, &modulus))),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be nonzero")]
+    fn mul_limb_mod_zero_modulus() {
+        crate::U64::ONE.mul_limb_mod(Limb::ONE, &crate::U64::ZERO);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn mont_mul_matches_boxed_residue() {
+        use crate::{
+            modular::{BoxedResidue, BoxedResidueParams, MontgomeryParams},
+            U256,
+        };
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        let p =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        let params = MontgomeryParams::new(&p).unwrap();
+        let boxed_params = BoxedResidueParams::new(p.to_boxed()).unwrap();
+        let modulus = NonZero::new(p).unwrap();
+
+        for _ in 0..32 {
+            let a = U256::random_mod(&mut rng, &modulus);
+            let b = U256::random_mod(&mut rng, &modulus);
+            let product = a.mont_mul(&b, &p, params.mod_neg_inv());
+            assert!(product < p);
+
+            // Boxed Montgomery forms are only almost reduced, so compare the retrieved values
+            let expected = BoxedResidue::from_montgomery(a.to_boxed(), boxed_params.clone())
+                * BoxedResidue::from_montgomery(b.to_boxed(), boxed_params.clone());
+            assert_eq!(
+                BoxedResidue::from_montgomery(product.to_boxed(), boxed_params.clone()).retrieve(),
+                expected.retrieve()
+            );
+
+            // Round trip through Montgomery form
+            let a_mont = a.mont_mul(params.r2(), &p, params.mod_neg_inv());
+            assert_eq!(a_mont.mont_mul(&U256::ONE, &p, params.mod_neg_inv()), a);
+        }
+    }
+
     test_mul_mod_special!(1, mul_mod_special_1);
     test_mul_mod_special!(2, mul_mod_special_2);
     test_mul_mod_special!(3, mul_mod_special_3);
@@ -11,8 +11,8 @@ impl<const LIMBS: usize> Neg for Wrapping<Uint<LIMBS>> {
 }
 
 impl<const LIMBS: usize> Uint<LIMBS> {
-    /// Negates based on `choice` by wrapping the integer.
-    pub(crate) const fn conditional_wrapping_neg(&self, choice: CtChoice) -> Uint<LIMBS> {
+    /// Perform wrapping negation if `choice` is truthy, otherwise return `self` unchanged.
+    pub const fn conditional_negate(&self, choice: CtChoice) -> Uint<LIMBS> {
         Uint::ct_select(self, &self.wrapping_neg(), choice)
     }
 
@@ -33,7 +33,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
 #[cfg(test)]
 mod tests {
-    use crate::U256;
+    use crate::{CtChoice, U256};
 
     #[test]
     fn wrapping_neg() {
@@ -48,4 +48,23 @@ mod tests {
             U256::from_u64(42).saturating_sub(&U256::ONE).not()
         );
     }
+
+    #[test]
+    fn wrapping_neg_add() {
+        for x in [
+            U256::ZERO,
+            U256::ONE,
+            U256::MAX,
+            U256::from_be_hex("8d16e171674b4e6d8529edba4593802bf30b8cb161dd30aa8e550d41380007c2"),
+        ] {
+            assert_eq!(x.wrapping_add(&x.wrapping_neg()), U256::ZERO);
+        }
+    }
+
+    #[test]
+    fn conditional_negate() {
+        let x = U256::from_u64(42);
+        assert_eq!(x.conditional_negate(CtChoice::FALSE), x);
+        assert_eq!(x.conditional_negate(CtChoice::TRUE), x.wrapping_neg());
+    }
 }
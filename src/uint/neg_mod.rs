@@ -65,4 +65,24 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn neg_mod_in_range() {
+        use crate::{NonZero, RandomMod};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let p =
+            U256::from_be_hex("928334a4e4be0843ec225a4c9c61df34bdc7a81513e4b6f76f2bfa3148e2e1b5");
+
+        for _ in 0..100 {
+            let x = U256::random_mod(&mut rng, &NonZero::new(p).unwrap());
+            let neg = x.neg_mod(&p);
+
+            assert!(neg < p);
+            assert_eq!(x.add_mod(&neg, &p), U256::ZERO);
+        }
+    }
 }
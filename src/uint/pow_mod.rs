@@ -0,0 +1,145 @@
+//! [`Uint`] modular exponentiation operations.
+
+use crate::{
+    modular::{DynResidue, DynResidueParams},
+    Uint,
+};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes `self ^ exponent mod modulus`.
+    ///
+    /// For an odd `modulus` this uses Montgomery exponentiation, equivalent to raising a
+    /// [`DynResidue`] to the `exponent` power. Even moduli fall back to a (slower) square-and-multiply
+    /// using division to reduce.
+    ///
+    /// Constant-time with respect to `self` and `exponent`, but whether `modulus` is odd may be
+    /// leaked in the time pattern.
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn pow_mod(&self, exponent: &Uint<LIMBS>, modulus: &Uint<LIMBS>) -> Self {
+        assert!(
+            modulus.ct_is_nonzero().is_true_vartime(),
+            "modulus must be nonzero"
+        );
+
+        let ret = match DynResidueParams::new(modulus).into() {
+            Some(params) => DynResidue::new(self, params).pow(exponent).retrieve(),
+            None => pow_mod_even(self, exponent, modulus),
+        };
+
+        // Everything is congruent to zero modulo one, but Montgomery form doesn't account for it
+        Uint::ct_select(&ret, &Uint::ZERO, Uint::ct_eq(modulus, &Uint::ONE))
+    }
+}
+
+/// Square-and-multiply exponentiation for a nonzero, possibly even, `modulus`.
+fn pow_mod_even<const LIMBS: usize>(
+    base: &Uint<LIMBS>,
+    exponent: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+) -> Uint<LIMBS> {
+    let base = base.const_rem(modulus).0;
+    let mut z = Uint::ONE.const_rem(modulus).0;
+
+    let mut i = Uint::<LIMBS>::BITS;
+    while i > 0 {
+        i -= 1;
+        z = Uint::const_rem_wide(z.square_wide(), modulus).0;
+        let product = Uint::const_rem_wide(z.mul_wide(&base), modulus).0;
+        z = Uint::ct_select(&z, &product, exponent.bit(i));
+    }
+
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::U128;
+
+    /// Reference implementation for moduli which fit in a `u64`.
+    fn pow_mod_u128(base: u128, mut exponent: u128, modulus: u128) -> u128 {
+        let mut base = base % modulus;
+        let mut ret = 1 % modulus;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                ret = ret * base % modulus;
+            }
+            base = base * base % modulus;
+            exponent >>= 1;
+        }
+
+        ret
+    }
+
+    #[test]
+    fn pow_mod_edge_cases() {
+        for modulus in [1u128, 2, 3, 4, 0xffff_fffb, 0x1_0000_0000] {
+            let m = U128::from_u128(modulus);
+
+            // `x^0 == 1 mod modulus`
+            assert_eq!(
+                U128::from(5u8).pow_mod(&U128::ZERO, &m),
+                U128::from_u128(1 % modulus)
+            );
+
+            // `0^x == 0` for `x > 0`
+            assert_eq!(U128::ZERO.pow_mod(&U128::from(7u8), &m), U128::ZERO);
+        }
+
+        // Everything is zero modulo one
+        assert_eq!(U128::MAX.pow_mod(&U128::MAX, &U128::ONE), U128::ZERO);
+        assert_eq!(U128::ONE.pow_mod(&U128::ZERO, &U128::ONE), U128::ZERO);
+    }
+
+    #[test]
+    fn pow_mod_matches_u128() {
+        let cases = [
+            (2u128, 10u128, 1000u128),
+            (3, 0xffff, 0xffff_fffb),
+            (0x1234_5678_9abc, 0xdead_beef, 0xffff_ffff_ffff_ffc5),
+            (0xffff_ffff_ffff_ffff_ffff, u128::MAX, 0x8000_0000_0000_0000),
+            (0xabcd_ef01_2345, 0x31_4159_2653, 0x1_0000_0000_0000),
+            (u128::MAX, u128::MAX, 0xffff_ffff_ffff_fffe),
+        ];
+
+        for (base, exponent, modulus) in cases {
+            assert_eq!(
+                U128::from_u128(base)
+                    .pow_mod(&U128::from_u128(exponent), &U128::from_u128(modulus)),
+                U128::from_u128(pow_mod_u128(base, exponent, modulus)),
+                "{}^{} mod {}",
+                base,
+                exponent,
+                modulus
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn pow_mod_random() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let base = (u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64());
+            let exponent = (u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64());
+            let modulus = u128::from(rng.next_u64()).max(1);
+
+            assert_eq!(
+                U128::from_u128(base)
+                    .pow_mod(&U128::from_u128(exponent), &U128::from_u128(modulus)),
+                U128::from_u128(pow_mod_u128(base, exponent, modulus))
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be nonzero")]
+    fn pow_mod_zero_modulus() {
+        U128::ONE.pow_mod(&U128::ONE, &U128::ZERO);
+    }
+}
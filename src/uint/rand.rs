@@ -2,12 +2,14 @@
 
 use super::Uint;
 use crate::{Encoding, Limb, NonZero, Random, RandomMod, Zero};
-use rand_core::CryptoRngCore;
+use rand_core::{CryptoRngCore, RngCore};
 use subtle::ConstantTimeLess;
 
 impl<const LIMBS: usize> Random for Uint<LIMBS> {
-    /// Generate a cryptographically secure random [`Uint`].
-    fn random(mut rng: &mut impl CryptoRngCore) -> Self {
+    /// Generate a random [`Uint`].
+    ///
+    /// Any [`RngCore`] is accepted, so this is cryptographically secure only if `rng` is a CSRNG.
+    fn random(mut rng: &mut impl RngCore) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
 
         for limb in &mut limbs {
@@ -37,10 +39,10 @@ impl<const LIMBS: usize> RandomMod for Uint<LIMBS> {
     }
 }
 
-/// Generic implementation of `random_mod` which can be shared with `BoxedUint`.
+/// Generic implementation of `random_mod` which can be shared with `BoxedUint` and `Residue`.
 // TODO(tarcieri): obtain `n_bits` via a trait like `Integer`
-pub(super) fn random_mod_core<T>(
-    rng: &mut impl CryptoRngCore,
+pub(crate) fn random_mod_core<T>(
+    rng: &mut impl RngCore,
     n: &mut T,
     modulus: &NonZero<T>,
     n_bits: u32,
@@ -75,8 +77,41 @@ pub(super) fn random_mod_core<T>(
 
 #[cfg(test)]
 mod tests {
-    use crate::{NonZero, RandomMod, U256};
-    use rand_core::SeedableRng;
+    use crate::{NonZero, Random, RandomMod, Word, U256};
+    use rand_core::{impls, RngCore, SeedableRng};
+
+    /// Deterministic non-cryptographic RNG returning consecutive integers.
+    struct CounterRng(u64);
+
+    impl RngCore for CounterRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn random_non_crypto_rng() {
+        let mut rng = CounterRng(0);
+        let expected = U256::from_words(core::array::from_fn(|i| i as Word + 1));
+        assert_eq!(U256::random(&mut rng), expected);
+
+        let expected = U256::from_words(core::array::from_fn(|i| (i + U256::LIMBS) as Word + 1));
+        assert_eq!(U256::random(&mut rng), expected);
+    }
 
     #[test]
     fn random_mod() {
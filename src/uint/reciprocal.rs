@@ -0,0 +1,210 @@
+//! Multi-limb division by a reused divisor via reciprocal precomputation.
+//!
+//! The divisor is normalized so that its most significant bit is set, and a [`Reciprocal`] of its
+//! most significant limb is precomputed. Each division is then a schoolbook long division in base
+//! `2^Limb::BITS`, where every quotient limb is estimated with a 2-by-1 division using the
+//! reciprocal (see "Improved Division by Invariant Integers" by Niels Möller and Torbjorn
+//! Granlund) and corrected at most twice, as described in Knuth's TAOCP vol. 2, §4.3.1.
+
+use super::div_limb::{div2by1, Reciprocal};
+use crate::{CtChoice, Limb, Uint, WideWord, Word};
+use subtle::CtOption;
+
+/// A pre-calculated reciprocal for repeated division of [`Uint`]s by the same divisor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UintReciprocal<const LIMBS: usize> {
+    divisor_normalized: Uint<LIMBS>,
+    shift: u32,
+    reciprocal: Reciprocal,
+}
+
+impl<const LIMBS: usize> UintReciprocal<LIMBS> {
+    /// Pre-calculates a reciprocal for a known divisor, to be used in [`UintReciprocal::div_rem`].
+    /// Returns the reciprocal, and the truthy value if `divisor != 0` and the falsy value
+    /// otherwise.
+    ///
+    /// Note: if the returned flag is falsy, the returned reciprocal object is still self-consistent
+    /// and can be passed to functions here without causing them to panic,
+    /// but the results are naturally not to be used.
+    pub const fn ct_new(divisor: &Uint<LIMBS>) -> (Self, CtChoice) {
+        let is_some = divisor.ct_is_nonzero();
+
+        // Substitute a bogus divisor of one if `divisor = 0`
+        let divisor = Uint::ct_select(&Uint::ONE, divisor, is_some);
+        let shift = divisor.leading_zeros();
+        let divisor_normalized = divisor.shl(shift);
+        let (reciprocal, _) = Reciprocal::ct_new(divisor_normalized.limbs[LIMBS - 1]);
+
+        (
+            Self {
+                divisor_normalized,
+                shift,
+                reciprocal,
+            },
+            is_some,
+        )
+    }
+
+    /// A non-const-fn version of `ct_new()`, wrapping the result in a `CtOption`.
+    pub fn new(divisor: &Uint<LIMBS>) -> CtOption<Self> {
+        let (rec, is_some) = Self::ct_new(divisor);
+        CtOption::new(rec, is_some.into())
+    }
+
+    /// Returns the divisor this reciprocal was computed for.
+    pub const fn divisor(&self) -> Uint<LIMBS> {
+        self.divisor_normalized.shr(self.shift)
+    }
+
+    /// Computes `dividend / divisor`, returns the quotient (q) and remainder (r).
+    ///
+    /// This function is constant-time with respect to both `dividend` and the divisor.
+    pub const fn div_rem(&self, dividend: &Uint<LIMBS>) -> (Uint<LIMBS>, Uint<LIMBS>) {
+        let d = &self.divisor_normalized.limbs;
+        let d_hi = d[LIMBS - 1].0;
+
+        // `dividend << shift`, split into the low `LIMBS` limbs and the high part which is
+        // smaller than the normalized divisor
+        let u_lo = dividend.shl(self.shift);
+        let mut r = dividend.shr(Uint::<LIMBS>::BITS - self.shift).limbs;
+        let mut q = [Limb::ZERO; LIMBS];
+
+        let mut j = LIMBS;
+        while j > 0 {
+            j -= 1;
+
+            // The partial remainder is `r * 2^Limb::BITS + u_lo[j]`, with `r < divisor`
+            let r_hi = r[LIMBS - 1].0;
+            let r_next = if LIMBS > 1 {
+                r[LIMBS - 2].0
+            } else {
+                u_lo.limbs[j].0
+            };
+
+            // Estimate the quotient limb from the top two limbs of the partial remainder. Since
+            // `r < divisor` we have `r_hi <= d_hi`, and the estimate saturates when they are equal.
+            // In either case the estimate exceeds the true quotient limb by at most two.
+            let saturated = CtChoice::from_word_eq(r_hi, d_hi);
+            let (qhat, _) = div2by1(saturated.select_word(r_hi, 0), r_next, &self.reciprocal);
+            let mut qhat = saturated.select_word(qhat, Word::MAX);
+
+            // Subtract `qhat * divisor` from the partial remainder, shifting in `u_lo[j]`
+            let mut rem = [Limb::ZERO; LIMBS];
+            let mut carry: Word = 0;
+            let mut borrow = Limb::ZERO;
+            let mut i = 0;
+            while i < LIMBS {
+                let prod = (qhat as WideWord) * (d[i].0 as WideWord) + (carry as WideWord);
+                carry = (prod >> Word::BITS) as Word;
+
+                let v = if i == 0 { u_lo.limbs[j] } else { r[i - 1] };
+                let (w, b) = v.sbb(Limb(prod as Word), borrow);
+                rem[i] = w;
+                borrow = b;
+                i += 1;
+            }
+            let (mut rem_hi, _) = Limb(r_hi).sbb(Limb(carry), borrow);
+
+            // The result lies in `[-2 * divisor, divisor)`, so add the divisor back at most twice
+            let mut k = 0;
+            while k < 2 {
+                let negative = CtChoice::from_word_lsb(rem_hi.0 >> (Word::BITS - 1));
+                let mut carry = Limb::ZERO;
+                let mut i = 0;
+                while i < LIMBS {
+                    let (w, c) = rem[i].adc(Limb(negative.if_true_word(d[i].0)), carry);
+                    rem[i] = w;
+                    carry = c;
+                    i += 1;
+                }
+                rem_hi = rem_hi.wrapping_add(carry);
+                qhat = qhat.wrapping_sub(negative.if_true_word(1));
+                k += 1;
+            }
+
+            q[j] = Limb(qhat);
+            r = rem;
+        }
+
+        (Uint::new(q), Uint::new(r).shr(self.shift))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UintReciprocal;
+    use crate::{NonZero, U1024, U256, U64};
+
+    #[test]
+    fn zero_divisor() {
+        assert!(bool::from(UintReciprocal::new(&U256::ZERO).is_none()));
+        let (_, is_some) = UintReciprocal::ct_new(&U256::ZERO);
+        assert!(!is_some.is_true_vartime());
+    }
+
+    #[test]
+    fn divisor() {
+        let d = U256::from_u64(0x1234_5678_9abc_def0);
+        assert_eq!(UintReciprocal::new(&d).unwrap().divisor(), d);
+    }
+
+    #[test]
+    fn div_rem_edge_cases() {
+        for d in [
+            U256::ONE,
+            U256::from_u8(3),
+            U256::MAX,
+            U256::MAX.shr_vartime(1),
+        ] {
+            let reciprocal = UintReciprocal::new(&d).unwrap();
+            let nz = NonZero::new(d).unwrap();
+
+            for n in [
+                U256::ZERO,
+                U256::ONE,
+                d,
+                U256::MAX,
+                U256::MAX.wrapping_sub(&d),
+            ] {
+                assert_eq!(reciprocal.div_rem(&n), n.div_rem(&nz));
+            }
+        }
+    }
+
+    #[test]
+    fn div_rem_single_limb() {
+        let d = U64::from_u64(0xffff_fffb);
+        let reciprocal = UintReciprocal::new(&d).unwrap();
+        let n = U64::from_u64(0xffee_ccbb_aa99_8877);
+        assert_eq!(reciprocal.div_rem(&n), n.div_rem(&NonZero::new(d).unwrap()));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn div_rem_matches_uint() {
+        use crate::{Random, Uint};
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        fn check<const LIMBS: usize>(rng: &mut ChaCha8Rng) {
+            for _ in 0..32 {
+                // Divisors of all sizes, including ones with leading zero limbs
+                let d =
+                    Uint::<LIMBS>::random(rng).shr_vartime(rng.next_u32() % Uint::<LIMBS>::BITS);
+                let n =
+                    Uint::<LIMBS>::random(rng).shr_vartime(rng.next_u32() % Uint::<LIMBS>::BITS);
+                if d == Uint::ZERO {
+                    continue;
+                }
+
+                let reciprocal = UintReciprocal::new(&d).unwrap();
+                assert_eq!(reciprocal.div_rem(&n), n.div_rem(&NonZero::new(d).unwrap()));
+            }
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        check::<{ U64::LIMBS }>(&mut rng);
+        check::<{ U256::LIMBS }>(&mut rng);
+        check::<{ U1024::LIMBS }>(&mut rng);
+    }
+}
@@ -20,6 +20,29 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::ct_select(&result, &Self::ZERO, overflow)
     }
 
+    /// Computes `self << shift` for a [`Word`]-sized `shift`.
+    /// Returns zero if `shift >= Self::BITS`.
+    ///
+    /// Unlike casting the shift amount to `u32` for [`Uint::shl`], this also returns zero for
+    /// shifts which would be truncated to a small `u32`, e.g. `Word::MAX`.
+    #[allow(trivial_numeric_casts)]
+    pub const fn shl_word(&self, shift: Word) -> Self {
+        let overflow = CtChoice::from_word_lt(shift, Self::BITS as Word).not();
+
+        // The reduced shift is less than `Self::BITS`, so it always fits in a `u32`
+        let shift = (shift % Self::BITS as Word) as u32;
+        Uint::ct_select(&self.shl(shift), &Self::ZERO, overflow)
+    }
+
+    /// Computes `self << (shift % Self::BITS)`, matching the semantics of `wrapping_shl` on
+    /// the primitive integer types.
+    ///
+    /// Unlike [`Uint::shl`], which returns zero if `shift >= Self::BITS`, the shift amount is
+    /// reduced modulo `Self::BITS`, so `self.wrapping_shl(Self::BITS) == self`.
+    pub const fn wrapping_shl(&self, shift: u32) -> Self {
+        self.shl(shift % Self::BITS)
+    }
+
     /// Computes `self << shift`.
     ///
     /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
@@ -49,6 +72,8 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Computes a left shift on a wide input as `(lo, hi)`.
     ///
+    /// Returns zero if `shift >= 2 * Self::BITS`.
+    ///
     /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
     ///
     /// When used with a fixed `shift`, this function is constant-time with respect
@@ -91,10 +116,34 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (Uint::<LIMBS>::new(limbs), Limb(carry))
     }
 
-    /// Computes `self >> 1` in constant-time.
+    /// Computes `self << 1` in constant-time, returning [`CtChoice::TRUE`] if the overflowing bit
+    /// was set, and [`CtChoice::FALSE`] otherwise.
+    ///
+    /// The overflowing bit is the most significant bit of `self`, i.e. the one shifted out of the
+    /// top of the integer.
+    pub const fn shl1_with_overflow(&self) -> (Self, CtChoice) {
+        let mut ret = Self::ZERO;
+        let mut carry = 0;
+
+        let mut i = 0;
+        while i < LIMBS {
+            ret.limbs[i] = Limb((self.limbs[i].0 << 1) | carry);
+            carry = self.limbs[i].0 >> Limb::HI_BIT;
+            i += 1;
+        }
+
+        (ret, CtChoice::from_word_lsb(carry))
+    }
+
+    /// Computes `self << 1` in constant-time.
     pub(crate) const fn shl1(&self) -> Self {
-        // TODO(tarcieri): optimized implementation
-        self.shl_vartime(1)
+        self.shl1_with_overflow().0
+    }
+
+    /// Computes `2 * self`, returning [`CtChoice::TRUE`] if the result overflowed, i.e. if the
+    /// carry-out is set, and [`CtChoice::FALSE`] otherwise.
+    pub const fn double(&self) -> (Self, CtChoice) {
+        self.shl1_with_overflow()
     }
 }
 
@@ -122,7 +171,7 @@ impl<const LIMBS: usize> ShlAssign<u32> for Uint<LIMBS> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Limb, Uint, U128, U256};
+    use crate::{Limb, Uint, Word, U128, U256};
 
     const N: U256 =
         U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
@@ -150,11 +199,62 @@ mod tests {
         assert_eq!(t << 8, U256::from(0x300u16));
     }
 
+    #[test]
+    fn shl_word() {
+        for shift in [0, 1, 2, 65, 88, 200, 255] {
+            assert_eq!(N.shl_word(Word::from(shift)), N.shl(shift));
+        }
+
+        assert_eq!(N.shl_word(256), U256::ZERO);
+        assert_eq!(N.shl_word(Word::MAX), U256::ZERO);
+
+        // Would be truncated to a shift of 1 as a `u32` on 64-bit targets
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(N.shl_word((1 << 32) | 1), U256::ZERO);
+    }
+
     #[test]
     fn shl1() {
         assert_eq!(N << 1, TWO_N);
     }
 
+    #[test]
+    fn shl1_with_overflow() {
+        let (ret, overflow) = N.shl1_with_overflow();
+        assert_eq!(ret, TWO_N);
+        assert!(overflow.is_true_vartime());
+
+        let (ret, overflow) = U256::ONE.shl1_with_overflow();
+        assert_eq!(ret, U256::from(2u8));
+        assert!(!overflow.is_true_vartime());
+    }
+
+    #[test]
+    fn shl1_shr1_round_trip() {
+        // Shifting out a bit and shifting it back in restores the original value
+        for n in [U256::ZERO, U256::ONE, U256::MAX, N, N.shr_vartime(1)] {
+            let (shifted, overflow) = n.shl1_with_overflow();
+            let (restored, lsb) = shifted.shr1_with_overflow();
+            assert!(!lsb.is_true_vartime());
+            assert_eq!(
+                restored.bitor(
+                    &U256::ONE
+                        .shl_vartime(255)
+                        .wrapping_mul(&U256::from(overflow.is_true_vartime() as u8))
+                ),
+                n
+            );
+
+            let (shifted, lsb) = n.shr1_with_overflow();
+            let (restored, overflow) = shifted.shl1_with_overflow();
+            assert!(!overflow.is_true_vartime());
+            assert_eq!(
+                U256::ct_select(&restored, &restored.bitor(&U256::ONE), lsb),
+                n
+            );
+        }
+    }
+
     #[test]
     fn shl2() {
         assert_eq!(N << 2, FOUR_N);
@@ -203,4 +303,46 @@ mod tests {
             (U128::ZERO, U128::ZERO)
         );
     }
+
+    /// Reference left shift of the 256-bit integer `(lo, hi)`.
+    fn shl_u256((lo, hi): (u128, u128), shift: u32) -> (u128, u128) {
+        match shift {
+            0 => (lo, hi),
+            1..=127 => (lo << shift, (hi << shift) | (lo >> (128 - shift))),
+            128..=255 => (0, lo << (shift - 128)),
+            _ => (0, 0),
+        }
+    }
+
+    #[test]
+    fn shl_wide_boundaries() {
+        let values = [
+            (u128::MAX, u128::MAX),
+            (1, 1 << 127),
+            (0x0123_4567_89ab_cdef_fedc_ba98_7654_3210, 0xdead_beef),
+        ];
+
+        for (lo, hi) in values {
+            for shift in [0, 1, 63, 64, 127, 128, 129, 191, 255, 256, 257, 1000] {
+                let (expected_lo, expected_hi) = shl_u256((lo, hi), shift);
+                assert_eq!(
+                    Uint::shl_vartime_wide((U128::from_u128(lo), U128::from_u128(hi)), shift),
+                    (U128::from_u128(expected_lo), U128::from_u128(expected_hi)),
+                    "({lo:x}, {hi:x}) << {shift}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn wrapping_shl() {
+        assert_eq!(N.wrapping_shl(0), N);
+        assert_eq!(N.wrapping_shl(256), N);
+        assert_eq!(N.wrapping_shl(1), N.shl(1));
+
+        for k in 0..(3 * U256::BITS) {
+            assert_eq!(N.wrapping_shl(k), N.wrapping_shl(k % U256::BITS));
+            assert_eq!(N.wrapping_shl(k), N.shl_vartime(k % U256::BITS));
+        }
+    }
 }
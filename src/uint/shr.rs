@@ -1,11 +1,11 @@
 //! [`Uint`] bitwise right shift operations.
 
 use super::Uint;
-use crate::{CtChoice, Limb};
+use crate::{CtChoice, Limb, Word};
 use core::ops::{Shr, ShrAssign};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
-    /// Computes `self << shift`.
+    /// Computes `self >> shift`.
     /// Returns zero if `shift >= Self::BITS`.
     pub const fn shr(&self, shift: u32) -> Self {
         let overflow = CtChoice::from_u32_lt(shift, Self::BITS).not();
@@ -21,6 +21,40 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::ct_select(&result, &Self::ZERO, overflow)
     }
 
+    /// Computes `self >> shift` for a [`Word`]-sized `shift`.
+    /// Returns zero if `shift >= Self::BITS`.
+    ///
+    /// Unlike casting the shift amount to `u32` for [`Uint::shr`], this also returns zero for
+    /// shifts which would be truncated to a small `u32`, e.g. `Word::MAX`.
+    #[allow(trivial_numeric_casts)]
+    pub const fn shr_word(&self, shift: Word) -> Self {
+        let overflow = CtChoice::from_word_lt(shift, Self::BITS as Word).not();
+
+        // The reduced shift is less than `Self::BITS`, so it always fits in a `u32`
+        let shift = (shift % Self::BITS as Word) as u32;
+        Uint::ct_select(&self.shr(shift), &Self::ZERO, overflow)
+    }
+
+    /// Computes `self >> (shift % Self::BITS)`, matching the semantics of `wrapping_shr` on
+    /// the primitive integer types.
+    ///
+    /// Unlike [`Uint::shr`], which returns zero if `shift >= Self::BITS`, the shift amount is
+    /// reduced modulo `Self::BITS`, so `self.wrapping_shr(Self::BITS) == self`.
+    pub const fn wrapping_shr(&self, shift: u32) -> Self {
+        self.shr(shift % Self::BITS)
+    }
+
+    /// Computes `self >> shift` along with the bits which were shifted out.
+    ///
+    /// Returns `(self >> shift, self & (2^shift - 1))`, i.e. the quotient and remainder of
+    /// dividing `self` by `2^shift`. If `shift >= Self::BITS`, the quotient is zero and the
+    /// remainder is `self`.
+    pub const fn shr_extract(&self, shift: u32) -> (Self, Self) {
+        let quotient = self.shr(shift);
+        let remainder = self.wrapping_sub(&quotient.shl(shift));
+        (quotient, remainder)
+    }
+
     /// Computes `self >> shift`.
     ///
     /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
@@ -33,7 +67,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         let small_shift = shift & (Limb::BITS - 1);
         let mut limbs = [Limb::ZERO; LIMBS];
 
-        if shift > Self::BITS {
+        if shift >= Self::BITS {
             return Self { limbs };
         }
 
@@ -63,6 +97,8 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Computes a right shift on a wide input as `(lo, hi)`.
     ///
+    /// Returns zero if `shift >= 2 * Self::BITS`.
+    ///
     /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
     ///
     /// When used with a fixed `shift`, this function is constant-time with respect
@@ -83,7 +119,10 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Computes `self >> 1` in constant-time, returning [`CtChoice::TRUE`] if the overflowing bit
     /// was set, and [`CtChoice::FALSE`] otherwise.
-    pub(crate) const fn shr1_with_overflow(&self) -> (Self, CtChoice) {
+    ///
+    /// The overflowing bit is bit 0 of `self`, i.e. the one shifted out of the bottom of the
+    /// integer.
+    pub const fn shr1_with_overflow(&self) -> (Self, CtChoice) {
         let carry = CtChoice::from_word_lsb(self.limbs[0].0 & 1);
         let mut ret = Self::ZERO;
         ret.limbs[0] = self.limbs[0].shr(1);
@@ -129,7 +168,7 @@ impl<const LIMBS: usize> ShrAssign<u32> for Uint<LIMBS> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Uint, U128, U256};
+    use crate::{Uint, Word, U128, U256};
 
     const N: U256 =
         U256::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
@@ -142,6 +181,52 @@ mod tests {
         assert_eq!(N >> 1, N_2);
     }
 
+    #[test]
+    fn shr1_with_overflow() {
+        let (ret, overflow) = N.shr1_with_overflow();
+        assert_eq!(ret, N_2);
+        assert!(overflow.is_true_vartime());
+
+        let (ret, overflow) = N_2.shr1_with_overflow();
+        assert_eq!(ret, N >> 2);
+        assert!(!overflow.is_true_vartime());
+    }
+
+    #[test]
+    fn shr_word() {
+        for shift in [0, 1, 7, 64, 65, 200, 255] {
+            assert_eq!(N.shr_word(Word::from(shift)), N.shr(shift));
+        }
+
+        assert_eq!(N.shr_word(256), U256::ZERO);
+        assert_eq!(N.shr_word(Word::MAX), U256::ZERO);
+
+        // Would be truncated to a shift of 1 as a `u32` on 64-bit targets
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(N.shr_word((1 << 32) | 1), U256::ZERO);
+    }
+
+    #[test]
+    fn shr_extract() {
+        for shift in [1, 7, 64, 65, 200, 255] {
+            let (q, r) = N.shr_extract(shift);
+            assert_eq!(q, N >> shift);
+            assert!(r < U256::ONE << shift);
+            assert_eq!(q.shl(shift).wrapping_add(&r), N);
+        }
+    }
+
+    #[test]
+    fn shr_extract_zero() {
+        assert_eq!(N.shr_extract(0), (N, U256::ZERO));
+    }
+
+    #[test]
+    fn shr_extract_overflow() {
+        assert_eq!(N.shr_extract(256), (U256::ZERO, N));
+        assert_eq!(N.shr_extract(300), (U256::ZERO, N));
+    }
+
     #[test]
     fn shr_wide_1_1_128() {
         assert_eq!(
@@ -165,4 +250,46 @@ mod tests {
             (U128::ZERO, U128::ZERO)
         );
     }
+
+    /// Reference right shift of the 256-bit integer `(lo, hi)`.
+    fn shr_u256((lo, hi): (u128, u128), shift: u32) -> (u128, u128) {
+        match shift {
+            0 => (lo, hi),
+            1..=127 => ((lo >> shift) | (hi << (128 - shift)), hi >> shift),
+            128..=255 => (hi >> (shift - 128), 0),
+            _ => (0, 0),
+        }
+    }
+
+    #[test]
+    fn shr_wide_boundaries() {
+        let values = [
+            (u128::MAX, u128::MAX),
+            (1, 1 << 127),
+            (0x0123_4567_89ab_cdef_fedc_ba98_7654_3210, 0xdead_beef),
+        ];
+
+        for (lo, hi) in values {
+            for shift in [0, 1, 63, 64, 127, 128, 129, 191, 255, 256, 257, 1000] {
+                let (expected_lo, expected_hi) = shr_u256((lo, hi), shift);
+                assert_eq!(
+                    Uint::shr_vartime_wide((U128::from_u128(lo), U128::from_u128(hi)), shift),
+                    (U128::from_u128(expected_lo), U128::from_u128(expected_hi)),
+                    "({lo:x}, {hi:x}) >> {shift}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn wrapping_shr() {
+        assert_eq!(N.wrapping_shr(0), N);
+        assert_eq!(N.wrapping_shr(256), N);
+        assert_eq!(N.wrapping_shr(1), N.shr(1));
+
+        for k in 0..(3 * U256::BITS) {
+            assert_eq!(N.wrapping_shr(k), N.wrapping_shr(k % U256::BITS));
+            assert_eq!(N.wrapping_shr(k), N.shr_vartime(k % U256::BITS));
+        }
+    }
 }
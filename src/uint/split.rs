@@ -24,9 +24,21 @@ pub(crate) const fn split_mixed<const L: usize, const H: usize, const O: usize>(
     (Uint { limbs: hi }, Uint { limbs: lo })
 }
 
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Splits `self` at bit `k` into its low `k` bits and the remaining high bits, returning
+    /// `(self.rem2k(k), self.shr(k))`, so that `low | (high << k) == self`.
+    ///
+    /// Returns `(ZERO, self)` if `k == 0`, and `(self, ZERO)` if `k >= Self::BITS`.
+    ///
+    /// This is constant-time with respect to both `self` and `k`.
+    pub const fn split_at_bit(&self, k: u32) -> (Self, Self) {
+        (self.rem2k(k), self.shr(k))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{U128, U64};
+    use crate::{U128, U256, U64};
 
     #[test]
     fn split() {
@@ -34,4 +46,57 @@ mod tests {
         assert_eq!(hi, U64::from_u64(0x0011223344556677));
         assert_eq!(lo, U64::from_u64(0x8899aabbccddeeff));
     }
+
+    #[test]
+    fn split_at_bit() {
+        let x =
+            U256::from_be_hex("77117f1273373c26c700d076b3f780074d03339f56dd0efb60e7f58441fd3685");
+        assert_eq!(x.split_at_bit(0), (U256::ZERO, x));
+        assert_eq!(x.split_at_bit(U256::BITS), (x, U256::ZERO));
+        assert_eq!(x.split_at_bit(1000), (x, U256::ZERO));
+
+        for k in [1, 7, 63, 64, 65, 128, 200, 255] {
+            let (lo, hi) = x.split_at_bit(k);
+            assert_eq!(lo | hi.shl(k), x, "k = {k}");
+            assert!(lo.bits_vartime() <= k, "k = {k}");
+            assert!(hi.bits_vartime() <= U256::BITS - k, "k = {k}");
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn split_at_bit_random() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let x = U256::random(&mut rng);
+            let k = rng.next_u32() % (U256::BITS + 1);
+            let (lo, hi) = x.split_at_bit(k);
+            assert_eq!(lo | hi.shl(k), x, "k = {k}");
+        }
+    }
+
+    #[test]
+    fn split_wide() {
+        let (lo, hi) = U128::from_be_hex("00112233445566778899aabbccddeeff").split_wide();
+        assert_eq!(lo, U64::from_u64(0x8899aabbccddeeff));
+        assert_eq!(hi, U64::from_u64(0x0011223344556677));
+    }
+
+    #[test]
+    fn split_wide_concat_wide() {
+        let lo = U128::from_be_hex("8899aabbccddeeff0011223344556677");
+        let hi = U128::from_be_hex("ffeeddccbbaa99887766554433221100");
+        assert_eq!(U128::concat_wide((lo, hi)).split_wide(), (lo, hi));
+
+        // Consistent with the `(lo, hi)` products of `mul_wide`
+        let x = U128::MAX.wrapping_sub(&U128::from_u64(5));
+        let wide = U128::concat_wide(x.mul_wide(&x));
+        assert_eq!(wide, x.square());
+        assert_eq!(wide.split_wide(), x.square_wide());
+    }
 }
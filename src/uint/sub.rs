@@ -7,6 +7,22 @@ use subtle::CtOption;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `a - (b + borrow)`, returning the result along with the new borrow.
+    ///
+    /// As with [`Limb::sbb`], the borrow is a mask: only the most significant bit of the incoming
+    /// `borrow` is used, and the returned borrow is either `0` or [`Limb::MAX`]. Subtractions of
+    /// multi-word integers can be chained by passing the borrow out of the lower half into the
+    /// subtraction of the upper half:
+    ///
+    /// ```
+    /// use crypto_bigint::{Limb, U128};
+    ///
+    /// // (0, 1) - (1, 0) as two-word little endian integers
+    /// let (lo, borrow) = U128::ZERO.sbb(&U128::ONE, Limb::ZERO);
+    /// assert_eq!((lo, borrow), (U128::MAX, Limb::MAX));
+    ///
+    /// let (hi, borrow) = U128::ONE.sbb(&U128::ZERO, borrow);
+    /// assert_eq!((hi, borrow), (U128::ZERO, Limb::ZERO));
+    /// ```
     #[inline(always)]
     pub const fn sbb(&self, rhs: &Self, mut borrow: Limb) -> (Self, Limb) {
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -28,12 +44,29 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Self::ct_select(&res, &Self::ZERO, CtChoice::from_word_mask(underflow.0))
     }
 
+    /// Computes the absolute difference `|self - rhs|`.
+    ///
+    /// Both differences are computed and the result is selected in constant time, so the timing
+    /// doesn't reveal which of the operands is larger.
+    pub const fn abs_diff(&self, rhs: &Self) -> Self {
+        let (diff, borrow) = self.sbb(rhs, Limb::ZERO);
+        let (neg_diff, _) = rhs.sbb(self, Limb::ZERO);
+        Self::ct_select(&diff, &neg_diff, CtChoice::from_word_mask(borrow.0))
+    }
+
     /// Perform wrapping subtraction, discarding underflow and wrapping around
     /// the boundary of the type.
     pub const fn wrapping_sub(&self, rhs: &Self) -> Self {
         self.sbb(rhs, Limb::ZERO).0
     }
 
+    /// Perform wrapping subtraction, returning the wrapped result along with the truthy value if
+    /// an underflow has occurred, like [`u64::overflowing_sub`].
+    pub const fn overflowing_sub(&self, rhs: &Self) -> (Self, CtChoice) {
+        let (res, borrow) = self.sbb(rhs, Limb::ZERO);
+        (res, CtChoice::from_word_mask(borrow.0))
+    }
+
     /// Perform wrapping subtraction, returning the truthy value as the second element of the tuple
     /// if an underflow has occurred.
     pub(crate) const fn conditional_wrapping_sub(
@@ -212,4 +245,86 @@ mod tests {
         let result = U128::ZERO.checked_sub(&U128::ONE);
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn overflowing_sub() {
+        let (res, underflow) = U128::ZERO.overflowing_sub(&U128::ONE);
+        assert_eq!(res, U128::MAX);
+        assert!(underflow.is_true_vartime());
+
+        let (res, underflow) = U128::MAX.overflowing_sub(&U128::MAX);
+        assert_eq!(res, U128::ZERO);
+        assert!(!underflow.is_true_vartime());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn overflowing_sub_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U128::random(&mut rng);
+            let b = U128::random(&mut rng);
+            let (res, underflow) = a.overflowing_sub(&b);
+            assert_eq!(
+                (u128::from(res), underflow.is_true_vartime()),
+                u128::from(a).overflowing_sub(u128::from(b))
+            );
+        }
+    }
+
+    #[test]
+    fn saturating_sub_zero() {
+        assert_eq!(U128::ZERO.saturating_sub(&U128::ONE), U128::ZERO);
+    }
+
+    #[test]
+    fn abs_diff() {
+        assert_eq!(U128::MAX.abs_diff(&U128::ZERO), U128::MAX);
+        assert_eq!(U128::ZERO.abs_diff(&U128::MAX), U128::MAX);
+        assert_eq!(U128::MAX.abs_diff(&U128::MAX), U128::ZERO);
+        assert_eq!(U128::ONE.abs_diff(&U128::from(5u64)), U128::from(4u64));
+        assert_eq!(U128::from(5u64).abs_diff(&U128::ONE), U128::from(4u64));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn abs_diff_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U128::random(&mut rng);
+            let b = U128::random(&mut rng);
+            let expected = u128::from(a).abs_diff(u128::from(b));
+            assert_eq!(u128::from(a.abs_diff(&b)), expected);
+            assert_eq!(u128::from(b.abs_diff(&a)), expected);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn saturating_sub_matches_u128() {
+        use crate::Random;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for _ in 0..32 {
+            let a = U128::random(&mut rng);
+            let b = U128::random(&mut rng);
+            assert_eq!(
+                u128::from(a.saturating_sub(&b)),
+                u128::from(a).saturating_sub(u128::from(b))
+            );
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! [`Uint`] modular subtraction operations.
 
-use crate::{Limb, SubMod, Uint};
+use crate::{CtChoice, Limb, SubMod, Uint};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self - rhs mod p`.
@@ -14,10 +14,37 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         out.wrapping_add(&p.bitand_limb(mask))
     }
 
+    /// Computes `self - subtrahend mod p` if `choice` is truthy, and returns `self` otherwise.
+    ///
+    /// Assumes `self - subtrahend` as unbounded signed integer is in `[-p, p)`, and `self < p`.
+    pub const fn conditional_sub_mod(&self, subtrahend: &Self, p: &Self, choice: CtChoice) -> Self {
+        let subtrahend = Self::ct_select(&Self::ZERO, subtrahend, choice);
+        self.sub_mod(&subtrahend, p)
+    }
+
     /// Returns `(self..., carry) - (rhs...) mod (p...)`, where `carry <= 1`.
     /// Assumes `-(p...) <= (self..., carry) - (rhs...) < (p...)`.
+    ///
+    /// `carry` is the bit above the top limb of `self`, e.g. the carry out of a [`Uint::adc`], so
+    /// this can be used to reduce a value in `[0, 2p)` which doesn't fit in `Self`, as in the final
+    /// step of a Montgomery reduction:
+    ///
+    /// ```
+    /// use crypto_bigint::{Limb, U128};
+    ///
+    /// let p = U128::MAX.wrapping_sub(&U128::from_u8(4)); // 2^128 - 5
+    /// let a = p.wrapping_sub(&U128::ONE);
+    ///
+    /// // (p - 1) + (p - 1) = 2p - 2 overflows, so it's represented as (sum, carry)
+    /// let (sum, carry) = a.adc(&a, Limb::ZERO);
+    /// assert_eq!(carry, Limb::ONE);
+    ///
+    /// // 2p - 2 - p = p - 2
+    /// let reduced = sum.sub_mod_with_carry(carry, &p, &p);
+    /// assert_eq!(reduced, p.wrapping_sub(&U128::from_u8(2)));
+    /// ```
     #[inline(always)]
-    pub(crate) const fn sub_mod_with_carry(&self, carry: Limb, rhs: &Self, p: &Self) -> Self {
+    pub const fn sub_mod_with_carry(&self, carry: Limb, rhs: &Self, p: &Self) -> Self {
         debug_assert!(carry.0 <= 1);
 
         let (out, borrow) = self.sbb(rhs, Limb::ZERO);
@@ -30,6 +57,16 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         out.wrapping_add(&p.bitand_limb(mask))
     }
 
+    /// Computes `self mod modulus` for `self` in `[0, 2 * modulus)`, i.e. subtracts `modulus` iff
+    /// `self >= modulus`, in constant time.
+    ///
+    /// This is the final conditional subtraction of Montgomery reduction, e.g. for normalizing
+    /// the result of a lazily reduced operation.
+    #[inline]
+    pub const fn reduce_once(&self, modulus: &Self) -> Self {
+        self.sub_mod_with_carry(Limb::ZERO, modulus, modulus)
+    }
+
     /// Computes `self - rhs mod p` for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -57,9 +94,35 @@ impl<const LIMBS: usize> SubMod for Uint<LIMBS> {
 
 #[cfg(all(test, feature = "rand"))]
 mod tests {
-    use crate::{Limb, NonZero, Random, RandomMod, Uint, U256};
+    use crate::{CtChoice, Limb, NonZero, Random, RandomMod, Uint, U256};
     use rand_core::SeedableRng;
 
+    #[test]
+    fn reduce_once() {
+        let modulus = U256::from_u8(97);
+        for x in 0..(2 * 97) {
+            let x = U256::from_u8(x);
+            let expected = x.wrapping_rem(&modulus);
+            assert_eq!(x.reduce_once(&modulus), expected);
+            if x < modulus {
+                assert_eq!(x.reduce_once(&modulus), x);
+            }
+        }
+
+        // Every value is less than twice a modulus above `MAX / 2`
+        let modulus =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        for x in [
+            U256::ZERO,
+            modulus.wrapping_sub(&U256::ONE),
+            modulus,
+            modulus.wrapping_add(&U256::ONE),
+            U256::MAX,
+        ] {
+            assert_eq!(x.reduce_once(&modulus), x.wrapping_rem(&modulus));
+        }
+    }
+
     #[test]
     fn sub_mod_nist_p256() {
         let a =
@@ -76,6 +139,23 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn conditional_sub_mod() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let p = NonZero::<U256>::random(&mut rng);
+
+        for _ in 0..100 {
+            let a = U256::random_mod(&mut rng, &p);
+            let b = U256::random_mod(&mut rng, &p);
+
+            assert_eq!(a.conditional_sub_mod(&b, &p, CtChoice::FALSE), a);
+            assert_eq!(
+                a.conditional_sub_mod(&b, &p, CtChoice::TRUE),
+                a.sub_mod(&b, &p)
+            );
+        }
+    }
+
     macro_rules! test_sub_mod {
         ($size:expr, $test_name:ident) => {
             #[test]
@@ -36,7 +36,7 @@ proptest! {
         let inverter = BernsteinYangInverter::<6>::new(P.as_words(), &[1]);
         let actual = inverter.invert::<{U256::LIMBS}>(x.as_words());
 
-        prop_assert_eq!(bool::from(expected_is_some), actual.is_some());
+        prop_assert_eq!(expected_is_some, actual.is_some());
 
         if let Some(actual) = actual {
             let inv_bi = to_biguint(&U256::from(actual));
@@ -93,12 +93,25 @@ proptest! {
 
         let a_bi = retrieve_biguint(&a);
         let b_bi = retrieve_biguint(&b);
-        let p_bi = to_biguint(&p);
+        let p_bi = to_biguint(p);
         let expected = (a_bi * b_bi) % p_bi;
 
         prop_assert_eq!(retrieve_biguint(&actual), expected);
     }
 
+    #[test]
+    fn square(a in uint(), n in modulus()) {
+        let a = reduce(&a, n.clone());
+        let actual = a.square();
+        prop_assert_eq!(&actual, &(&a * &a));
+
+        let a_bi = retrieve_biguint(&a);
+        let n_bi = to_biguint(n.modulus());
+        let expected = (&a_bi * &a_bi) % n_bi;
+
+        prop_assert_eq!(retrieve_biguint(&actual), expected);
+    }
+
     #[test]
     fn pow(a in uint(), b in uint(), n in modulus()) {
         let a = reduce(&a, n.clone());
@@ -5,7 +5,7 @@
 use core::cmp::Ordering;
 use crypto_bigint::{BoxedUint, CheckedAdd, Limb, NonZero};
 use num_bigint::{BigUint, ModInverse};
-use proptest::prelude::*;
+use proptest::{collection::vec, prelude::*};
 
 fn to_biguint(uint: &BoxedUint) -> BigUint {
     BigUint::from_bytes_be(&uint.to_be_bytes())
@@ -55,6 +55,20 @@ prop_compose! {
         (a, b)
     }
 }
+prop_compose! {
+    /// Generate a pair of random `BoxedUint`s with the same precision, sized around the threshold
+    /// where Karatsuba multiplication is used.
+    fn large_uint_pair()(nlimbs in 24usize..80)(
+        a in vec(any::<u8>(), nlimbs * Limb::BYTES),
+        b in vec(any::<u8>(), nlimbs * Limb::BYTES),
+    ) -> (BoxedUint, BoxedUint) {
+        let bits_precision = a.len() as u32 * 8;
+        (
+            BoxedUint::from_be_slice(&a, bits_precision).unwrap(),
+            BoxedUint::from_be_slice(&b, bits_precision).unwrap(),
+        )
+    }
+}
 prop_compose! {
     /// Generate a random odd modulus.
     fn modulus()(n in uint()) -> BoxedUint {
@@ -187,6 +201,17 @@ proptest! {
         prop_assert_eq!(expected, to_biguint(&actual));
     }
 
+    #[test]
+    fn mul_wide_large((a, b) in large_uint_pair()) {
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        let expected = a_bi * b_bi;
+        let actual = a.mul(&b);
+
+        prop_assert_eq!(expected, to_biguint(&actual));
+    }
+
     #[test]
     fn rem((a, b) in uint_pair()) {
         if bool::from(!b.is_zero()) {